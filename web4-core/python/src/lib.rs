@@ -209,9 +209,10 @@ impl PyLct {
         }
     }
 
-    /// Create a child LCT under this parent
-    pub fn create_child(&self, entity_type: PyEntityType) -> (Self, PyKeyPair) {
-        let (lct, keypair) = self.inner.create_child(entity_type.into());
+    /// Create a child LCT under this parent, cross-signed with
+    /// `parent_keypair` (this LCT's own keypair)
+    pub fn create_child(&self, entity_type: PyEntityType, parent_keypair: &PyKeyPair) -> (Self, PyKeyPair) {
+        let (lct, keypair) = self.inner.create_child(entity_type.into(), &parent_keypair.inner);
         (Self { inner: lct }, PyKeyPair { inner: keypair })
     }
 
@@ -0,0 +1,423 @@
+// Copyright (c) 2026 MetaLINXX Inc.
+// SPDX-License-Identifier: MIT
+//
+// This software is covered by US Patents 11,477,027 and 12,278,913,
+// and pending application 19/178,619. See PATENTS.md for details.
+
+//! Apache Arrow export of [`ValueObservation`](crate::v3::ValueObservation)
+//! and [`TrustObservation`](crate::t3::TrustObservation) streams (behind the
+//! `arrow` feature).
+//!
+//! `V3`/`T3` fold each observation into an EMA and discard it, so an
+//! observation stream is the only place the raw contributions still exist
+//! (see [`ObservationLog`](crate::v3::ObservationLog) for `V3`'s replayable
+//! ledger). This module lays those observations out as Arrow columns for
+//! bulk analytics: `observer_id`/`contributor_id` (or `subject_id`) as
+//! 16-byte `FixedSizeBinary` straight from their `Uuid`s, `dimension`
+//! dictionary-encoded from its `name()`, and `timestamp` as
+//! microsecond-precision UTC. [`ObservationStreamWriter`] chunks a live
+//! stream of observations into Arrow IPC record batches without holding the
+//! whole stream in memory at once.
+
+use std::io::Write;
+use std::sync::Arc;
+
+use arrow::array::{
+    Array, DictionaryArray, FixedSizeBinaryArray, Float64Array, StringArray,
+    TimestampMicrosecondArray,
+};
+use arrow::compute::cast;
+use arrow::datatypes::{DataType, Field, Int32Type, Schema, TimeUnit};
+use arrow::ipc::writer::StreamWriter;
+use arrow::record_batch::RecordBatch;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::error::{Result, Web4Error};
+use crate::t3::{TrustDimension, TrustObservation};
+use crate::v3::{ValueDimension, ValueObservation};
+
+const TIMESTAMP_UTC: DataType = DataType::Timestamp(TimeUnit::Microsecond, None);
+
+fn uuid_column(ids: impl Iterator<Item = Uuid>) -> Result<FixedSizeBinaryArray> {
+    FixedSizeBinaryArray::try_from_iter(ids.map(|id| *id.as_bytes()))
+        .map_err(|e| Web4Error::InvalidInput(format!("failed to build Uuid column: {e}")))
+}
+
+fn uuid_from_fixed_size_binary(array: &FixedSizeBinaryArray, row: usize) -> Result<Uuid> {
+    Uuid::from_slice(array.value(row))
+        .map_err(|e| Web4Error::InvalidInput(format!("invalid Uuid bytes at row {row}: {e}")))
+}
+
+fn column<'a, T: Array + 'static>(batch: &'a RecordBatch, name: &str) -> Result<&'a T> {
+    batch
+        .column_by_name(name)
+        .ok_or_else(|| Web4Error::InvalidInput(format!("missing Arrow column {name:?}")))?
+        .as_any()
+        .downcast_ref::<T>()
+        .ok_or_else(|| Web4Error::InvalidInput(format!("Arrow column {name:?} has the wrong type")))
+}
+
+/// `dimension` is dictionary-encoded; cast it back to plain `Utf8` rather
+/// than threading dictionary keys/values through the rest of this module.
+fn dictionary_column(batch: &RecordBatch, name: &str) -> Result<StringArray> {
+    let raw = batch
+        .column_by_name(name)
+        .ok_or_else(|| Web4Error::InvalidInput(format!("missing Arrow column {name:?}")))?;
+    let utf8 = cast(raw, &DataType::Utf8).map_err(|e| {
+        Web4Error::InvalidInput(format!("Arrow column {name:?} is not castable to Utf8: {e}"))
+    })?;
+    Ok(utf8
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .expect("cast to Utf8 always yields a StringArray")
+        .clone())
+}
+
+fn parse_value_dimension(name: &str) -> Result<ValueDimension> {
+    ValueDimension::all()
+        .into_iter()
+        .find(|d| d.name() == name)
+        .ok_or_else(|| Web4Error::InvalidInput(format!("unknown value dimension: {name}")))
+}
+
+fn parse_trust_dimension(name: &str) -> Result<TrustDimension> {
+    TrustDimension::all()
+        .into_iter()
+        .find(|d| d.name() == name)
+        .ok_or_else(|| Web4Error::InvalidInput(format!("unknown trust dimension: {name}")))
+}
+
+/// The fixed column layout produced by [`value_observations_to_record_batch`].
+pub fn value_observation_schema() -> Schema {
+    Schema::new(vec![
+        Field::new("observer_id", DataType::FixedSizeBinary(16), false),
+        Field::new("contributor_id", DataType::FixedSizeBinary(16), false),
+        Field::new(
+            "dimension",
+            DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8)),
+            false,
+        ),
+        Field::new("sub_dimension", DataType::Utf8, true),
+        Field::new("score", DataType::Float64, false),
+        Field::new("context", DataType::Utf8, false),
+        Field::new("timestamp", TIMESTAMP_UTC, false),
+    ])
+}
+
+/// Map `observations` to a columnar [`RecordBatch`] matching
+/// [`value_observation_schema`].
+pub fn value_observations_to_record_batch(observations: &[ValueObservation]) -> Result<RecordBatch> {
+    let observer_id = uuid_column(observations.iter().map(|o| o.observer_id))?;
+    let contributor_id = uuid_column(observations.iter().map(|o| o.contributor_id))?;
+    let dimension: DictionaryArray<Int32Type> = observations
+        .iter()
+        .map(|o| Some(o.dimension.name()))
+        .collect();
+    let sub_dimension: StringArray = observations
+        .iter()
+        .map(|o| o.sub_dimension.as_deref())
+        .collect();
+    let score: Float64Array = observations.iter().map(|o| Some(o.score)).collect();
+    let context: StringArray = observations.iter().map(|o| Some(o.context.as_str())).collect();
+    let timestamp: TimestampMicrosecondArray = observations
+        .iter()
+        .map(|o| Some(o.timestamp.timestamp_micros()))
+        .collect();
+
+    RecordBatch::try_new(
+        Arc::new(value_observation_schema()),
+        vec![
+            Arc::new(observer_id),
+            Arc::new(contributor_id),
+            Arc::new(dimension),
+            Arc::new(sub_dimension),
+            Arc::new(score),
+            Arc::new(context),
+            Arc::new(timestamp),
+        ],
+    )
+    .map_err(|e| Web4Error::InvalidInput(format!("failed to build record batch: {e}")))
+}
+
+/// Reconstruct [`ValueObservation`]s from a [`RecordBatch`] produced by
+/// [`value_observations_to_record_batch`] (or any batch matching
+/// [`value_observation_schema`]).
+pub fn record_batch_to_value_observations(batch: &RecordBatch) -> Result<Vec<ValueObservation>> {
+    let observer_id = column::<FixedSizeBinaryArray>(batch, "observer_id")?;
+    let contributor_id = column::<FixedSizeBinaryArray>(batch, "contributor_id")?;
+    let dimension = dictionary_column(batch, "dimension")?;
+    let sub_dimension = column::<StringArray>(batch, "sub_dimension")?;
+    let score = column::<Float64Array>(batch, "score")?;
+    let context = column::<StringArray>(batch, "context")?;
+    let timestamp = column::<TimestampMicrosecondArray>(batch, "timestamp")?;
+
+    (0..batch.num_rows())
+        .map(|row| {
+            let ts = DateTime::from_timestamp_micros(timestamp.value(row)).ok_or_else(|| {
+                Web4Error::InvalidInput(format!("timestamp out of range for row {row}"))
+            })?;
+            Ok(ValueObservation {
+                observer_id: uuid_from_fixed_size_binary(observer_id, row)?,
+                contributor_id: uuid_from_fixed_size_binary(contributor_id, row)?,
+                dimension: parse_value_dimension(dimension.value(row))?,
+                sub_dimension: if sub_dimension.is_null(row) {
+                    None
+                } else {
+                    Some(sub_dimension.value(row).to_string())
+                },
+                score: score.value(row),
+                context: context.value(row).to_string(),
+                timestamp: ts,
+            })
+        })
+        .collect()
+}
+
+/// The fixed column layout produced by [`trust_observations_to_record_batch`].
+pub fn trust_observation_schema() -> Schema {
+    Schema::new(vec![
+        Field::new("observer_id", DataType::FixedSizeBinary(16), false),
+        Field::new("subject_id", DataType::FixedSizeBinary(16), false),
+        Field::new(
+            "dimension",
+            DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8)),
+            false,
+        ),
+        Field::new("score", DataType::Float64, false),
+        Field::new("context", DataType::Utf8, false),
+        Field::new("timestamp", TIMESTAMP_UTC, false),
+    ])
+}
+
+/// Map `observations` to a columnar [`RecordBatch`] matching
+/// [`trust_observation_schema`].
+pub fn trust_observations_to_record_batch(observations: &[TrustObservation]) -> Result<RecordBatch> {
+    let observer_id = uuid_column(observations.iter().map(|o| o.observer_id))?;
+    let subject_id = uuid_column(observations.iter().map(|o| o.subject_id))?;
+    let dimension: DictionaryArray<Int32Type> = observations
+        .iter()
+        .map(|o| Some(o.dimension.name()))
+        .collect();
+    let score: Float64Array = observations.iter().map(|o| Some(o.score)).collect();
+    let context: StringArray = observations.iter().map(|o| Some(o.context.as_str())).collect();
+    let timestamp: TimestampMicrosecondArray = observations
+        .iter()
+        .map(|o| Some(o.timestamp.timestamp_micros()))
+        .collect();
+
+    RecordBatch::try_new(
+        Arc::new(trust_observation_schema()),
+        vec![
+            Arc::new(observer_id),
+            Arc::new(subject_id),
+            Arc::new(dimension),
+            Arc::new(score),
+            Arc::new(context),
+            Arc::new(timestamp),
+        ],
+    )
+    .map_err(|e| Web4Error::InvalidInput(format!("failed to build record batch: {e}")))
+}
+
+/// Reconstruct [`TrustObservation`]s from a [`RecordBatch`] produced by
+/// [`trust_observations_to_record_batch`] (or any batch matching
+/// [`trust_observation_schema`]).
+pub fn record_batch_to_trust_observations(batch: &RecordBatch) -> Result<Vec<TrustObservation>> {
+    let observer_id = column::<FixedSizeBinaryArray>(batch, "observer_id")?;
+    let subject_id = column::<FixedSizeBinaryArray>(batch, "subject_id")?;
+    let dimension = dictionary_column(batch, "dimension")?;
+    let score = column::<Float64Array>(batch, "score")?;
+    let context = column::<StringArray>(batch, "context")?;
+    let timestamp = column::<TimestampMicrosecondArray>(batch, "timestamp")?;
+
+    (0..batch.num_rows())
+        .map(|row| {
+            let ts = DateTime::from_timestamp_micros(timestamp.value(row)).ok_or_else(|| {
+                Web4Error::InvalidInput(format!("timestamp out of range for row {row}"))
+            })?;
+            Ok(TrustObservation {
+                observer_id: uuid_from_fixed_size_binary(observer_id, row)?,
+                subject_id: uuid_from_fixed_size_binary(subject_id, row)?,
+                dimension: parse_trust_dimension(dimension.value(row))?,
+                score: score.value(row),
+                context: context.value(row).to_string(),
+                timestamp: ts,
+            })
+        })
+        .collect()
+}
+
+/// Chunks a live stream of [`ValueObservation`]s into Arrow IPC record
+/// batches of at most `chunk_size` rows each, so a long-running observer
+/// doesn't have to buffer its whole history in memory before writing.
+///
+/// Call [`Self::write`] per observation and [`Self::finish`] once the
+/// stream ends; `finish` flushes any partial, not-yet-full chunk before
+/// closing out the IPC stream.
+pub struct ObservationStreamWriter<W: Write> {
+    inner: StreamWriter<W>,
+    chunk_size: usize,
+    buffer: Vec<ValueObservation>,
+}
+
+impl<W: Write> ObservationStreamWriter<W> {
+    /// Open a new chunked writer over `writer`, emitting up to `chunk_size`
+    /// observations per Arrow IPC record batch.
+    pub fn try_new(writer: W, chunk_size: usize) -> Result<Self> {
+        let inner = StreamWriter::try_new(writer, &value_observation_schema())
+            .map_err(|e| Web4Error::InvalidInput(format!("failed to open IPC stream: {e}")))?;
+        Ok(Self {
+            inner,
+            chunk_size: chunk_size.max(1),
+            buffer: Vec::new(),
+        })
+    }
+
+    /// Buffer `observation`, flushing a full record batch once the buffer
+    /// reaches `chunk_size`.
+    pub fn write(&mut self, observation: ValueObservation) -> Result<()> {
+        self.buffer.push(observation);
+        if self.buffer.len() >= self.chunk_size {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Flush any buffered observations as one record batch, even if it's
+    /// smaller than `chunk_size`.
+    pub fn flush(&mut self) -> Result<()> {
+        if self.buffer.is_empty() {
+            return Ok(());
+        }
+        let batch = value_observations_to_record_batch(&self.buffer)?;
+        self.inner
+            .write(&batch)
+            .map_err(|e| Web4Error::InvalidInput(format!("failed to write IPC batch: {e}")))?;
+        self.buffer.clear();
+        Ok(())
+    }
+
+    /// Flush any remaining observations and close out the IPC stream,
+    /// returning the underlying writer.
+    pub fn finish(mut self) -> Result<W> {
+        self.flush()?;
+        self.inner
+            .finish()
+            .map_err(|e| Web4Error::InvalidInput(format!("failed to finish IPC stream: {e}")))?;
+        self.inner
+            .into_inner()
+            .map_err(|e| Web4Error::InvalidInput(format!("failed to recover IPC writer: {e}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::ipc::reader::StreamReader;
+    use std::io::Cursor;
+
+    fn sample_value_observations() -> Vec<ValueObservation> {
+        let observer = Uuid::new_v4();
+        let contributor = Uuid::new_v4();
+        vec![
+            ValueObservation::new(observer, contributor, ValueDimension::Valuation, 0.8, "good work")
+                .unwrap(),
+            ValueObservation::new_sub_dimension(
+                observer,
+                contributor,
+                "market_demand",
+                ValueDimension::Valuation,
+                0.65,
+                "strong demand",
+            )
+            .unwrap(),
+        ]
+    }
+
+    fn sample_trust_observations() -> Vec<TrustObservation> {
+        let observer = Uuid::new_v4();
+        let subject = Uuid::new_v4();
+        vec![
+            TrustObservation::new(observer, subject, TrustDimension::Talent, 0.7, "solid delivery")
+                .unwrap(),
+            TrustObservation::new(observer, subject, TrustDimension::Temperament, 0.4, "missed a deadline")
+                .unwrap(),
+        ]
+    }
+
+    #[test]
+    fn test_value_observation_round_trip() {
+        let observations = sample_value_observations();
+        let batch = value_observations_to_record_batch(&observations).unwrap();
+        assert_eq!(batch.num_rows(), 2);
+
+        let restored = record_batch_to_value_observations(&batch).unwrap();
+        assert_eq!(restored.len(), observations.len());
+        for (original, restored) in observations.iter().zip(restored.iter()) {
+            assert_eq!(restored.observer_id, original.observer_id);
+            assert_eq!(restored.contributor_id, original.contributor_id);
+            assert_eq!(restored.dimension, original.dimension);
+            assert_eq!(restored.sub_dimension, original.sub_dimension);
+            assert_eq!(restored.score, original.score);
+            assert_eq!(restored.context, original.context);
+            assert_eq!(
+                restored.timestamp.timestamp_micros(),
+                original.timestamp.timestamp_micros()
+            );
+        }
+    }
+
+    #[test]
+    fn test_trust_observation_round_trip() {
+        let observations = sample_trust_observations();
+        let batch = trust_observations_to_record_batch(&observations).unwrap();
+        let restored = record_batch_to_trust_observations(&batch).unwrap();
+
+        assert_eq!(restored.len(), observations.len());
+        for (original, restored) in observations.iter().zip(restored.iter()) {
+            assert_eq!(restored.observer_id, original.observer_id);
+            assert_eq!(restored.subject_id, original.subject_id);
+            assert_eq!(restored.dimension, original.dimension);
+            assert_eq!(restored.score, original.score);
+            assert_eq!(restored.context, original.context);
+        }
+    }
+
+    #[test]
+    fn test_stream_writer_chunks_and_round_trips() {
+        let observer = Uuid::new_v4();
+        let contributor = Uuid::new_v4();
+        let observations: Vec<ValueObservation> = (0..5)
+            .map(|i| {
+                ValueObservation::new(
+                    observer,
+                    contributor,
+                    ValueDimension::Veracity,
+                    0.5 + i as f64 * 0.05,
+                    "stream test",
+                )
+                .unwrap()
+            })
+            .collect();
+
+        let mut writer = ObservationStreamWriter::try_new(Vec::new(), 2).unwrap();
+        for obs in observations.clone() {
+            writer.write(obs).unwrap();
+        }
+        let bytes = writer.finish().unwrap();
+
+        let reader = StreamReader::try_new(Cursor::new(bytes), None).unwrap();
+        let mut restored = Vec::new();
+        for batch in reader {
+            let batch = batch.unwrap();
+            restored.extend(record_batch_to_value_observations(&batch).unwrap());
+        }
+
+        assert_eq!(restored.len(), observations.len());
+        // chunk_size=2 over 5 rows => 3 batches (2, 2, 1), all flushed by `finish`.
+        for (original, restored) in observations.iter().zip(restored.iter()) {
+            assert_eq!(restored.score, original.score);
+        }
+    }
+}
@@ -192,6 +192,28 @@ impl CoherenceCalculator {
         1.0 / (1.0 + (std_dev * 5.0).exp())
     }
 
+    /// Calculate stability from time-bucketed behavioral history.
+    ///
+    /// Unlike [`calculate_stability`](Self::calculate_stability), which weights
+    /// a score from a year ago the same as one from yesterday, this consumes a
+    /// [`StabilityHistory`] so recent erratic behavior lowers stability faster
+    /// than stale data. When the *decayed* observation weight falls below
+    /// `min_interactions`, the estimate regresses to the neutral default of 0.3
+    /// — a long-inactive entity is treated as un-assessed rather than stable.
+    pub fn calculate_stability_from_history(
+        &self,
+        history: &StabilityHistory,
+        now: i64,
+        half_life_days: f64,
+    ) -> f64 {
+        let (variance, weight) = history.weighted_variance(now, half_life_days);
+        if weight < self.min_interactions as f64 {
+            return 0.3;
+        }
+        let std_dev = variance.max(0.0).sqrt();
+        1.0 / (1.0 + (std_dev * 5.0).exp())
+    }
+
     /// Calculate phi (information integration)
     ///
     /// Inspired by Integrated Information Theory.
@@ -307,6 +329,120 @@ pub fn check_coherence(lct: &Lct, coherence: &Coherence) -> Result<()> {
     Ok(())
 }
 
+/// Number of coarse time buckets kept by [`StabilityHistory`].
+pub const STABILITY_BUCKETS: usize = 32;
+
+/// A single time bucket of behavioral observations.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+struct StabilityBucket {
+    /// Bucket index = `timestamp / bucket_duration`.
+    epoch: i64,
+    /// Sum of observed scores in this bucket.
+    sum: f64,
+    /// Sum of squared observed scores (for intra-bucket variance).
+    sum_sq: f64,
+    /// Number of observations in this bucket.
+    count: u64,
+}
+
+/// Time-bucketed behavioral history for a recency-weighted stability estimate.
+///
+/// Interaction scores are accumulated into coarse fixed-duration buckets (up to
+/// [`STABILITY_BUCKETS`]); older buckets are down-weighted by the crate's decay
+/// factor before the mean/variance is computed, so recent behavior dominates
+/// and stale data fades. This mirrors the decaying historical buckets used by
+/// probabilistic scorers.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct StabilityHistory {
+    bucket_duration: i64,
+    buckets: Vec<StabilityBucket>,
+}
+
+impl Default for StabilityHistory {
+    fn default() -> Self {
+        // One-day buckets by default.
+        Self::with_bucket_duration(86400)
+    }
+}
+
+impl StabilityHistory {
+    /// Create a history with the given bucket duration (in seconds).
+    pub fn with_bucket_duration(bucket_duration: i64) -> Self {
+        Self {
+            bucket_duration: bucket_duration.max(1),
+            buckets: Vec::new(),
+        }
+    }
+
+    /// Record an interaction score observed at `timestamp`.
+    ///
+    /// Scores landing in the same time bucket accumulate together; when more
+    /// than [`STABILITY_BUCKETS`] distinct buckets exist, the oldest is evicted.
+    pub fn observe(&mut self, score: f64, timestamp: i64) {
+        let score = score.clamp(0.0, 1.0);
+        let epoch = timestamp.div_euclid(self.bucket_duration);
+
+        if let Some(bucket) = self.buckets.iter_mut().find(|b| b.epoch == epoch) {
+            bucket.sum += score;
+            bucket.sum_sq += score * score;
+            bucket.count += 1;
+            return;
+        }
+
+        self.buckets.push(StabilityBucket {
+            epoch,
+            sum: score,
+            sum_sq: score * score,
+            count: 1,
+        });
+
+        if self.buckets.len() > STABILITY_BUCKETS {
+            // Evict the oldest bucket.
+            if let Some((idx, _)) = self
+                .buckets
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, b)| b.epoch)
+            {
+                self.buckets.swap_remove(idx);
+            }
+        }
+    }
+
+    /// Compute the recency-weighted variance as of `now`, plus the total
+    /// decayed observation weight.
+    ///
+    /// Each bucket's contribution is scaled by `0.5^(age_days / half_life_days)`
+    /// so older observations count for less. Returns `(variance, weight)`; the
+    /// weight lets callers decide whether there is enough recent evidence.
+    pub fn weighted_variance(&self, now: i64, half_life_days: f64) -> (f64, f64) {
+        let mut w_count = 0.0; // Σ w_b * count_b
+        let mut w_sum = 0.0; // Σ w_b * sum_b
+        let mut w_sum_sq = 0.0; // Σ w_b * sum_sq_b
+
+        for bucket in &self.buckets {
+            let bucket_time = bucket.epoch * self.bucket_duration;
+            let age_days = (now - bucket_time) as f64 / 86400.0;
+            let weight = if half_life_days > 0.0 {
+                0.5_f64.powf(age_days.max(0.0) / half_life_days)
+            } else {
+                1.0
+            };
+            w_count += weight * bucket.count as f64;
+            w_sum += weight * bucket.sum;
+            w_sum_sq += weight * bucket.sum_sq;
+        }
+
+        if w_count <= 0.0 {
+            return (0.0, 0.0);
+        }
+
+        let mean = w_sum / w_count;
+        let variance = (w_sum_sq / w_count - mean * mean).max(0.0);
+        (variance, w_count)
+    }
+}
+
 /// Coherence event for tracking history
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct CoherenceEvent {
@@ -345,6 +481,148 @@ impl CoherenceEvent {
     }
 }
 
+/// Map an entity type to its stable snake_case label (matches the serde repr).
+fn entity_type_label(entity_type: &EntityType) -> &'static str {
+    match entity_type {
+        EntityType::Human => "human",
+        EntityType::AiSoftware => "ai_software",
+        EntityType::AiEmbodied => "ai_embodied",
+        EntityType::Organization => "organization",
+        EntityType::Role => "role",
+        EntityType::Task => "task",
+        EntityType::Resource => "resource",
+        EntityType::Hybrid => "hybrid",
+    }
+}
+
+/// How often each factor was the coherence bottleneck.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct LimitingFactorCounts {
+    pub continuity: u64,
+    pub stability: u64,
+    pub phi: u64,
+    pub reachability: u64,
+}
+
+impl LimitingFactorCounts {
+    fn increment(&mut self, name: &str) {
+        match name {
+            "continuity" => self.continuity += 1,
+            "stability" => self.stability += 1,
+            "phi" => self.phi += 1,
+            "reachability" => self.reachability += 1,
+            _ => {}
+        }
+    }
+}
+
+/// Aggregate coherence statistics for a single entity type.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct EntityTypeCoherenceStats {
+    /// Number of events observed.
+    pub total_events: u64,
+    /// Events meeting the type's coherence threshold.
+    pub passing: u64,
+    /// Events below the threshold.
+    pub failing: u64,
+    /// Distribution of which factor was limiting.
+    pub limiting_factors: LimitingFactorCounts,
+    /// Mean of each coherence component over all events.
+    pub mean_continuity: f64,
+    pub mean_stability: f64,
+    pub mean_phi: f64,
+    pub mean_reachability: f64,
+    pub mean_total: f64,
+}
+
+/// A point-in-time, JSON-serializable view of aggregate coherence health.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct CoherenceMetricsSnapshot {
+    /// Stats keyed by entity-type label (e.g. `"ai_software"`).
+    pub by_entity_type: std::collections::BTreeMap<String, EntityTypeCoherenceStats>,
+}
+
+#[derive(Default)]
+struct CoherenceAccumulator {
+    total: u64,
+    passing: u64,
+    failing: u64,
+    limiting: LimitingFactorCounts,
+    sum_continuity: f64,
+    sum_stability: f64,
+    sum_phi: f64,
+    sum_reachability: f64,
+    sum_total: f64,
+}
+
+/// Aggregator for coherence observability.
+///
+/// Ingests [`CoherenceEvent`]s tagged with their [`EntityType`] and answers, in
+/// one [`snapshot`](Self::snapshot) call, questions like "which coherence
+/// dimension is dragging down AiSoftware entities network-wide" — the pass/fail
+/// split against [`check_coherence`], the distribution of limiting factors, and
+/// the mean component scores per entity type. Previously this required manual
+/// iteration over every event.
+#[derive(Default)]
+pub struct CoherenceMetrics {
+    by_type: std::collections::HashMap<&'static str, CoherenceAccumulator>,
+}
+
+impl CoherenceMetrics {
+    /// Create an empty aggregator.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Ingest one coherence event for an entity of `entity_type`.
+    ///
+    /// Pass/fail is decided against [`coherence_threshold_for_entity`], the
+    /// per-type threshold [`check_coherence`] starts from; the raise to
+    /// `1 - trust_ceiling` that [`check_coherence`] also applies is not made here.
+    pub fn record(&mut self, entity_type: &EntityType, event: &CoherenceEvent) {
+        let label = entity_type_label(entity_type);
+        let threshold = coherence_threshold_for_entity(entity_type);
+        let c = &event.coherence;
+        let acc = self.by_type.entry(label).or_default();
+
+        acc.total += 1;
+        if c.total() >= threshold {
+            acc.passing += 1;
+        } else {
+            acc.failing += 1;
+        }
+        acc.limiting.increment(c.limiting_factor().0);
+        acc.sum_continuity += c.continuity;
+        acc.sum_stability += c.stability;
+        acc.sum_phi += c.phi;
+        acc.sum_reachability += c.reachability;
+        acc.sum_total += c.total();
+    }
+
+    /// Produce a serializable snapshot of the aggregated metrics.
+    pub fn snapshot(&self) -> CoherenceMetricsSnapshot {
+        let mut by_entity_type = std::collections::BTreeMap::new();
+        for (label, acc) in &self.by_type {
+            let n = acc.total.max(1) as f64;
+            by_entity_type.insert(
+                label.to_string(),
+                EntityTypeCoherenceStats {
+                    total_events: acc.total,
+                    passing: acc.passing,
+                    failing: acc.failing,
+                    limiting_factors: acc.limiting.clone(),
+                    mean_continuity: acc.sum_continuity / n,
+                    mean_stability: acc.sum_stability / n,
+                    mean_phi: acc.sum_phi / n,
+                    mean_reachability: acc.sum_reachability / n,
+                    mean_total: acc.sum_total / n,
+                },
+            );
+        }
+        CoherenceMetricsSnapshot { by_entity_type }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -398,6 +676,45 @@ mod tests {
         assert!(stability_erratic < stability); // Erratic should be less stable
     }
 
+    #[test]
+    fn test_stability_history_recency_weighting() {
+        let calc = CoherenceCalculator::default();
+        let day = 86400;
+
+        // Erratic long ago, calm recently.
+        let mut history = StabilityHistory::default();
+        for i in 0..20 {
+            let score = if i % 2 == 0 { 0.1 } else { 0.9 };
+            history.observe(score, (300 + i) * day); // 62-81 days before `now`
+        }
+        for i in 0..20 {
+            history.observe(0.8, (360 + i) * day); // recent, calm
+        }
+
+        let now = 381 * day;
+        let recent_weighted = calc.calculate_stability_from_history(&history, now, 30.0);
+        let flat = calc.calculate_stability(&[
+            0.1, 0.9, 0.1, 0.9, 0.1, 0.9, 0.1, 0.9, 0.1, 0.9, 0.8, 0.8, 0.8, 0.8, 0.8,
+        ]);
+        // Recency weighting should rate the now-calm entity as more stable than
+        // a flat variance that still remembers the old erratic streak.
+        assert!(recent_weighted > flat);
+    }
+
+    #[test]
+    fn test_stability_history_regresses_when_stale() {
+        let calc = CoherenceCalculator::default();
+        let day = 86400;
+        let mut history = StabilityHistory::default();
+        for i in 0..20 {
+            history.observe(0.8, i * day);
+        }
+        // Query years later: decayed weight drops below min_interactions → 0.3.
+        let now = 2000 * day;
+        let stability = calc.calculate_stability_from_history(&history, now, 30.0);
+        assert!((stability - 0.3).abs() < 1e-9);
+    }
+
     #[test]
     fn test_entity_thresholds() {
         assert_eq!(coherence_threshold_for_entity(&EntityType::Human), 0.5);
@@ -405,6 +722,34 @@ mod tests {
         assert_eq!(coherence_threshold_for_entity(&EntityType::Task), 0.3);
     }
 
+    #[test]
+    fn test_coherence_metrics_aggregation() {
+        let mut metrics = CoherenceMetrics::new();
+        let uuid = Uuid::new_v4();
+
+        // Two passing AiSoftware events (threshold 0.7) and one failing.
+        let pass = Coherence::with_values(0.95, 0.95, 0.95, 0.95).unwrap();
+        let fail = Coherence::with_values(0.95, 0.4, 0.95, 0.95).unwrap();
+        metrics.record(
+            &EntityType::AiSoftware,
+            &CoherenceEvent::new(uuid, pass.clone()),
+        );
+        metrics.record(&EntityType::AiSoftware, &CoherenceEvent::new(uuid, pass));
+        metrics.record(&EntityType::AiSoftware, &CoherenceEvent::new(uuid, fail));
+        // One Human event kept in a separate bucket.
+        let human = Coherence::with_values(0.8, 0.8, 0.8, 0.8).unwrap();
+        metrics.record(&EntityType::Human, &CoherenceEvent::new(uuid, human));
+
+        let snap = metrics.snapshot();
+        let ai = &snap.by_entity_type["ai_software"];
+        assert_eq!(ai.total_events, 3);
+        assert_eq!(ai.passing, 2);
+        assert_eq!(ai.failing, 1);
+        // Stability was the bottleneck on the one failing event.
+        assert_eq!(ai.limiting_factors.stability, 1);
+        assert_eq!(snap.by_entity_type["human"].total_events, 1);
+    }
+
     #[test]
     fn test_coherence_check() {
         let (lct, _) = Lct::new(EntityType::Human, None);
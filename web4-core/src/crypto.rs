@@ -102,6 +102,37 @@ impl PublicKey {
     pub fn to_hex(&self) -> String {
         hex::encode(&self.to_bytes())
     }
+
+    /// Parse from a hex-encoded public key, as produced by [`to_hex`](Self::to_hex).
+    pub fn from_hex(hex_str: &str) -> Result<Self> {
+        let bytes = hex::decode(hex_str).map_err(|e| Web4Error::Crypto(format!("Invalid hex: {}", e)))?;
+        let arr: [u8; 32] = bytes
+            .try_into()
+            .map_err(|_| Web4Error::Crypto("Invalid public key length".into()))?;
+        Self::from_bytes(&arr)
+    }
+
+    /// Multibase string (`z`-prefixed base58btc) over the key, multicodec-prefixed
+    /// the same way `did:key` expects — the result is directly usable as a
+    /// `did:key:` suffix with no separate conversion step.
+    pub fn to_multibase(&self) -> String {
+        let mut prefixed = Vec::with_capacity(ED25519_MULTICODEC_PREFIX.len() + 32);
+        prefixed.extend_from_slice(&ED25519_MULTICODEC_PREFIX);
+        prefixed.extend_from_slice(&self.to_bytes());
+        multibase::encode_base58btc(&prefixed)
+    }
+
+    /// Parse from a multibase string as produced by [`to_multibase`](Self::to_multibase).
+    pub fn from_multibase(s: &str) -> Result<Self> {
+        let prefixed = multibase::decode(s).map_err(|e| Web4Error::Crypto(format!("Invalid multibase: {}", e)))?;
+        if prefixed.len() != ED25519_MULTICODEC_PREFIX.len() + 32 || prefixed[..2] != ED25519_MULTICODEC_PREFIX {
+            return Err(Web4Error::Crypto(
+                "not a multibase-encoded Ed25519 public key with the expected multicodec prefix".into(),
+            ));
+        }
+        let arr: [u8; 32] = prefixed[2..].try_into().expect("checked length above");
+        Self::from_bytes(&arr)
+    }
 }
 
 /// Signature bytes wrapper
@@ -121,6 +152,20 @@ impl SignatureBytes {
     pub fn to_hex(&self) -> String {
         hex::encode(&self.bytes)
     }
+
+    /// Multibase string (`z`-prefixed base58btc) over the raw signature bytes.
+    pub fn to_multibase(&self) -> String {
+        multibase::encode_base58btc(&self.bytes)
+    }
+
+    /// Parse from a multibase string as produced by [`to_multibase`](Self::to_multibase).
+    pub fn from_multibase(s: &str) -> Result<Self> {
+        let bytes = multibase::decode(s).map_err(|e| Web4Error::Crypto(format!("Invalid multibase: {}", e)))?;
+        let arr: [u8; 64] = bytes
+            .try_into()
+            .map_err(|_| Web4Error::Crypto("Invalid signature length".into()))?;
+        Ok(Self::from_bytes(arr))
+    }
 }
 
 /// Compute SHA-256 hash
@@ -207,6 +252,713 @@ mod hex {
     }
 }
 
+/// Multicodec prefix for an Ed25519 public key (`0xed 0x01`), as used by the
+/// `did:key` method: [`PublicKey::to_multibase`] embeds it so the resulting
+/// string is directly usable as a `did:key:` suffix.
+const ED25519_MULTICODEC_PREFIX: [u8; 2] = [0xed, 0x01];
+
+/// Base58btc (Bitcoin alphabet) codec.
+///
+/// More compact and self-describing than the crate's hex helper, and the
+/// encoding `did:key` and other multibase-based DID/credential tooling
+/// expect — see [`multibase`] and [`PublicKey::to_multibase`].
+pub mod base58btc {
+    const ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+    /// Encode `bytes` as base58btc.
+    pub fn encode(bytes: &[u8]) -> String {
+        let zeros = bytes.iter().take_while(|&&b| b == 0).count();
+        let mut num = bytes.to_vec();
+        let mut digits: Vec<u8> = Vec::new();
+        while num.iter().any(|&b| b != 0) {
+            let mut remainder = 0u32;
+            for byte in num.iter_mut() {
+                let acc = remainder * 256 + *byte as u32;
+                *byte = (acc / 58) as u8;
+                remainder = acc % 58;
+            }
+            digits.push(remainder as u8);
+        }
+        let mut out = "1".repeat(zeros);
+        out.extend(digits.iter().rev().map(|&d| ALPHABET[d as usize] as char));
+        out
+    }
+
+    /// Decode a base58btc string back into bytes.
+    pub fn decode(s: &str) -> std::result::Result<Vec<u8>, String> {
+        let zeros = s.chars().take_while(|&c| c == '1').count();
+        let mut bytes: Vec<u8> = Vec::new();
+        for c in s.chars().skip(zeros) {
+            let value = ALPHABET
+                .iter()
+                .position(|&a| a as char == c)
+                .ok_or_else(|| format!("invalid base58 character: {c}"))?;
+            let mut carry = value as u32;
+            for byte in bytes.iter_mut() {
+                carry += (*byte as u32) * 58;
+                *byte = (carry & 0xff) as u8;
+                carry >>= 8;
+            }
+            while carry > 0 {
+                bytes.push((carry & 0xff) as u8);
+                carry >>= 8;
+            }
+        }
+        let mut out = vec![0u8; zeros];
+        out.extend(bytes.iter().rev());
+        Ok(out)
+    }
+}
+
+/// Minimal [multibase](https://github.com/multiformats/multibase) framing: a
+/// single leading character identifying the encoding, so a byte string
+/// round-trips without an out-of-band format agreement. Only the two bases
+/// Web4 needs are implemented: `z` (base58btc) and `f` (base16/hex).
+pub mod multibase {
+    use super::{base58btc, hex};
+
+    /// Encode `bytes` as base58btc, multibase-prefixed with `z`.
+    pub fn encode_base58btc(bytes: &[u8]) -> String {
+        format!("z{}", base58btc::encode(bytes))
+    }
+
+    /// Encode `bytes` as base16 (hex), multibase-prefixed with `f`.
+    pub fn encode_base16(bytes: &[u8]) -> String {
+        format!("f{}", hex::encode(bytes))
+    }
+
+    /// Decode a multibase string, dispatching on its leading character.
+    pub fn decode(s: &str) -> std::result::Result<Vec<u8>, String> {
+        let prefix = s.chars().next().ok_or("empty multibase string")?;
+        let rest = &s[prefix.len_utf8()..];
+        match prefix {
+            'z' => base58btc::decode(rest),
+            'f' => hex::decode(rest),
+            other => Err(format!("unsupported multibase prefix: {other}")),
+        }
+    }
+}
+
+/// FROST (Flexible Round-Optimized Schnorr Threshold) signatures over the
+/// Ed25519 group.
+///
+/// `EntityTrust::receive_witness`/`give_witness` and `WasmTrustStore::witness`
+/// treat every witness as its own signed event. FROST instead lets `t` of `n`
+/// witnesses jointly produce a *single* 64-byte signature over one statement,
+/// which verifies as an ordinary Ed25519 signature via [`PublicKey::verify`]
+/// against the group's public key — downstream code needs no awareness that
+/// the signature was produced by a quorum rather than one keyholder.
+///
+/// The three-round protocol:
+/// 1. [`trusted_dealer_keygen`] splits a secret into `n` Shamir shares over a
+///    degree-`(t - 1)` polynomial, alongside a group public key and a
+///    per-participant verification share so the aggregator can catch a bad
+///    signer.
+/// 2. [`commit`] — each of the `t` chosen signers samples a nonce pair and
+///    publishes its commitment. The returned [`SigningNonces`] must be kept
+///    secret and is consumed exactly once by [`sign_round2`].
+/// 3. [`sign_round2`] — once every signer's commitment has been collected,
+///    each signer derives a per-signer binding factor from the full
+///    commitment list, then emits a signature share.
+/// 4. [`aggregate`] sums the shares into a standard Ed25519 `(R, z)`
+///    signature, rejecting any share that fails `g^{zᵢ} = Rᵢ · (g^{sᵢ})^{λᵢc}`
+///    before it can corrupt the aggregate.
+///
+/// This module implements trusted-dealer key generation only; a production
+/// deployment should replace step 1 with a distributed key generation (DKG)
+/// round so no single party ever learns the full secret.
+pub mod frost {
+    use super::{PublicKey, SignatureBytes};
+    use crate::error::{Result, Web4Error};
+    use curve25519_dalek::edwards::{CompressedEdwardsY, EdwardsPoint};
+    use curve25519_dalek::scalar::Scalar;
+    use curve25519_dalek::traits::Identity;
+    use rand::rngs::OsRng;
+    use sha2::{Digest, Sha512};
+    use std::collections::BTreeMap;
+
+    /// 1-based index identifying a participant within a signing group.
+    pub type ParticipantId = u16;
+
+    fn scalar_from_id(id: ParticipantId) -> Scalar {
+        Scalar::from(id as u64)
+    }
+
+    /// Hash arbitrary context bytes to a scalar the same way RFC 8032 derives
+    /// an Ed25519 challenge from SHA-512 output.
+    fn hash_to_scalar(parts: &[&[u8]]) -> Scalar {
+        let mut hasher = Sha512::new();
+        for part in parts {
+            hasher.update(part);
+        }
+        Scalar::from_hash(hasher)
+    }
+
+    fn decompress(bytes: &[u8; 32]) -> Result<EdwardsPoint> {
+        CompressedEdwardsY(*bytes)
+            .decompress()
+            .ok_or_else(|| Web4Error::Crypto("invalid curve point in FROST commitment".into()))
+    }
+
+    /// One participant's Shamir share of the group signing key.
+    #[derive(Clone)]
+    pub struct KeyShare {
+        pub id: ParticipantId,
+        secret_share: Scalar,
+        /// `g^{secret_share}`, published so the aggregator can verify this
+        /// participant's signature shares.
+        pub verification_share: [u8; 32],
+        /// `g^{secret}`, the group's Ed25519 public key.
+        pub group_public_key: [u8; 32],
+    }
+
+    /// Trusted-dealer key generation: split `secret_bytes` into `n` shares
+    /// under a random degree-`(t - 1)` polynomial, any `t` of which can
+    /// jointly sign for the resulting group public key.
+    pub fn trusted_dealer_keygen(
+        secret_bytes: &[u8; 32],
+        n: ParticipantId,
+        t: ParticipantId,
+    ) -> Result<Vec<KeyShare>> {
+        if t == 0 || t > n {
+            return Err(Web4Error::InvalidInput(format!(
+                "threshold {t} must be between 1 and n={n}"
+            )));
+        }
+
+        let secret = Scalar::from_bytes_mod_order(*secret_bytes);
+        let mut coefficients = Vec::with_capacity(t as usize);
+        coefficients.push(secret);
+        for _ in 1..t {
+            coefficients.push(Scalar::random(&mut OsRng));
+        }
+
+        let group_public_key = EdwardsPoint::mul_base(&secret).compress().to_bytes();
+
+        let eval = |x: Scalar| -> Scalar {
+            coefficients
+                .iter()
+                .rev()
+                .fold(Scalar::ZERO, |acc, coeff| acc * x + *coeff)
+        };
+
+        Ok((1..=n)
+            .map(|id| {
+                let secret_share = eval(scalar_from_id(id));
+                KeyShare {
+                    id,
+                    secret_share,
+                    verification_share: EdwardsPoint::mul_base(&secret_share)
+                        .compress()
+                        .to_bytes(),
+                    group_public_key,
+                }
+            })
+            .collect())
+    }
+
+    /// A signer's private nonce pair for one signing round. Must never be
+    /// reused; [`sign_round2`] consumes it by value so the type system
+    /// prevents a second use.
+    pub struct SigningNonces {
+        id: ParticipantId,
+        hiding: Scalar,
+        binding: Scalar,
+    }
+
+    /// The public commitment `(D = g^d, E = g^e)` a signer publishes in round one.
+    #[derive(Clone, Copy, Debug)]
+    pub struct NonceCommitment {
+        pub id: ParticipantId,
+        pub hiding: [u8; 32],
+        pub binding: [u8; 32],
+    }
+
+    /// Round one: sample a fresh nonce pair for `id` and the commitment to publish for it.
+    pub fn commit(id: ParticipantId) -> (SigningNonces, NonceCommitment) {
+        let hiding = Scalar::random(&mut OsRng);
+        let binding = Scalar::random(&mut OsRng);
+        let commitment = NonceCommitment {
+            id,
+            hiding: EdwardsPoint::mul_base(&hiding).compress().to_bytes(),
+            binding: EdwardsPoint::mul_base(&binding).compress().to_bytes(),
+        };
+        (SigningNonces { id, hiding, binding }, commitment)
+    }
+
+    fn encode_commitment_list(commitments: &[NonceCommitment]) -> Vec<u8> {
+        let mut sorted: Vec<&NonceCommitment> = commitments.iter().collect();
+        sorted.sort_by_key(|c| c.id);
+        let mut buf = Vec::with_capacity(sorted.len() * 68);
+        for c in sorted {
+            buf.extend_from_slice(&c.id.to_be_bytes());
+            buf.extend_from_slice(&c.hiding);
+            buf.extend_from_slice(&c.binding);
+        }
+        buf
+    }
+
+    fn binding_factor(id: ParticipantId, message: &[u8], commitments: &[NonceCommitment]) -> Scalar {
+        hash_to_scalar(&[
+            b"FROST-Ed25519-rho",
+            &id.to_be_bytes(),
+            message,
+            &encode_commitment_list(commitments),
+        ])
+    }
+
+    /// `R = Σ (Dᵢ · Eᵢ^{ρᵢ})`, the group commitment for this message and commitment set.
+    fn group_commitment(message: &[u8], commitments: &[NonceCommitment]) -> Result<EdwardsPoint> {
+        let mut r = EdwardsPoint::identity();
+        for c in commitments {
+            let rho = binding_factor(c.id, message, commitments);
+            r += decompress(&c.hiding)? + rho * decompress(&c.binding)?;
+        }
+        Ok(r)
+    }
+
+    /// The plain Ed25519 challenge `c = SHA512(R || Y || msg) mod L`, so the
+    /// aggregated signature verifies under the ordinary RFC 8032 rule.
+    fn challenge(r_bytes: &[u8; 32], group_public_key: &[u8; 32], message: &[u8]) -> Scalar {
+        hash_to_scalar(&[r_bytes, group_public_key, message])
+    }
+
+    /// The Lagrange coefficient `λᵢ` for `id` within the participating set.
+    fn lagrange_coefficient(id: ParticipantId, participants: &[ParticipantId]) -> Scalar {
+        let xi = scalar_from_id(id);
+        let mut num = Scalar::ONE;
+        let mut den = Scalar::ONE;
+        for &j in participants {
+            if j == id {
+                continue;
+            }
+            let xj = scalar_from_id(j);
+            num *= xj;
+            den *= xj - xi;
+        }
+        num * den.invert()
+    }
+
+    /// This signer's round-two signature share `zᵢ = dᵢ + eᵢ·ρᵢ + λᵢ·sᵢ·c`.
+    #[derive(Clone, Copy, Debug)]
+    pub struct SignatureShare {
+        pub id: ParticipantId,
+        pub(crate) bytes: [u8; 32],
+    }
+
+    /// Round two: consume this signer's nonces and key share to produce a
+    /// signature share over `message`. `commitments` must be the full set
+    /// published by every participant in the signing set during round one.
+    pub fn sign_round2(
+        nonces: SigningNonces,
+        key_share: &KeyShare,
+        message: &[u8],
+        commitments: &[NonceCommitment],
+    ) -> Result<SignatureShare> {
+        if nonces.id != key_share.id {
+            return Err(Web4Error::InvalidInput(
+                "nonce and key share belong to different participants".into(),
+            ));
+        }
+        let participants: Vec<ParticipantId> = commitments.iter().map(|c| c.id).collect();
+        let rho = binding_factor(nonces.id, message, commitments);
+        let r = group_commitment(message, commitments)?;
+        let c = challenge(&r.compress().to_bytes(), &key_share.group_public_key, message);
+        let lambda = lagrange_coefficient(nonces.id, &participants);
+        let z = nonces.hiding + nonces.binding * rho + lambda * key_share.secret_share * c;
+        Ok(SignatureShare {
+            id: nonces.id,
+            bytes: z.to_bytes(),
+        })
+    }
+
+    /// Sum every signer's verified share into a single standard Ed25519
+    /// signature over `message`, verifiable with [`PublicKey::verify`]
+    /// against `group_public_key`.
+    ///
+    /// Rejects any share whose `g^{zᵢ}` does not equal `Rᵢ · (g^{sᵢ})^{λᵢ·c}`
+    /// (`Rᵢ` being that signer's own commitment contribution) before it can
+    /// corrupt the aggregate.
+    pub fn aggregate(
+        message: &[u8],
+        commitments: &[NonceCommitment],
+        shares: &[SignatureShare],
+        verification_shares: &BTreeMap<ParticipantId, [u8; 32]>,
+        group_public_key: &[u8; 32],
+    ) -> Result<SignatureBytes> {
+        let participants: Vec<ParticipantId> = commitments.iter().map(|c| c.id).collect();
+        let r = group_commitment(message, commitments)?;
+        let r_bytes = r.compress().to_bytes();
+        let c = challenge(&r_bytes, group_public_key, message);
+
+        let mut z_sum = Scalar::ZERO;
+        for share in shares {
+            let commitment = commitments
+                .iter()
+                .find(|cmt| cmt.id == share.id)
+                .ok_or_else(|| {
+                    Web4Error::InvalidInput(format!(
+                        "no commitment published for participant {}",
+                        share.id
+                    ))
+                })?;
+            let verification_share = verification_shares.get(&share.id).ok_or_else(|| {
+                Web4Error::InvalidInput(format!(
+                    "no verification share for participant {}",
+                    share.id
+                ))
+            })?;
+
+            let rho = binding_factor(share.id, message, commitments);
+            let r_i = decompress(&commitment.hiding)? + rho * decompress(&commitment.binding)?;
+            let lambda = lagrange_coefficient(share.id, &participants);
+            let vshare_point = decompress(verification_share)?;
+
+            let z_i: Scalar = Option::from(Scalar::from_canonical_bytes(share.bytes))
+                .ok_or_else(|| {
+                    Web4Error::SignatureInvalid(format!(
+                        "signature share from participant {} is not a canonical scalar",
+                        share.id
+                    ))
+                })?;
+            let lhs = EdwardsPoint::mul_base(&z_i);
+            let rhs = r_i + vshare_point * (lambda * c);
+            if lhs.compress() != rhs.compress() {
+                return Err(Web4Error::SignatureInvalid(format!(
+                    "signature share from participant {} failed verification",
+                    share.id
+                )));
+            }
+            z_sum += z_i;
+        }
+
+        let mut sig_bytes = [0u8; 64];
+        sig_bytes[..32].copy_from_slice(&r_bytes);
+        sig_bytes[32..].copy_from_slice(&z_sum.to_bytes());
+        Ok(SignatureBytes::from_bytes(sig_bytes))
+    }
+
+    /// Convenience: reconstruct the group [`PublicKey`] from the bytes every
+    /// [`KeyShare`] carries, so callers can verify an aggregated signature
+    /// with [`PublicKey::verify`] without separately tracking the group key.
+    pub fn group_public_key(key_share: &KeyShare) -> Result<PublicKey> {
+        PublicKey::from_bytes(&key_share.group_public_key)
+    }
+}
+
+/// Pluggable signature algorithms alongside the crate's default Ed25519.
+///
+/// [`KeyPair`]/[`PublicKey`]/[`SignatureBytes`] stay concrete, fixed-width
+/// Ed25519 types on purpose: `scale.rs` bakes their 32/64-byte layout
+/// directly into its SCALE `Encode`/`Decode` impls (consensus-critical wire
+/// format), and `lct.rs`/`attestation.rs`/`transparency.rs`/`group_key.rs`
+/// all assume a single key type throughout. Turning those into enums would
+/// ripple a breaking wire-format change through every caller. Instead, this
+/// module adds a parallel, opt-in [`AnyKeyPair`]/[`AnyPublicKey`]/
+/// [`AnySignature`] family for entities that must speak a second scheme —
+/// starting with ECDSA over NIST P-256, for hardware elements and WebCrypto
+/// backends that don't support Ed25519. Ed25519 remains the default; callers
+/// who never touch this module see no change at all.
+#[cfg(feature = "ecdsa-p256")]
+pub mod multi {
+    use super::{hex, PublicKey, SignatureBytes};
+    use crate::error::{Result, Web4Error};
+    use p256::ecdsa::signature::{Signer as _, Verifier as _};
+    use p256::ecdsa::{Signature as P256Signature, SigningKey as P256SigningKey, VerifyingKey as P256VerifyingKey};
+    use rand::rngs::OsRng;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// A serialized-form identifier for a [`SignatureAlgorithm`], embedded as
+    /// the `alg` tag on [`AnyPublicKey`]'s serde form. [`AnySignature`] has no
+    /// serde form.
+    pub trait SignatureAlgorithm {
+        /// Short identifier used in the `alg` tag (e.g. `"ed25519"`).
+        const ALG_ID: &'static str;
+        /// Length, in bytes, of a serialized public key for this scheme.
+        const PUBLIC_KEY_LEN: usize;
+    }
+
+    /// Marker type for the crate's default scheme.
+    pub struct Ed25519Scheme;
+
+    impl SignatureAlgorithm for Ed25519Scheme {
+        const ALG_ID: &'static str = "ed25519";
+        const PUBLIC_KEY_LEN: usize = 32;
+    }
+
+    /// Marker type for ECDSA over NIST P-256 (SEC1-compressed public keys).
+    pub struct EcdsaP256Scheme;
+
+    impl SignatureAlgorithm for EcdsaP256Scheme {
+        const ALG_ID: &'static str = "ecdsa-p256";
+        const PUBLIC_KEY_LEN: usize = 33;
+    }
+
+    /// A keypair for any supported [`SignatureAlgorithm`].
+    pub enum AnyKeyPair {
+        Ed25519(super::KeyPair),
+        EcdsaP256(P256SigningKey),
+    }
+
+    impl AnyKeyPair {
+        /// Generate a new random Ed25519 keypair.
+        pub fn generate_ed25519() -> Self {
+            Self::Ed25519(super::KeyPair::generate())
+        }
+
+        /// Generate a new random ECDSA P-256 keypair.
+        pub fn generate_ecdsa_p256() -> Self {
+            Self::EcdsaP256(P256SigningKey::random(&mut OsRng))
+        }
+
+        /// The algorithm identifier of this keypair and of the signatures it
+        /// produces.
+        pub fn algorithm_id(&self) -> &'static str {
+            match self {
+                Self::Ed25519(_) => Ed25519Scheme::ALG_ID,
+                Self::EcdsaP256(_) => EcdsaP256Scheme::ALG_ID,
+            }
+        }
+
+        /// The public half of this keypair.
+        pub fn public_key(&self) -> AnyPublicKey {
+            match self {
+                Self::Ed25519(kp) => AnyPublicKey::Ed25519(kp.verifying_key()),
+                Self::EcdsaP256(sk) => {
+                    let point = sk.verifying_key().to_encoded_point(true);
+                    let mut bytes = [0u8; 33];
+                    bytes.copy_from_slice(point.as_bytes());
+                    AnyPublicKey::EcdsaP256(bytes)
+                }
+            }
+        }
+
+        /// Sign a message, producing a signature tagged with this keypair's
+        /// algorithm.
+        pub fn sign(&self, message: &[u8]) -> AnySignature {
+            match self {
+                Self::Ed25519(kp) => AnySignature::Ed25519(kp.sign(message)),
+                Self::EcdsaP256(sk) => {
+                    let signature: P256Signature = sk.sign(message);
+                    let mut bytes = [0u8; 64];
+                    bytes.copy_from_slice(&signature.to_bytes());
+                    AnySignature::EcdsaP256(bytes)
+                }
+            }
+        }
+    }
+
+    /// A public key for any supported [`SignatureAlgorithm`].
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub enum AnyPublicKey {
+        Ed25519(PublicKey),
+        EcdsaP256([u8; 33]),
+    }
+
+    impl AnyPublicKey {
+        /// The `alg` tag this key is serialized under.
+        pub fn algorithm_id(&self) -> &'static str {
+            match self {
+                Self::Ed25519(_) => Ed25519Scheme::ALG_ID,
+                Self::EcdsaP256(_) => EcdsaP256Scheme::ALG_ID,
+            }
+        }
+
+        /// Verify a signature, rejecting any mismatch between the key's and
+        /// the signature's algorithm as a verification failure rather than a
+        /// panic.
+        pub fn verify(&self, message: &[u8], signature: &AnySignature) -> Result<()> {
+            match (self, signature) {
+                (Self::Ed25519(key), AnySignature::Ed25519(sig)) => key.verify(message, sig),
+                (Self::EcdsaP256(key_bytes), AnySignature::EcdsaP256(sig_bytes)) => {
+                    let verifying_key = P256VerifyingKey::from_sec1_bytes(key_bytes)
+                        .map_err(|e| Web4Error::Crypto(format!("Invalid P-256 public key: {}", e)))?;
+                    let sig = P256Signature::from_slice(sig_bytes)
+                        .map_err(|e| Web4Error::Crypto(format!("Invalid P-256 signature: {}", e)))?;
+                    verifying_key
+                        .verify(message, &sig)
+                        .map_err(|e| Web4Error::SignatureInvalid(format!("{}", e)))
+                }
+                _ => Err(Web4Error::SignatureInvalid(
+                    "signature algorithm does not match public key algorithm".into(),
+                )),
+            }
+        }
+    }
+
+    impl Serialize for AnyPublicKey {
+        fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let key_hex = match self {
+                Self::Ed25519(key) => hex::encode(&key.to_bytes()),
+                Self::EcdsaP256(bytes) => hex::encode(bytes),
+            };
+            TaggedKey { alg: self.algorithm_id().to_string(), key: key_hex }.serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for AnyPublicKey {
+        fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let tagged = TaggedKey::deserialize(deserializer)?;
+            let bytes = hex::decode(&tagged.key).map_err(serde::de::Error::custom)?;
+            match tagged.alg.as_str() {
+                "ed25519" => {
+                    let arr: [u8; 32] = bytes
+                        .try_into()
+                        .map_err(|_| serde::de::Error::custom("Invalid ed25519 public key length"))?;
+                    let key = PublicKey::from_bytes(&arr).map_err(serde::de::Error::custom)?;
+                    Ok(Self::Ed25519(key))
+                }
+                "ecdsa-p256" => {
+                    let arr: [u8; 33] = bytes
+                        .try_into()
+                        .map_err(|_| serde::de::Error::custom("Invalid P-256 public key length"))?;
+                    Ok(Self::EcdsaP256(arr))
+                }
+                other => Err(serde::de::Error::custom(format!("Unknown signature algorithm: {}", other))),
+            }
+        }
+    }
+
+    /// A signature for any supported [`SignatureAlgorithm`].
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub enum AnySignature {
+        Ed25519(SignatureBytes),
+        EcdsaP256([u8; 64]),
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct TaggedKey {
+        alg: String,
+        key: String,
+    }
+}
+
+/// Hierarchical deterministic Ed25519 keys, derived SLIP-0010 style.
+///
+/// Lets an operator hold one master seed and deterministically recover a
+/// distinct signing key per entity id, instead of generating and separately
+/// storing a [`KeyPair`] per entity. Ed25519 has no usable notion of a
+/// public-key-only ("non-hardened") child derivation the way secp256k1 does
+/// via point addition, so every child derived here is *hardened*: computing
+/// it always requires the parent's private key material, never just its
+/// public key.
+pub mod hd {
+    use super::{sha256, sha256_hex, KeyPair, PublicKey};
+    use sha2::{Digest, Sha512};
+
+    /// `I = HMAC-SHA512(key, message)`, split by the caller into a 32-byte
+    /// secret and a 32-byte chain code.
+    fn hmac_sha512(key: &[u8], message: &[u8]) -> [u8; 64] {
+        const BLOCK_SIZE: usize = 128;
+
+        let mut key_block = [0u8; BLOCK_SIZE];
+        if key.len() > BLOCK_SIZE {
+            let hashed = Sha512::digest(key);
+            key_block[..64].copy_from_slice(&hashed);
+        } else {
+            key_block[..key.len()].copy_from_slice(key);
+        }
+
+        let mut ipad = [0x36u8; BLOCK_SIZE];
+        let mut opad = [0x5cu8; BLOCK_SIZE];
+        for i in 0..BLOCK_SIZE {
+            ipad[i] ^= key_block[i];
+            opad[i] ^= key_block[i];
+        }
+
+        let mut inner = Sha512::new();
+        inner.update(ipad);
+        inner.update(message);
+        let inner_digest = inner.finalize();
+
+        let mut outer = Sha512::new();
+        outer.update(opad);
+        outer.update(inner_digest);
+        outer.finalize().into()
+    }
+
+    fn split(i: [u8; 64]) -> ([u8; 32], [u8; 32]) {
+        let mut secret = [0u8; 32];
+        let mut chain_code = [0u8; 32];
+        secret.copy_from_slice(&i[..32]);
+        chain_code.copy_from_slice(&i[32..]);
+        (secret, chain_code)
+    }
+
+    /// A node in an Ed25519 hierarchical deterministic key tree: a signing
+    /// keypair plus the chain code needed to derive its hardened children.
+    #[derive(Clone)]
+    pub struct ExtendedKeyPair {
+        keypair: KeyPair,
+        chain_code: [u8; 32],
+    }
+
+    impl ExtendedKeyPair {
+        /// Derive the master extended keypair from an arbitrary-length seed.
+        pub fn from_seed(seed: &[u8]) -> Self {
+            let (secret, chain_code) = split(hmac_sha512(b"ed25519 seed", seed));
+            Self { keypair: KeyPair::from_secret_bytes(&secret), chain_code }
+        }
+
+        /// Derive the hardened child at `index` (the hardened bit is set
+        /// automatically; pass the unhardened index, e.g. `0`, `1`, `2`, ...).
+        pub fn derive_child(&self, index: u32) -> Self {
+            let hardened_index = index | 0x8000_0000;
+            let mut data = [0u8; 37];
+            data[0] = 0x00;
+            data[1..33].copy_from_slice(&self.keypair.secret_key_bytes());
+            data[33..].copy_from_slice(&hardened_index.to_be_bytes());
+
+            let (secret, chain_code) = split(hmac_sha512(&self.chain_code, &data));
+            Self { keypair: KeyPair::from_secret_bytes(&secret), chain_code }
+        }
+
+        /// Derive the descendant reached by applying [`derive_child`](Self::derive_child)
+        /// for each index in `path`, in order.
+        pub fn derive_path(&self, path: &[u32]) -> Self {
+            path.iter().fold(self.clone(), |node, &index| node.derive_child(index))
+        }
+
+        /// Deterministically derive the per-entity keypair for `entity_id`:
+        /// hashes the id into a path of hardened indices so the same id
+        /// always yields the same keypair from the same master seed.
+        pub fn derive_for_entity(&self, entity_id: &str) -> KeyPair {
+            let digest = sha256(entity_id.as_bytes());
+            let path: Vec<u32> = digest
+                .chunks_exact(4)
+                .take(4)
+                .map(|chunk| u32::from_be_bytes(chunk.try_into().expect("chunk is 4 bytes")))
+                .collect();
+            self.derive_path(&path).keypair
+        }
+
+        /// This node's keypair, for signing directly at this point in the tree.
+        pub fn keypair(&self) -> &KeyPair {
+            &self.keypair
+        }
+
+        /// This node's public key.
+        pub fn public_key(&self) -> PublicKey {
+            self.keypair.verifying_key()
+        }
+
+        /// A short fingerprint over this node's public key and chain code,
+        /// for addressing nodes in the tree without exposing key material.
+        pub fn fingerprint(&self) -> String {
+            let mut data = Vec::with_capacity(64);
+            data.extend_from_slice(&self.public_key().to_bytes());
+            data.extend_from_slice(&self.chain_code);
+            sha256_hex(&data)[..16].to_string()
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -261,4 +1013,262 @@ mod tests {
 
         assert_eq!(public, recovered);
     }
+
+    #[test]
+    fn test_public_key_hex_roundtrip() {
+        let kp = KeyPair::generate();
+        let public = kp.verifying_key();
+
+        let recovered = PublicKey::from_hex(&public.to_hex()).unwrap();
+        assert_eq!(public, recovered);
+    }
+
+    #[test]
+    fn test_public_key_multibase_roundtrip() {
+        let kp = KeyPair::generate();
+        let public = kp.verifying_key();
+
+        let multibase = public.to_multibase();
+        assert!(multibase.starts_with('z'));
+
+        let recovered = PublicKey::from_multibase(&multibase).unwrap();
+        assert_eq!(public, recovered);
+    }
+
+    #[test]
+    fn test_signature_multibase_roundtrip() {
+        let kp = KeyPair::generate();
+        let signature = kp.sign(b"Hello, Web4!");
+
+        let multibase = signature.to_multibase();
+        assert!(multibase.starts_with('z'));
+
+        let recovered = SignatureBytes::from_multibase(&multibase).unwrap();
+        assert_eq!(signature, recovered);
+    }
+
+    #[test]
+    fn test_base58btc_roundtrip() {
+        let cases: Vec<Vec<u8>> = vec![
+            vec![],
+            vec![0],
+            vec![0, 0, 1],
+            (0..=255u8).collect(),
+        ];
+        for bytes in cases {
+            let encoded = base58btc::encode(&bytes);
+            let decoded = base58btc::decode(&encoded).unwrap();
+            assert_eq!(decoded, bytes);
+        }
+    }
+
+    #[test]
+    fn test_multibase_rejects_unknown_prefix() {
+        assert!(multibase::decode("qabc").is_err());
+    }
+
+    #[test]
+    fn test_frost_threshold_signature_verifies_as_ed25519() {
+        use frost::*;
+        use std::collections::BTreeMap;
+
+        let secret = KeyPair::generate().secret_key_bytes();
+        let shares = trusted_dealer_keygen(&secret, 5, 3).unwrap();
+        let group_key = group_public_key(&shares[0]).unwrap();
+
+        // Signers 1, 3, 5 (a subset, not the full group) co-sign.
+        let signing_ids = [1u16, 3, 5];
+        let message = b"quorum witness statement";
+
+        let mut nonces = Vec::new();
+        let mut commitments = Vec::new();
+        for &id in &signing_ids {
+            let (n, c) = commit(id);
+            nonces.push(n);
+            commitments.push(c);
+        }
+
+        let mut sig_shares = Vec::new();
+        for nonce in nonces {
+            let key_share = shares.iter().find(|s| s.id == nonce.id).unwrap();
+            sig_shares.push(sign_round2(nonce, key_share, message, &commitments).unwrap());
+        }
+
+        let verification_shares: BTreeMap<ParticipantId, [u8; 32]> = shares
+            .iter()
+            .filter(|s| signing_ids.contains(&s.id))
+            .map(|s| (s.id, s.verification_share))
+            .collect();
+
+        let signature = aggregate(
+            message,
+            &commitments,
+            &sig_shares,
+            &verification_shares,
+            &shares[0].group_public_key,
+        )
+        .unwrap();
+
+        assert!(group_key.verify(message, &signature).is_ok());
+        assert!(group_key.verify(b"different message", &signature).is_err());
+    }
+
+    #[test]
+    fn test_frost_rejects_corrupted_signature_share() {
+        use frost::*;
+        use std::collections::BTreeMap;
+
+        let secret = KeyPair::generate().secret_key_bytes();
+        let shares = trusted_dealer_keygen(&secret, 3, 2).unwrap();
+        let signing_ids = [1u16, 2];
+        let message = b"quorum witness statement";
+
+        let mut nonces = Vec::new();
+        let mut commitments = Vec::new();
+        for &id in &signing_ids {
+            let (n, c) = commit(id);
+            nonces.push(n);
+            commitments.push(c);
+        }
+
+        let mut sig_shares: Vec<SignatureShare> = nonces
+            .into_iter()
+            .map(|nonce| {
+                let key_share = shares.iter().find(|s| s.id == nonce.id).unwrap();
+                sign_round2(nonce, key_share, message, &commitments).unwrap()
+            })
+            .collect();
+
+        // Corrupt one signer's share.
+        sig_shares[0].bytes[0] ^= 0xFF;
+
+        let verification_shares: BTreeMap<ParticipantId, [u8; 32]> = shares
+            .iter()
+            .filter(|s| signing_ids.contains(&s.id))
+            .map(|s| (s.id, s.verification_share))
+            .collect();
+
+        let result = aggregate(
+            message,
+            &commitments,
+            &sig_shares,
+            &verification_shares,
+            &shares[0].group_public_key,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_frost_rejects_invalid_threshold() {
+        let secret = [7u8; 32];
+        assert!(frost::trusted_dealer_keygen(&secret, 3, 0).is_err());
+        assert!(frost::trusted_dealer_keygen(&secret, 3, 4).is_err());
+    }
+
+    #[cfg(feature = "ecdsa-p256")]
+    #[test]
+    fn test_any_key_pair_ed25519_sign_verify() {
+        use multi::{AnyKeyPair, SignatureAlgorithm};
+
+        let kp = AnyKeyPair::generate_ed25519();
+        assert_eq!(kp.algorithm_id(), multi::Ed25519Scheme::ALG_ID);
+
+        let message = b"Hello, Web4!";
+        let signature = kp.sign(message);
+        assert!(kp.public_key().verify(message, &signature).is_ok());
+    }
+
+    #[cfg(feature = "ecdsa-p256")]
+    #[test]
+    fn test_any_key_pair_ecdsa_p256_sign_verify() {
+        use multi::{AnyKeyPair, SignatureAlgorithm};
+
+        let kp = AnyKeyPair::generate_ecdsa_p256();
+        assert_eq!(kp.algorithm_id(), multi::EcdsaP256Scheme::ALG_ID);
+
+        let message = b"Hello, Web4!";
+        let signature = kp.sign(message);
+        assert!(kp.public_key().verify(message, &signature).is_ok());
+        assert!(kp.public_key().verify(b"different message", &signature).is_err());
+    }
+
+    #[cfg(feature = "ecdsa-p256")]
+    #[test]
+    fn test_any_public_key_serde_roundtrip() {
+        use multi::AnyKeyPair;
+
+        let kp = AnyKeyPair::generate_ecdsa_p256();
+        let public = kp.public_key();
+
+        let json = serde_json::to_string(&public).unwrap();
+        assert!(json.contains("ecdsa-p256"));
+
+        let recovered: multi::AnyPublicKey = serde_json::from_str(&json).unwrap();
+        assert_eq!(public, recovered);
+    }
+
+    #[cfg(feature = "ecdsa-p256")]
+    #[test]
+    fn test_any_signature_algorithm_mismatch_rejected() {
+        use multi::AnyKeyPair;
+
+        let ed25519_kp = AnyKeyPair::generate_ed25519();
+        let p256_kp = AnyKeyPair::generate_ecdsa_p256();
+
+        let message = b"cross-algorithm check";
+        let signature = p256_kp.sign(message);
+        assert!(ed25519_kp.public_key().verify(message, &signature).is_err());
+    }
+
+    #[test]
+    fn test_hd_derivation_is_deterministic() {
+        use hd::ExtendedKeyPair;
+
+        let master = ExtendedKeyPair::from_seed(b"test master seed");
+        let a = master.derive_for_entity("mcp:filesystem");
+        let b = master.derive_for_entity("mcp:filesystem");
+
+        assert_eq!(a.public_key_bytes(), b.public_key_bytes());
+    }
+
+    #[test]
+    fn test_hd_derivation_differs_per_entity() {
+        use hd::ExtendedKeyPair;
+
+        let master = ExtendedKeyPair::from_seed(b"test master seed");
+        let a = master.derive_for_entity("mcp:filesystem");
+        let b = master.derive_for_entity("mcp:github");
+
+        assert_ne!(a.public_key_bytes(), b.public_key_bytes());
+    }
+
+    #[test]
+    fn test_hd_derived_keypair_signs_and_verifies() {
+        use hd::ExtendedKeyPair;
+
+        let master = ExtendedKeyPair::from_seed(b"test master seed");
+        let child = master.derive_for_entity("human:alice");
+
+        let message = b"Hello, Web4!";
+        let signature = child.sign(message);
+        assert!(child.verifying_key().verify(message, &signature).is_ok());
+    }
+
+    #[test]
+    fn test_hd_different_seeds_yield_different_masters() {
+        use hd::ExtendedKeyPair;
+
+        let a = ExtendedKeyPair::from_seed(b"seed one").public_key();
+        let b = ExtendedKeyPair::from_seed(b"seed two").public_key();
+        assert_ne!(a.to_bytes(), b.to_bytes());
+    }
+
+    #[test]
+    fn test_hd_fingerprint_is_stable() {
+        use hd::ExtendedKeyPair;
+
+        let master = ExtendedKeyPair::from_seed(b"test master seed");
+        assert_eq!(master.fingerprint(), master.fingerprint());
+        assert_eq!(master.fingerprint().len(), 16);
+    }
 }
@@ -48,6 +48,14 @@ pub enum Web4Error {
     /// LCT has been voided or slashed
     #[error("LCT voided: {0}")]
     LctVoided(String),
+
+    /// DICE/BCC hardware attestation chain failed verification
+    #[error("Attestation chain invalid: {0}")]
+    AttestationInvalid(String),
+
+    /// Transparency-log inclusion or consistency proof failed verification
+    #[error("Transparency log proof invalid: {0}")]
+    TransparencyInvalid(String),
 }
 
 /// Result type alias for web4-core operations
@@ -0,0 +1,371 @@
+// Copyright (c) 2026 MetaLINXX Inc.
+// SPDX-License-Identifier: AGPL-3.0-only
+//
+// This software is covered by US Patents 11,477,027 and 12,278,913,
+// and pending application 19/178,619. A royalty-free license is granted
+// under AGPL-3.0 terms for non-commercial and research use.
+// For commercial licensing: dp@metalinxx.io
+// See PATENTS.md for details.
+
+//! TreeKEM-style group key agreement for `Organization`/`Role` LCTs.
+//!
+//! An Organization or Role's child LCTs are its members, but nothing ties
+//! them to a shared secret that survives membership changes confidentially.
+//! [`GroupKeyTree`] gives them one, modeled on MLS/TreeKEM (RFC 9420):
+//! members occupy the leaves of a binary tree (array-indexed as a complete
+//! binary heap — node `0` is the root, node `i`'s children are `2i+1` and
+//! `2i+2`, and the last `capacity` slots are leaves), each internal node's
+//! secret is derived by hashing its two children together, and the root is
+//! the group's current epoch secret. [`GroupKeyTree::commit`] applies any
+//! staged [`add_member`](GroupKeyTree::add_member)/[`remove_member`](GroupKeyTree::remove_member)
+//! calls, re-deriving only the direct path from each changed leaf to the
+//! root (the rest of the tree is untouched, unless the tree has to grow to
+//! fit new members, which re-derives every node) and advancing the epoch
+//! counter — so a member who leaves can no longer compute the new epoch
+//! secret (forward secrecy) and a member who (re-)joins can't compute past
+//! ones, while other members' path secrets ratchet forward too (partial
+//! post-compromise security, the direct benefit of "hashing up the tree"
+//! from a freshly-random leaf).
+//!
+//! # Limitation: no HPKE
+//!
+//! Real TreeKEM encrypts each new path secret to every member on its
+//! copath with HPKE (an asymmetric KEM), so only those members — not an
+//! eavesdropper holding ciphertext — can derive it. This crate has no KEM
+//! primitive (only Ed25519 signing and SHA-256), so path secrets here are
+//! plain values the tree holder computes and would need to distribute to
+//! members out-of-band over an already-secure channel. The tree shape,
+//! direct-path-only updates, and epoch ratchet are faithful to TreeKEM;
+//! the copath-encryption step — the part that lets members who only see
+//! ciphertext still derive the epoch secret — is not implemented.
+
+use std::collections::HashSet;
+
+use uuid::Uuid;
+
+use crate::crypto::{sha256, KeyPair, PublicKey};
+use crate::error::{Result, Web4Error};
+use crate::lct::{EntityType, Lct};
+
+/// A committed member's leaf: their LCT id and public key. The tree itself
+/// owns the derived secrets (see [`GroupKeyTree::secrets`]); members are
+/// not responsible for deriving them from scratch.
+#[derive(Clone, Debug)]
+struct Member {
+    id: Uuid,
+    #[allow(dead_code)]
+    public_key: PublicKey,
+}
+
+/// The smallest power of two `>= n.max(1)`.
+fn next_pow2(n: usize) -> usize {
+    let mut cap = 1;
+    while cap < n.max(1) {
+        cap *= 2;
+    }
+    cap
+}
+
+/// A fresh, random leaf secret for a newly (re-)occupied leaf.
+fn fresh_leaf_secret() -> [u8; 32] {
+    KeyPair::generate().secret_key_bytes()
+}
+
+/// Derive a parent node's secret from its two children.
+fn derive_parent_secret(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut buf = Vec::with_capacity(64);
+    buf.extend_from_slice(left);
+    buf.extend_from_slice(right);
+    sha256(&buf)
+}
+
+fn parent(i: usize) -> usize {
+    (i - 1) / 2
+}
+
+fn left_child(i: usize) -> usize {
+    2 * i + 1
+}
+
+fn right_child(i: usize) -> usize {
+    2 * i + 2
+}
+
+/// A TreeKEM-style ratchet tree establishing a shared epoch secret for an
+/// `Organization`/`Role` LCT's child members. See the module docs for the
+/// tree construction and its gap versus real TreeKEM.
+#[derive(Clone, Debug)]
+pub struct GroupKeyTree {
+    owner_id: Uuid,
+    /// Leaf slots, `None` where blank (never occupied, or vacated by a
+    /// `remove_member`). Indices are stable across commits that don't
+    /// change `capacity`, so a member's position — and the cost of
+    /// updating it — doesn't shift when an unrelated member joins/leaves.
+    leaves: Vec<Option<Member>>,
+    capacity: usize,
+    /// Heap-indexed node secrets, length `2 * capacity - 1`.
+    secrets: Vec<[u8; 32]>,
+    epoch: u64,
+    pending_adds: Vec<(Uuid, PublicKey)>,
+    pending_removes: Vec<Uuid>,
+}
+
+impl GroupKeyTree {
+    /// Create an empty group keyed to `owner` (must be an `Organization` or
+    /// `Role` LCT).
+    pub fn new(owner: &Lct) -> Result<Self> {
+        match owner.entity_type {
+            EntityType::Organization | EntityType::Role => {}
+            _ => {
+                return Err(Web4Error::InvalidInput(
+                    "group key tree requires an Organization or Role owner LCT".into(),
+                ))
+            }
+        }
+
+        Ok(Self {
+            owner_id: owner.id,
+            leaves: vec![None],
+            capacity: 1,
+            secrets: vec![sha256(owner.id.as_bytes())],
+            epoch: 0,
+            pending_adds: Vec::new(),
+            pending_removes: Vec::new(),
+        })
+    }
+
+    /// Number of occupied member leaves.
+    pub fn member_count(&self) -> usize {
+        self.leaves.iter().filter(|m| m.is_some()).count()
+    }
+
+    /// The current epoch counter, incremented by every [`commit`](Self::commit)
+    /// that applies at least one staged change.
+    pub fn epoch(&self) -> u64 {
+        self.epoch
+    }
+
+    /// The group's current shared secret (the tree's root node).
+    pub fn current_epoch_secret(&self) -> [u8; 32] {
+        self.secrets[0]
+    }
+
+    /// Stage `child_lct` (which must be a child LCT of this group's owner)
+    /// for addition. Takes effect on the next [`commit`](Self::commit).
+    pub fn add_member(&mut self, child_lct: &Lct) -> Result<()> {
+        if child_lct.parent_id != Some(self.owner_id) {
+            return Err(Web4Error::InvalidInput(
+                "member is not a child LCT of this group's owner".into(),
+            ));
+        }
+        if self.leaves.iter().flatten().any(|m| m.id == child_lct.id)
+            || self.pending_adds.iter().any(|(id, _)| *id == child_lct.id)
+        {
+            return Err(Web4Error::InvalidInput(
+                "member is already present or already staged".into(),
+            ));
+        }
+        self.pending_adds
+            .push((child_lct.id, child_lct.public_key.clone()));
+        Ok(())
+    }
+
+    /// Stage the member with `id` for removal. Takes effect on the next
+    /// [`commit`](Self::commit).
+    pub fn remove_member(&mut self, id: Uuid) -> Result<()> {
+        if !self.leaves.iter().flatten().any(|m| m.id == id) {
+            return Err(Web4Error::NotFound(format!("member {} not in group", id)));
+        }
+        self.pending_removes.push(id);
+        Ok(())
+    }
+
+    /// Apply every staged `add_member`/`remove_member` call, re-derive the
+    /// epoch secret, and advance the epoch. A no-op (no epoch change) if
+    /// nothing is staged.
+    ///
+    /// Removed members' leaves are blanked in place — re-randomizing just
+    /// that leaf and its direct path to the root — so a removed member can
+    /// no longer compute the new epoch secret, and every other member's
+    /// position (and the cost of updating it) is unaffected. Added members
+    /// fill existing blank leaves where possible, also updating only their
+    /// own direct path. Only when the tree must grow to fit new members
+    /// does the whole tree re-randomize, since every leaf's position
+    /// shifts in that case.
+    pub fn commit(&mut self) {
+        if self.pending_adds.is_empty() && self.pending_removes.is_empty() {
+            return;
+        }
+
+        let removed: HashSet<Uuid> = self.pending_removes.drain(..).collect();
+        for slot in 0..self.leaves.len() {
+            let is_removed = self.leaves[slot]
+                .as_ref()
+                .map(|m| removed.contains(&m.id))
+                .unwrap_or(false);
+            if is_removed {
+                self.leaves[slot] = None;
+                self.update_leaf(slot, fresh_leaf_secret());
+            }
+        }
+
+        let added: Vec<(Uuid, PublicKey)> = self.pending_adds.drain(..).collect();
+        let needed = self.member_count() + added.len();
+        let new_capacity = next_pow2(needed).max(self.capacity);
+
+        if new_capacity != self.capacity {
+            self.capacity = new_capacity;
+            self.leaves.resize_with(new_capacity, || None);
+            for (id, public_key) in added {
+                let slot = self
+                    .leaves
+                    .iter()
+                    .position(|m| m.is_none())
+                    .expect("capacity covers all members");
+                self.leaves[slot] = Some(Member { id, public_key });
+            }
+            self.rebuild_fresh();
+        } else {
+            for (id, public_key) in added {
+                let slot = self
+                    .leaves
+                    .iter()
+                    .position(|m| m.is_none())
+                    .expect("capacity covers all members");
+                self.leaves[slot] = Some(Member { id, public_key });
+                self.update_leaf(slot, fresh_leaf_secret());
+            }
+        }
+
+        self.epoch += 1;
+    }
+
+    /// Set leaf `slot`'s secret and recompute exactly its direct path to
+    /// the root — the only nodes whose secret depends on that leaf.
+    fn update_leaf(&mut self, slot: usize, new_secret: [u8; 32]) {
+        let mut idx = self.capacity - 1 + slot;
+        self.secrets[idx] = new_secret;
+        while idx != 0 {
+            let p = parent(idx);
+            self.secrets[p] =
+                derive_parent_secret(&self.secrets[left_child(p)], &self.secrets[right_child(p)]);
+            idx = p;
+        }
+    }
+
+    /// Re-randomize every occupied leaf and recompute every internal node
+    /// bottom-up. Used only when `capacity` changes, since every leaf's
+    /// array position shifts in that case.
+    fn rebuild_fresh(&mut self) {
+        self.secrets = vec![[0u8; 32]; 2 * self.capacity - 1];
+
+        for slot in 0..self.capacity {
+            let idx = self.capacity - 1 + slot;
+            self.secrets[idx] = match &self.leaves[slot] {
+                Some(_) => fresh_leaf_secret(),
+                None => sha256(format!("blank-leaf-{}", slot).as_bytes()),
+            };
+        }
+
+        for idx in (0..self.capacity.saturating_sub(1)).rev() {
+            self.secrets[idx] =
+                derive_parent_secret(&self.secrets[left_child(idx)], &self.secrets[right_child(idx)]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lct::EntityType;
+
+    #[test]
+    fn test_new_rejects_non_organization_role_owner() {
+        let (human, _) = Lct::new(EntityType::Human, None);
+        assert!(GroupKeyTree::new(&human).is_err());
+    }
+
+    #[test]
+    fn test_add_member_requires_commit_to_take_effect() {
+        let (org, org_keypair) = Lct::new(EntityType::Organization, None);
+        let (child, _) = org.create_child(EntityType::AiSoftware, &org_keypair);
+        let mut tree = GroupKeyTree::new(&org).unwrap();
+
+        let secret_before = tree.current_epoch_secret();
+        tree.add_member(&child).unwrap();
+        assert_eq!(tree.member_count(), 0);
+        assert_eq!(tree.current_epoch_secret(), secret_before);
+
+        tree.commit();
+        assert_eq!(tree.member_count(), 1);
+        assert_eq!(tree.epoch(), 1);
+        assert_ne!(tree.current_epoch_secret(), secret_before);
+    }
+
+    #[test]
+    fn test_epoch_secret_changes_on_every_commit() {
+        let (org, org_keypair) = Lct::new(EntityType::Organization, None);
+        let mut tree = GroupKeyTree::new(&org).unwrap();
+
+        let mut secrets = vec![tree.current_epoch_secret()];
+        for _ in 0..5 {
+            let (child, _) = org.create_child(EntityType::AiSoftware, &org_keypair);
+            tree.add_member(&child).unwrap();
+            tree.commit();
+            secrets.push(tree.current_epoch_secret());
+        }
+
+        let unique: HashSet<_> = secrets.iter().collect();
+        assert_eq!(unique.len(), secrets.len());
+        assert_eq!(tree.member_count(), 5);
+        assert_eq!(tree.epoch(), 5);
+    }
+
+    #[test]
+    fn test_remove_member_changes_epoch_secret_and_rejects_unknown_member() {
+        let (org, org_keypair) = Lct::new(EntityType::Organization, None);
+        let (child, _) = org.create_child(EntityType::Role, &org_keypair);
+        let mut tree = GroupKeyTree::new(&org).unwrap();
+        tree.add_member(&child).unwrap();
+        tree.commit();
+
+        let secret_before = tree.current_epoch_secret();
+        assert!(tree.remove_member(Uuid::new_v4()).is_err());
+
+        tree.remove_member(child.id).unwrap();
+        tree.commit();
+
+        assert_eq!(tree.member_count(), 0);
+        assert_ne!(tree.current_epoch_secret(), secret_before);
+    }
+
+    #[test]
+    fn test_commit_with_nothing_staged_is_a_no_op() {
+        let (org, _) = Lct::new(EntityType::Organization, None);
+        let mut tree = GroupKeyTree::new(&org).unwrap();
+
+        let secret_before = tree.current_epoch_secret();
+        let epoch_before = tree.epoch();
+        tree.commit();
+
+        assert_eq!(tree.current_epoch_secret(), secret_before);
+        assert_eq!(tree.epoch(), epoch_before);
+    }
+
+    #[test]
+    fn test_unrelated_member_unaffected_by_another_members_removal() {
+        let (org, org_keypair) = Lct::new(EntityType::Organization, None);
+        let (alice, _) = org.create_child(EntityType::AiSoftware, &org_keypair);
+        let (bob, _) = org.create_child(EntityType::AiSoftware, &org_keypair);
+        let mut tree = GroupKeyTree::new(&org).unwrap();
+        tree.add_member(&alice).unwrap();
+        tree.add_member(&bob).unwrap();
+        tree.commit();
+
+        tree.remove_member(alice.id).unwrap();
+        tree.commit();
+
+        assert_eq!(tree.member_count(), 1);
+        assert_eq!(tree.epoch(), 2);
+    }
+}
@@ -16,14 +16,21 @@
 //! be hardware-bound (TPM 2.0, Secure Enclave, TrustZone). Without hardware
 //! binding, LCTs can be copied and identity can be impersonated.
 
+use crate::attestation;
 use crate::crypto::{sha256_hex, KeyPair, PublicKey, SignatureBytes};
 use crate::error::{Result, Web4Error};
+use crate::personhood::HumanUniqueness;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use uuid::Uuid;
 
 /// Entity type that an LCT can represent
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "scale",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode, scale_info::TypeInfo)
+)]
 #[serde(rename_all = "snake_case")]
 pub enum EntityType {
     /// Human user
@@ -46,6 +53,10 @@ pub enum EntityType {
 
 /// LCT status
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "scale",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode, scale_info::TypeInfo)
+)]
 #[serde(rename_all = "snake_case")]
 pub enum LctStatus {
     /// Active and valid
@@ -70,21 +81,79 @@ pub struct HardwareBinding {
     /// Description of the binding
     pub description: String,
 
-    /// Trust ceiling based on binding level
+    /// Self-asserted trust ceiling based on `level`. Used by
+    /// [`Lct::trust_ceiling`], capped at the software-binding ceiling,
+    /// whenever `attestation_chain` is empty or fails to verify; a chain
+    /// that verifies replaces it.
     pub trust_ceiling: f64,
+
+    /// DICE/BCC attestation certs (see [`crate::attestation`]), root-signed
+    /// cert first, proving `level`/`trust_ceiling` rather than just
+    /// asserting them. Empty if this binding carries no hardware evidence.
+    #[serde(default)]
+    pub attestation_chain: Vec<Vec<u8>>,
+
+    /// Public key of the attestation chain's root of trust (e.g. a
+    /// TPM/Secure-Enclave endorsement key), required to verify
+    /// `attestation_chain`. `None` if `attestation_chain` is empty.
+    #[serde(default)]
+    pub root_public_key: Option<PublicKey>,
 }
 
 impl Default for HardwareBinding {
     fn default() -> Self {
-        // Default to software binding (level 4)
+        // Default to software binding (level 4), no attestation evidence
         Self {
             level: 4,
             description: "Software-bound keys (development)".into(),
             trust_ceiling: 0.85,
+            attestation_chain: Vec::new(),
+            root_public_key: None,
         }
     }
 }
 
+/// One hop in an LCT's key-rotation chain: the key being rotated away
+/// from, the key it was rotated to, and proof the old key authorized the
+/// change.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RotationRecord {
+    /// The key being rotated away from.
+    pub previous_key: PublicKey,
+
+    /// The key being rotated to.
+    pub new_key: PublicKey,
+
+    /// Signature over `new_key`'s bytes, produced with `previous_key`'s
+    /// private key.
+    pub proof: SignatureBytes,
+
+    /// When this rotation happened.
+    pub rotated_at: DateTime<Utc>,
+}
+
+/// A cross-signed lineage "shield" for an LCT: whether every hop from it up
+/// to a root was validly cross-signed, as computed by
+/// [`compute_verification_state`]. See [`Lct::verify_lineage`] for the
+/// single-hop check this is built from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VerificationState {
+    /// Every hop up to a root verified, and no ancestor in the chain is
+    /// slashed.
+    Verified,
+    /// This LCT (or an ancestor in the chain) carries no `parent_attestation`
+    /// to check — e.g. it predates cross-signing, or an ancestor is missing
+    /// from the lookup used to walk the chain.
+    Unverified,
+    /// A hop's `parent_attestation` doesn't verify against the claimed
+    /// parent's public key.
+    SignatureMismatch,
+    /// Every checked hop verified, but an ancestor in the chain has been
+    /// slashed.
+    ParentSlashed,
+}
+
 /// Linked Context Token - the fundamental identity primitive
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Lct {
@@ -114,6 +183,42 @@ pub struct Lct {
 
     /// Lineage depth (distance from root)
     pub lineage_depth: u32,
+
+    /// The key this LCT rotated from, if `rotate` has ever been called.
+    /// Together with `rotation_proof`, this is the most recent hop of the
+    /// rotation chain; earlier hops live in `rotation_history`.
+    #[serde(default)]
+    pub previous_key: Option<PublicKey>,
+
+    /// Signature over `public_key`'s bytes, produced with `previous_key`'s
+    /// private key, proving this rotation was authorized by the old key.
+    #[serde(default)]
+    pub rotation_proof: Option<SignatureBytes>,
+
+    /// When the most recent rotation happened. Drives the grace window in
+    /// [`Lct::verify_signature`].
+    #[serde(default)]
+    pub rotated_at: Option<DateTime<Utc>>,
+
+    /// Earlier rotation hops, oldest first. Does not include the most
+    /// recent hop, which lives in `previous_key`/`rotation_proof`.
+    #[serde(default)]
+    pub rotation_history: Vec<RotationRecord>,
+
+    /// Proof-of-personhood evidence (see [`crate::personhood`]), present
+    /// only on `EntityType::Human` LCTs minted through a
+    /// [`crate::personhood::PersonhoodRegistry`]'s uniqueness gate.
+    #[serde(default)]
+    pub human_uniqueness: Option<HumanUniqueness>,
+
+    /// Signature over `public_key || id`, produced with `created_by`'s
+    /// private key when this LCT was minted via [`Lct::create_child`],
+    /// cryptographically binding this LCT to its parent beyond the
+    /// otherwise-forgeable `parent_id`/`created_by` UUIDs. `None` for root
+    /// LCTs and LCTs built directly through [`LctBuilder`]. See
+    /// [`Lct::verify_lineage`].
+    #[serde(default)]
+    pub parent_attestation: Option<SignatureBytes>,
 }
 
 impl Lct {
@@ -134,18 +239,39 @@ impl Lct {
             hardware_binding: HardwareBinding::default(),
             parent_id: None,
             lineage_depth: 0,
+            previous_key: None,
+            rotation_proof: None,
+            rotated_at: None,
+            rotation_history: Vec::new(),
+            human_uniqueness: None,
+            parent_attestation: None,
         };
 
         (lct, keypair)
     }
 
-    /// Create a child LCT under this parent
-    pub fn create_child(&self, entity_type: EntityType) -> (Self, KeyPair) {
+    /// The message a parent signs to cross-sign a child: `public_key ||
+    /// id`. Shared by [`Lct::create_child`] (which signs it) and
+    /// [`Lct::verify_lineage`] (which verifies it).
+    fn lineage_attestation_message(child_public_key: &PublicKey, child_id: Uuid) -> Vec<u8> {
+        let mut message = child_public_key.to_bytes().to_vec();
+        message.extend_from_slice(child_id.as_bytes());
+        message
+    }
+
+    /// Create a child LCT under this parent, cross-signed with
+    /// `parent_keypair` (which must be this LCT's own keypair) so
+    /// [`verify_lineage`](Lct::verify_lineage) can later prove the child
+    /// was really created by this parent, not just labeled as such.
+    pub fn create_child(&self, entity_type: EntityType, parent_keypair: &KeyPair) -> (Self, KeyPair) {
         let keypair = KeyPair::generate();
         let public_key = keypair.verifying_key();
+        let id = Uuid::new_v4();
+        let parent_attestation =
+            parent_keypair.sign(&Self::lineage_attestation_message(&public_key, id));
 
         let lct = Self {
-            id: Uuid::new_v4(),
+            id,
             entity_type,
             status: LctStatus::Active,
             public_key,
@@ -154,6 +280,12 @@ impl Lct {
             hardware_binding: HardwareBinding::default(),
             parent_id: Some(self.id),
             lineage_depth: self.lineage_depth + 1,
+            previous_key: None,
+            rotation_proof: None,
+            rotated_at: None,
+            rotation_history: Vec::new(),
+            human_uniqueness: None,
+            parent_attestation: Some(parent_attestation),
         };
 
         (lct, keypair)
@@ -174,12 +306,189 @@ impl Lct {
         self.status = LctStatus::Slashed;
     }
 
-    /// Get trust ceiling based on hardware binding
+    /// Verify this LCT's DICE/BCC hardware attestation chain: each cert
+    /// signed by its predecessor's attested key, rooted at
+    /// `hardware_binding.root_public_key`, and ending at this LCT's own
+    /// `public_key`.
+    ///
+    /// Vacuously `Ok` if no chain is present — absence of evidence isn't
+    /// evidence of absence, but [`Lct::trust_ceiling`] reflects that by
+    /// never crediting an unattested binding above the legacy
+    /// self-asserted ceiling.
+    pub fn verify_attestation_chain(&self) -> Result<()> {
+        if self.hardware_binding.attestation_chain.is_empty() {
+            return Ok(());
+        }
+
+        let root_public_key = self.hardware_binding.root_public_key.as_ref().ok_or_else(|| {
+            Web4Error::AttestationInvalid(
+                "attestation chain present without a root public key".into(),
+            )
+        })?;
+
+        attestation::verify_attestation_chain(
+            &self.hardware_binding.attestation_chain,
+            root_public_key,
+            &self.public_key,
+        )
+    }
+
+    /// Verify this LCT's proof-of-personhood evidence against a
+    /// registry's current `registry_root`. Checks only the membership
+    /// proof (see [`HumanUniqueness::verify_membership`]) — it does not
+    /// check nullifier freshness, since that requires consulting the
+    /// registry's spent-nullifier set, not just this LCT. A registry
+    /// deciding whether to accept a new `Human` LCT should call
+    /// [`crate::personhood::PersonhoodRegistry::verify_personhood`]
+    /// instead, which checks both.
+    pub fn verify_personhood(&self, registry_root: [u8; 32]) -> Result<()> {
+        self.human_uniqueness
+            .as_ref()
+            .ok_or_else(|| Web4Error::InvalidInput("LCT carries no proof-of-personhood".into()))?
+            .verify_membership(registry_root)
+    }
+
+    /// Verify this LCT's `parent_attestation` against a claimed `parent`:
+    /// that `parent` really signed this LCT's `public_key || id`, and that
+    /// `parent` has not been slashed (a slashed parent's past attestations
+    /// no longer vouch for anything it created). Checks a single hop only
+    /// — see [`compute_verification_state`] for walking a full lineage
+    /// chain up to a root.
+    pub fn verify_lineage(&self, parent: &Lct) -> Result<()> {
+        if self.parent_id != Some(parent.id) {
+            return Err(Web4Error::InvalidInput(
+                "parent LCT id does not match this LCT's parent_id".into(),
+            ));
+        }
+
+        let attestation = self.parent_attestation.as_ref().ok_or_else(|| {
+            Web4Error::InvalidInput("LCT carries no parent attestation".into())
+        })?;
+
+        parent
+            .public_key
+            .verify(
+                &Self::lineage_attestation_message(&self.public_key, self.id),
+                attestation,
+            )
+            .map_err(|e| {
+                Web4Error::SignatureInvalid(format!("parent attestation does not verify: {}", e))
+            })?;
+
+        if parent.status == LctStatus::Slashed {
+            return Err(Web4Error::LctVoided(format!(
+                "parent LCT {} is slashed",
+                parent.id
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Get trust ceiling based on hardware binding.
+    ///
+    /// A `level`-5 binding only earns a ceiling above the software-binding
+    /// default if it carries an `attestation_chain` that actually verifies
+    /// (see [`Lct::verify_attestation_chain`]); the self-asserted
+    /// `hardware_binding.trust_ceiling` is otherwise capped at the
+    /// software-binding level (`0.85`), since it's an unverified claim.
     pub fn trust_ceiling(&self) -> f64 {
-        self.hardware_binding.trust_ceiling
+        const SOFTWARE_CEILING: f64 = 0.85;
+        const VERIFIED_PER_LAYER: f64 = 0.03;
+        const VERIFIED_MAX: f64 = 0.99;
+
+        let chain_len = self.hardware_binding.attestation_chain.len();
+        if chain_len > 0 && self.verify_attestation_chain().is_ok() {
+            (SOFTWARE_CEILING + VERIFIED_PER_LAYER * chain_len as f64).min(VERIFIED_MAX)
+        } else {
+            self.hardware_binding.trust_ceiling.min(SOFTWARE_CEILING)
+        }
+    }
+
+    /// Rotate this LCT's signing key in place, keeping `id` (and all trust
+    /// and lineage accumulated under it) stable. Generates a fresh
+    /// keypair, signs its public key with `old` (which must be the
+    /// current `public_key`'s keypair), and records the hop so
+    /// `verify_rotation_history` can walk the chain back to this LCT's
+    /// original key and `verify_signature` can still accept signatures
+    /// made under the superseded key within the grace window.
+    ///
+    /// Returns the new keypair, which should be securely stored in place
+    /// of `old`.
+    pub fn rotate(&mut self, old: &KeyPair) -> KeyPair {
+        let new_keypair = KeyPair::generate();
+        let new_public_key = new_keypair.verifying_key();
+        let proof = old.sign(&new_public_key.to_bytes());
+        let rotated_at = Utc::now();
+
+        if let (Some(previous_key), Some(rotation_proof), Some(previous_rotated_at)) = (
+            self.previous_key.take(),
+            self.rotation_proof.take(),
+            self.rotated_at,
+        ) {
+            self.rotation_history.push(RotationRecord {
+                previous_key,
+                new_key: self.public_key.clone(),
+                proof: rotation_proof,
+                rotated_at: previous_rotated_at,
+            });
+        }
+
+        self.previous_key = Some(self.public_key.clone());
+        self.rotation_proof = Some(proof);
+        self.rotated_at = Some(rotated_at);
+        self.public_key = new_public_key;
+
+        new_keypair
+    }
+
+    /// Walk this LCT's rotation chain, oldest hop first, verifying each
+    /// `proof` was signed by that hop's `previous_key` over its `new_key`,
+    /// and that each hop's `new_key` feeds into the next hop's
+    /// `previous_key` (the final hop's `new_key` is this LCT's current
+    /// `public_key`). Vacuously `Ok` if this LCT has never been rotated.
+    pub fn verify_rotation_history(&self) -> Result<()> {
+        let mut hops: Vec<(&PublicKey, &PublicKey, &SignatureBytes)> = self
+            .rotation_history
+            .iter()
+            .map(|hop| (&hop.previous_key, &hop.new_key, &hop.proof))
+            .collect();
+
+        if let (Some(previous_key), Some(rotation_proof)) =
+            (&self.previous_key, &self.rotation_proof)
+        {
+            hops.push((previous_key, &self.public_key, rotation_proof));
+        }
+
+        for (i, (previous_key, new_key, proof)) in hops.iter().enumerate() {
+            previous_key.verify(&new_key.to_bytes(), proof).map_err(|e| {
+                Web4Error::LctVoided(format!("rotation hop {} failed verification: {}", i, e))
+            })?;
+
+            if i > 0 {
+                let (_, prior_new_key, _) = hops[i - 1];
+                if prior_new_key != *previous_key {
+                    return Err(Web4Error::LctVoided(format!(
+                        "rotation hop {} does not chain from hop {}'s new key",
+                        i,
+                        i - 1
+                    )));
+                }
+            }
+        }
+
+        Ok(())
     }
 
-    /// Verify a signature from this LCT
+    /// How long a just-superseded key's signatures remain acceptable via
+    /// `verify_signature`'s previous-key fallback.
+    pub const ROTATION_GRACE_PERIOD_HOURS: i64 = 24;
+
+    /// Verify a signature from this LCT. If the signature doesn't match
+    /// the current `public_key` but this LCT rotated within the last
+    /// [`Lct::ROTATION_GRACE_PERIOD_HOURS`] hours, also accepts a
+    /// signature made under the immediately preceding key — so in-flight
+    /// messages signed just before a rotation still verify.
     pub fn verify_signature(&self, message: &[u8], signature: &SignatureBytes) -> Result<()> {
         if !self.is_active() {
             return Err(Web4Error::LctVoided(format!(
@@ -187,7 +496,22 @@ impl Lct {
                 self.id, self.status
             )));
         }
-        self.public_key.verify(message, signature)
+
+        match self.public_key.verify(message, signature) {
+            Ok(()) => Ok(()),
+            Err(current_err) => {
+                if let (Some(previous_key), Some(rotated_at)) =
+                    (&self.previous_key, self.rotated_at)
+                {
+                    let within_grace = Utc::now() - rotated_at
+                        < chrono::Duration::hours(Self::ROTATION_GRACE_PERIOD_HOURS);
+                    if within_grace {
+                        return previous_key.verify(message, signature);
+                    }
+                }
+                Err(current_err)
+            }
+        }
     }
 
     /// Get the LCT fingerprint (short identifier for display)
@@ -213,12 +537,44 @@ impl Lct {
     }
 }
 
+/// Compute a lineage-wide trust "shield" for `lct`: walk `parent_id` links
+/// (looking each ancestor up in `registry`, keyed by LCT id) up to a root,
+/// checking [`Lct::verify_lineage`] at every hop. See [`VerificationState`]
+/// for what each outcome means.
+pub fn compute_verification_state(lct: &Lct, registry: &HashMap<Uuid, Lct>) -> VerificationState {
+    let mut current = lct;
+    let mut ancestor_slashed = false;
+
+    while let Some(parent_id) = current.parent_id {
+        let parent = match registry.get(&parent_id) {
+            Some(parent) => parent,
+            None => return VerificationState::Unverified,
+        };
+
+        match current.verify_lineage(parent) {
+            Ok(()) => {}
+            Err(Web4Error::LctVoided(_)) => ancestor_slashed = true,
+            Err(Web4Error::SignatureInvalid(_)) => return VerificationState::SignatureMismatch,
+            Err(_) => return VerificationState::Unverified,
+        }
+
+        current = parent;
+    }
+
+    if ancestor_slashed {
+        VerificationState::ParentSlashed
+    } else {
+        VerificationState::Verified
+    }
+}
+
 /// Builder for creating LCTs with custom configuration
 pub struct LctBuilder {
     entity_type: EntityType,
     created_by: Option<Uuid>,
     parent_id: Option<Uuid>,
     hardware_binding: Option<HardwareBinding>,
+    human_uniqueness: Option<HumanUniqueness>,
 }
 
 impl LctBuilder {
@@ -228,6 +584,7 @@ impl LctBuilder {
             created_by: None,
             parent_id: None,
             hardware_binding: None,
+            human_uniqueness: None,
         }
     }
 
@@ -246,6 +603,16 @@ impl LctBuilder {
         self
     }
 
+    /// Attach proof-of-personhood evidence (see [`crate::personhood`]),
+    /// normally used only when building an `EntityType::Human` LCT under a
+    /// registry's uniqueness gate. `build` stores it as-is; callers who
+    /// need the uniqueness gate actually enforced should additionally run
+    /// it through a [`crate::personhood::PersonhoodRegistry::verify_personhood`].
+    pub fn human_uniqueness(mut self, uniqueness: HumanUniqueness) -> Self {
+        self.human_uniqueness = Some(uniqueness);
+        self
+    }
+
     pub fn build(self) -> (Lct, KeyPair) {
         let keypair = KeyPair::generate();
         let public_key = keypair.verifying_key();
@@ -260,10 +627,34 @@ impl LctBuilder {
             hardware_binding: self.hardware_binding.unwrap_or_default(),
             parent_id: self.parent_id,
             lineage_depth: if self.parent_id.is_some() { 1 } else { 0 },
+            previous_key: None,
+            rotation_proof: None,
+            rotated_at: None,
+            rotation_history: Vec::new(),
+            human_uniqueness: self.human_uniqueness,
+            parent_attestation: None,
         };
 
         (lct, keypair)
     }
+
+    /// Like [`build`](Self::build), but also appends the new LCT's leaf
+    /// (see [`crate::transparency::lct_leaf`]) to `log` and returns its
+    /// leaf index and inclusion proof, so verifiers can confirm this LCT
+    /// was logged — and is therefore subject to equivocation detection —
+    /// before any trust accrues to it.
+    pub fn build_with_log(
+        self,
+        log: &mut crate::transparency::LctTransparencyLog,
+    ) -> (Lct, KeyPair, usize, crate::transparency::InclusionProof) {
+        let (lct, keypair) = self.build();
+        let leaf = crate::transparency::lct_leaf(&lct.public_key, lct.id, lct.created_by);
+        let leaf_index = log.append(leaf);
+        let proof = log
+            .prove_inclusion(leaf_index)
+            .expect("just-appended leaf index is always in range");
+        (lct, keypair, leaf_index, proof)
+    }
 }
 
 #[cfg(test)]
@@ -282,8 +673,8 @@ mod tests {
 
     #[test]
     fn test_child_lct() {
-        let (parent, _) = Lct::new(EntityType::Organization, None);
-        let (child, _) = parent.create_child(EntityType::Role);
+        let (parent, parent_keypair) = Lct::new(EntityType::Organization, None);
+        let (child, _) = parent.create_child(EntityType::Role, &parent_keypair);
 
         assert_eq!(child.parent_id, Some(parent.id));
         assert_eq!(child.created_by, Some(parent.id));
@@ -320,6 +711,56 @@ mod tests {
         assert_eq!(ai_hw.coherence_threshold(), 0.6);
     }
 
+    #[test]
+    fn test_build_with_log_emits_a_verifiable_inclusion_proof() {
+        let mut log = crate::transparency::LctTransparencyLog::new();
+        let operator = KeyPair::generate();
+
+        let (lct, _keypair, leaf_index, proof) =
+            LctBuilder::new(EntityType::AiSoftware).build_with_log(&mut log);
+
+        let leaf = crate::transparency::lct_leaf(&lct.public_key, lct.id, lct.created_by);
+        let sth = log.sign_tree_head(&operator);
+
+        assert!(crate::transparency::verify_inclusion(
+            leaf,
+            leaf_index,
+            &proof,
+            &sth,
+            &operator.verifying_key()
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_human_lct_carries_verifiable_personhood_proof() {
+        use crate::personhood::{derive_nullifier, PersonhoodRegistry};
+
+        let mut registry = PersonhoodRegistry::new();
+        let secret = crate::crypto::sha256(b"alice's identity secret");
+        let index = registry.register(crate::crypto::sha256(&secret));
+        let proof = registry.prove_membership(index).unwrap();
+
+        let uniqueness = HumanUniqueness {
+            merkle_root: registry.root(),
+            nullifier: derive_nullifier(&secret, b"web4:human-lct"),
+            proof,
+        };
+
+        let (lct, _keypair) = LctBuilder::new(EntityType::Human)
+            .human_uniqueness(uniqueness.clone())
+            .build();
+
+        assert!(registry.verify_personhood(&uniqueness).is_ok());
+        assert!(lct.verify_personhood(registry.root()).is_ok());
+    }
+
+    #[test]
+    fn test_lct_without_personhood_proof_fails_verification() {
+        let (lct, _) = Lct::new(EntityType::Human, None);
+        assert!(lct.verify_personhood([0u8; 32]).is_err());
+    }
+
     #[test]
     fn test_lct_builder() {
         let parent_id = Uuid::new_v4();
@@ -344,4 +785,203 @@ mod tests {
         assert_eq!(fp.len(), 19);
         assert!(fp.contains("..."));
     }
+
+    #[test]
+    fn test_unattested_binding_keeps_software_ceiling() {
+        let (lct, _) = Lct::new(EntityType::AiEmbodied, None);
+        assert!(lct.verify_attestation_chain().is_ok());
+        assert_eq!(lct.trust_ceiling(), 0.85);
+    }
+
+    #[test]
+    fn test_verified_attestation_chain_raises_ceiling_above_software_default() {
+        let (mut lct, _) = Lct::new(EntityType::AiEmbodied, None);
+
+        let root_secret = crate::crypto::sha256(b"test TPM endorsement secret");
+        let root_public_key = attestation::keypair_from_cdi(&root_secret).verifying_key();
+        let measurements = [
+            crate::crypto::sha256(b"bootloader"),
+            crate::crypto::sha256(b"firmware"),
+        ];
+        let chain =
+            attestation::build_attestation_chain(&root_secret, &measurements, &lct.public_key);
+
+        lct.hardware_binding.level = 5;
+        lct.hardware_binding.attestation_chain = chain;
+        lct.hardware_binding.root_public_key = Some(root_public_key);
+
+        assert!(lct.verify_attestation_chain().is_ok());
+        assert!(lct.trust_ceiling() > 0.85);
+    }
+
+    #[test]
+    fn test_tampered_attestation_chain_falls_back_to_software_ceiling() {
+        let (mut lct, _) = Lct::new(EntityType::AiEmbodied, None);
+
+        let root_secret = crate::crypto::sha256(b"test TPM endorsement secret");
+        let root_public_key = attestation::keypair_from_cdi(&root_secret).verifying_key();
+        let measurements = [crate::crypto::sha256(b"bootloader")];
+        let mut chain =
+            attestation::build_attestation_chain(&root_secret, &measurements, &lct.public_key);
+        let last = chain.last_mut().unwrap();
+        let idx = last.len() / 2;
+        last[idx] ^= 0xff;
+
+        lct.hardware_binding.level = 5;
+        lct.hardware_binding.attestation_chain = chain;
+        lct.hardware_binding.root_public_key = Some(root_public_key);
+
+        assert!(lct.verify_attestation_chain().is_err());
+        assert_eq!(lct.trust_ceiling(), 0.85);
+    }
+
+    #[test]
+    fn test_rotate_keeps_id_and_updates_public_key() {
+        let (mut lct, old_keypair) = Lct::new(EntityType::Human, None);
+        let id = lct.id;
+        let old_public_key = lct.public_key.clone();
+
+        let new_keypair = lct.rotate(&old_keypair);
+
+        assert_eq!(lct.id, id);
+        assert_eq!(lct.public_key.to_bytes(), new_keypair.verifying_key().to_bytes());
+        assert_eq!(lct.previous_key.as_ref().unwrap().to_bytes(), old_public_key.to_bytes());
+        assert!(lct.rotation_history.is_empty());
+        assert!(lct.verify_rotation_history().is_ok());
+    }
+
+    #[test]
+    fn test_verify_signature_accepts_previous_key_within_grace_window() {
+        let (mut lct, old_keypair) = Lct::new(EntityType::Human, None);
+        let message = b"signed just before rotation";
+        let old_signature = old_keypair.sign(message);
+
+        lct.rotate(&old_keypair);
+
+        assert!(lct.verify_signature(message, &old_signature).is_ok());
+    }
+
+    #[test]
+    fn test_verify_signature_rejects_previous_key_after_grace_window() {
+        let (mut lct, old_keypair) = Lct::new(EntityType::Human, None);
+        let message = b"signed just before rotation";
+        let old_signature = old_keypair.sign(message);
+
+        lct.rotate(&old_keypair);
+        lct.rotated_at = Some(Utc::now() - chrono::Duration::hours(48));
+
+        assert!(lct.verify_signature(message, &old_signature).is_err());
+    }
+
+    #[test]
+    fn test_verify_rotation_history_walks_multiple_hops() {
+        let (mut lct, keypair_0) = Lct::new(EntityType::Human, None);
+        let new_keypair_1 = lct.rotate(&keypair_0);
+        let _new_keypair_2 = lct.rotate(&new_keypair_1);
+
+        assert_eq!(lct.rotation_history.len(), 1);
+        assert!(lct.verify_rotation_history().is_ok());
+    }
+
+    #[test]
+    fn test_verify_rotation_history_detects_tampered_proof() {
+        let (mut lct, keypair_0) = Lct::new(EntityType::Human, None);
+        lct.rotate(&keypair_0);
+
+        lct.rotation_proof = Some(keypair_0.sign(b"not the current public key"));
+
+        assert!(lct.verify_rotation_history().is_err());
+    }
+
+    #[test]
+    fn test_verify_lineage_accepts_genuine_parent_attestation() {
+        let (parent, parent_keypair) = Lct::new(EntityType::Organization, None);
+        let (child, _) = parent.create_child(EntityType::Role, &parent_keypair);
+
+        assert!(child.verify_lineage(&parent).is_ok());
+    }
+
+    #[test]
+    fn test_verify_lineage_rejects_wrong_parent() {
+        let (parent, parent_keypair) = Lct::new(EntityType::Organization, None);
+        let (other, _) = Lct::new(EntityType::Organization, None);
+        let (child, _) = parent.create_child(EntityType::Role, &parent_keypair);
+
+        assert!(child.verify_lineage(&other).is_err());
+    }
+
+    #[test]
+    fn test_verify_lineage_rejects_lct_without_attestation() {
+        let (parent, _) = Lct::new(EntityType::Organization, None);
+        let (lct, _) = LctBuilder::new(EntityType::Role).parent(parent.id).build();
+
+        assert!(lct.verify_lineage(&parent).is_err());
+    }
+
+    #[test]
+    fn test_verify_lineage_rejects_slashed_parent() {
+        let (mut parent, parent_keypair) = Lct::new(EntityType::Organization, None);
+        let (child, _) = parent.create_child(EntityType::Role, &parent_keypair);
+        parent.slash();
+
+        assert!(child.verify_lineage(&parent).is_err());
+    }
+
+    #[test]
+    fn test_compute_verification_state_walks_multi_hop_lineage() {
+        let (root, root_keypair) = Lct::new(EntityType::Organization, None);
+        let (mid, mid_keypair) = root.create_child(EntityType::Role, &root_keypair);
+        let (leaf, _) = mid.create_child(EntityType::AiSoftware, &mid_keypair);
+
+        let mut registry = HashMap::new();
+        registry.insert(root.id, root);
+        registry.insert(mid.id, mid);
+
+        assert_eq!(
+            compute_verification_state(&leaf, &registry),
+            VerificationState::Verified
+        );
+    }
+
+    #[test]
+    fn test_compute_verification_state_flags_missing_ancestor_as_unverified() {
+        let (parent, parent_keypair) = Lct::new(EntityType::Organization, None);
+        let (child, _) = parent.create_child(EntityType::Role, &parent_keypair);
+
+        let registry = HashMap::new();
+        assert_eq!(
+            compute_verification_state(&child, &registry),
+            VerificationState::Unverified
+        );
+    }
+
+    #[test]
+    fn test_compute_verification_state_flags_slashed_ancestor() {
+        let (mut root, root_keypair) = Lct::new(EntityType::Organization, None);
+        let (leaf, _) = root.create_child(EntityType::Role, &root_keypair);
+        root.slash();
+
+        let mut registry = HashMap::new();
+        registry.insert(root.id, root);
+
+        assert_eq!(
+            compute_verification_state(&leaf, &registry),
+            VerificationState::ParentSlashed
+        );
+    }
+
+    #[test]
+    fn test_compute_verification_state_flags_tampered_attestation() {
+        let (root, root_keypair) = Lct::new(EntityType::Organization, None);
+        let (mut leaf, _) = root.create_child(EntityType::Role, &root_keypair);
+        leaf.parent_attestation = Some(root_keypair.sign(b"not the real attestation message"));
+
+        let mut registry = HashMap::new();
+        registry.insert(root.id, root);
+
+        assert_eq!(
+            compute_verification_state(&leaf, &registry),
+            VerificationState::SignatureMismatch
+        );
+    }
 }
@@ -55,22 +55,47 @@
 //! - **Coherence requirements**: Entities must maintain identity coherence
 //! - **Hardware binding**: Production deployments bind keys to secure hardware
 
+#[cfg(feature = "arrow")]
+pub mod arrow;
+pub mod attestation;
 pub mod coherence;
 pub mod crypto;
 pub mod error;
+pub mod group_key;
 pub mod lct;
+pub mod personhood;
+#[cfg(feature = "scale")]
+pub mod scale;
 pub mod t3;
+#[cfg(feature = "telemetry")]
+pub mod telemetry;
+pub mod transparency;
+pub mod trust_registry;
 pub mod v3;
 
 // Re-export primary types for convenience
+pub use attestation::{build_attestation_chain, derive_cdi, keypair_from_cdi, verify_attestation_chain};
 pub use coherence::{
     check_coherence, coherence_threshold_for_entity, Coherence, CoherenceCalculator,
-    CoherenceEvent, CoherenceParams,
+    CoherenceEvent, CoherenceMetrics, CoherenceMetricsSnapshot, CoherenceParams,
+    EntityTypeCoherenceStats, LimitingFactorCounts, StabilityHistory,
 };
 pub use crypto::{sha256, sha256_hex, KeyPair, PublicKey, SignatureBytes};
 pub use error::{Result, Web4Error};
+pub use group_key::GroupKeyTree;
 pub use lct::{EntityType, HardwareBinding, Lct, LctBuilder, LctStatus};
-pub use t3::{TrustDimension, TrustObservation, TrustRelation, T3, T3_DIMENSIONS};
+pub use personhood::{
+    derive_nullifier, HumanUniqueness, IdentityCommitment, MembershipProof, PersonhoodRegistry,
+};
+pub use t3::{
+    HistoryConfig, IntervalScore, TrustDimension, TrustObservation, TrustRelation, T3,
+    CONFIRMATION_DEPTH_BUCKETS, T3_DIMENSIONS, TRUST_HISTORY_INTERVALS,
+};
+pub use transparency::{
+    lct_leaf, verify_consistency, verify_inclusion, InclusionProof, LctTransparencyLog,
+    SignedTreeHead,
+};
+pub use trust_registry::{NetworkTrust, TrustRegistry};
 pub use v3::{TrustValueScore, ValueDimension, ValueObservation, V3, V3_DIMENSIONS};
 
 /// Library version
@@ -93,11 +118,11 @@ mod tests {
     #[test]
     fn test_full_workflow() {
         // 1. Create an organization LCT
-        let (org_lct, _org_keypair) = Lct::new(EntityType::Organization, None);
+        let (org_lct, org_keypair) = Lct::new(EntityType::Organization, None);
         assert!(org_lct.is_active());
 
         // 2. Organization creates an AI agent
-        let (ai_lct, ai_keypair) = org_lct.create_child(EntityType::AiSoftware);
+        let (ai_lct, ai_keypair) = org_lct.create_child(EntityType::AiSoftware, &org_keypair);
         assert_eq!(ai_lct.parent_id, Some(org_lct.id));
         assert_eq!(ai_lct.lineage_depth, 1);
 
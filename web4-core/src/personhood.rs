@@ -0,0 +1,272 @@
+// Copyright (c) 2026 MetaLINXX Inc.
+// SPDX-License-Identifier: AGPL-3.0-only
+//
+// This software is covered by US Patents 11,477,027 and 12,278,913,
+// and pending application 19/178,619. A royalty-free license is granted
+// under AGPL-3.0 terms for non-commercial and research use.
+// For commercial licensing: dp@metalinxx.io
+// See PATENTS.md for details.
+
+//! Sybil-resistant proof-of-personhood for `EntityType::Human` LCTs.
+//!
+//! `Human` LCTs get a favorable [`coherence_threshold`](crate::lct::Lct::coherence_threshold),
+//! but nothing in [`crate::lct`] stops one person from minting unlimited
+//! `Human` LCTs. This module adds an optional uniqueness gate modeled on
+//! the Semaphore/World ID nullifier scheme: a [`PersonhoodRegistry`] holds
+//! a Merkle tree of verified-human identity commitments, and minting a
+//! `Human` LCT in a given context requires presenting a
+//! [`MembershipProof`] that some commitment is registered plus a
+//! [`derive_nullifier`]-derived nullifier scoped to that context. The
+//! registry rejects a second LCT presenting the same nullifier, proving
+//! "one human, one identity here" without the LCT itself revealing which
+//! registry member minted it.
+//!
+//! # Limitation: this is not zero-knowledge
+//!
+//! Semaphore/World ID prove registry membership with a zk-SNARK (Groth16),
+//! so a verifier learns only "some registered commitment matches" and
+//! never which one. This crate has no SNARK dependency, so
+//! [`MembershipProof`] is a plain Merkle inclusion proof that names
+//! `commitment` and its position directly. The nullifier-uniqueness
+//! guarantee (the property that actually blocks sybils) is fully
+//! implemented; the anonymity guarantee is not — a verifier here can link
+//! an LCT to the specific commitment it proved membership for. Upgrading
+//! `MembershipProof` to a real SNARK proof without changing
+//! [`HumanUniqueness`]'s shape is the intended path to closing that gap.
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::crypto::sha256;
+use crate::error::{Result, Web4Error};
+use crate::transparency::{build_path, leaf_hash, merkle_root, recompute_root};
+
+/// A verified-human identity commitment: `sha256(identity_secret)`, as
+/// registered in a [`PersonhoodRegistry`]. Never stored or transmitted
+/// alongside its `identity_secret`.
+pub type IdentityCommitment = [u8; 32];
+
+/// Derive the nullifier a human presents when minting a `Human` LCT in a
+/// given context: `sha256(identity_secret || external_nullifier)`.
+/// `external_nullifier` scopes the nullifier to one domain/context (e.g.
+/// `b"web4:human-lct"`), so the same person is unique-per-context without
+/// being linkable across contexts by the nullifier alone.
+pub fn derive_nullifier(identity_secret: &[u8; 32], external_nullifier: &[u8]) -> [u8; 32] {
+    let mut input = Vec::with_capacity(32 + external_nullifier.len());
+    input.extend_from_slice(identity_secret);
+    input.extend_from_slice(external_nullifier);
+    sha256(&input)
+}
+
+/// Proof that an [`IdentityCommitment`] is a member of a
+/// [`PersonhoodRegistry`]'s tree at the time `tree_size`/`audit_path` were
+/// produced. See the module docs for why this is a plain Merkle proof
+/// rather than a zero-knowledge one.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MembershipProof {
+    /// The identity commitment this proof is for.
+    pub commitment: IdentityCommitment,
+    /// The commitment's leaf index in the registry tree.
+    pub leaf_index: usize,
+    /// The registry tree's size when this proof was produced.
+    pub tree_size: usize,
+    /// Sibling hashes from the leaf up to the root.
+    pub audit_path: Vec<[u8; 32]>,
+}
+
+/// The proof-of-personhood evidence a `Human` LCT carries: which registry
+/// (`merkle_root`) it claims membership in, the `nullifier` proving "one
+/// human, one identity" for this context, and the `proof` of registry
+/// membership.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct HumanUniqueness {
+    /// The registry root this proof targets.
+    pub merkle_root: [u8; 32],
+    /// `sha256(identity_secret || external_nullifier)`, see [`derive_nullifier`].
+    pub nullifier: [u8; 32],
+    /// Proof `merkle_root` contains `proof.commitment`.
+    pub proof: MembershipProof,
+}
+
+impl HumanUniqueness {
+    /// Check only that `proof` is a valid membership proof against
+    /// `registry_root` — a stateless, self-contained check an `Lct` holder
+    /// can do on its own. Does **not** check nullifier freshness: that
+    /// requires consulting a [`PersonhoodRegistry`]'s spent-nullifier set,
+    /// since freshness is inherently a property of shared state, not of
+    /// this proof alone. See [`PersonhoodRegistry::verify_personhood`] for
+    /// the full check a registry performs before accepting a new LCT.
+    pub fn verify_membership(&self, registry_root: [u8; 32]) -> Result<()> {
+        if self.merkle_root != registry_root {
+            return Err(Web4Error::InvalidInput(
+                "proof-of-personhood targets a different registry root".into(),
+            ));
+        }
+
+        let leaf = leaf_hash(&self.proof.commitment);
+        let mut it = self.proof.audit_path.iter();
+        let recomputed = recompute_root(self.proof.leaf_index, self.proof.tree_size, leaf, &mut it);
+        if it.next().is_some() || recomputed != Some(self.merkle_root) {
+            return Err(Web4Error::InvalidInput(
+                "proof-of-personhood membership proof does not verify".into(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// An append-only Merkle tree of verified-human [`IdentityCommitment`]s,
+/// plus the set of nullifiers already spent against it.
+#[derive(Clone, Debug, Default)]
+pub struct PersonhoodRegistry {
+    commitments: Vec<IdentityCommitment>,
+    used_nullifiers: HashSet<[u8; 32]>,
+}
+
+impl PersonhoodRegistry {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of registered identity commitments.
+    pub fn len(&self) -> usize {
+        self.commitments.len()
+    }
+
+    /// Whether the registry has no commitments yet.
+    pub fn is_empty(&self) -> bool {
+        self.commitments.is_empty()
+    }
+
+    /// Register a new verified-human identity commitment, returning its
+    /// leaf index.
+    pub fn register(&mut self, commitment: IdentityCommitment) -> usize {
+        self.commitments.push(commitment);
+        self.commitments.len() - 1
+    }
+
+    fn leaf_hashes(&self) -> Vec<[u8; 32]> {
+        self.commitments.iter().map(|c| leaf_hash(c)).collect()
+    }
+
+    /// The current Merkle root.
+    pub fn root(&self) -> [u8; 32] {
+        merkle_root(&self.leaf_hashes())
+    }
+
+    /// Build a [`MembershipProof`] for the commitment at `leaf_index`.
+    /// `None` if `leaf_index` is out of range.
+    pub fn prove_membership(&self, leaf_index: usize) -> Option<MembershipProof> {
+        let hashes = self.leaf_hashes();
+        if leaf_index >= hashes.len() {
+            return None;
+        }
+        Some(MembershipProof {
+            commitment: self.commitments[leaf_index],
+            leaf_index,
+            tree_size: hashes.len(),
+            audit_path: build_path(leaf_index, &hashes),
+        })
+    }
+
+    /// The full check a registry performs before accepting a new `Human`
+    /// LCT: `uniqueness.proof` must verify against this registry's current
+    /// root, and `uniqueness.nullifier` must not already have been spent.
+    /// Records the nullifier as spent on success, so presenting the same
+    /// `HumanUniqueness` again is rejected.
+    pub fn verify_personhood(&mut self, uniqueness: &HumanUniqueness) -> Result<()> {
+        uniqueness.verify_membership(self.root())?;
+
+        if !self.used_nullifiers.insert(uniqueness.nullifier) {
+            return Err(Web4Error::Unauthorized(
+                "nullifier already used to mint a Human LCT in this context".into(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn commitment(secret: &[u8; 32]) -> IdentityCommitment {
+        sha256(secret)
+    }
+
+    #[test]
+    fn test_registered_commitment_proves_membership() {
+        let mut registry = PersonhoodRegistry::new();
+        let secrets: Vec<[u8; 32]> = (0..5).map(|n| sha256(format!("secret-{}", n).as_bytes())).collect();
+        for secret in &secrets {
+            registry.register(commitment(secret));
+        }
+
+        let root = registry.root();
+        for (i, secret) in secrets.iter().enumerate() {
+            let proof = registry.prove_membership(i).unwrap();
+            let uniqueness = HumanUniqueness {
+                merkle_root: root,
+                nullifier: derive_nullifier(secret, b"web4:human-lct"),
+                proof,
+            };
+            assert!(uniqueness.verify_membership(root).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_first_personhood_claim_succeeds_and_reuse_is_rejected() {
+        let mut registry = PersonhoodRegistry::new();
+        let secret = sha256(b"alice's identity secret");
+        let index = registry.register(commitment(&secret));
+        let proof = registry.prove_membership(index).unwrap();
+        let uniqueness = HumanUniqueness {
+            merkle_root: registry.root(),
+            nullifier: derive_nullifier(&secret, b"web4:human-lct"),
+            proof,
+        };
+
+        assert!(registry.verify_personhood(&uniqueness).is_ok());
+        assert!(registry.verify_personhood(&uniqueness).is_err());
+    }
+
+    #[test]
+    fn test_same_secret_different_context_is_not_a_replay() {
+        let mut registry = PersonhoodRegistry::new();
+        let secret = sha256(b"alice's identity secret");
+        let index = registry.register(commitment(&secret));
+
+        let first = HumanUniqueness {
+            merkle_root: registry.root(),
+            nullifier: derive_nullifier(&secret, b"web4:human-lct"),
+            proof: registry.prove_membership(index).unwrap(),
+        };
+        let second = HumanUniqueness {
+            merkle_root: registry.root(),
+            nullifier: derive_nullifier(&secret, b"web4:governance-vote"),
+            proof: registry.prove_membership(index).unwrap(),
+        };
+
+        assert!(registry.verify_personhood(&first).is_ok());
+        assert!(registry.verify_personhood(&second).is_ok());
+    }
+
+    #[test]
+    fn test_membership_proof_rejects_wrong_registry_root() {
+        let mut registry = PersonhoodRegistry::new();
+        let secret = sha256(b"alice's identity secret");
+        let index = registry.register(commitment(&secret));
+        let proof = registry.prove_membership(index).unwrap();
+        let uniqueness = HumanUniqueness {
+            merkle_root: registry.root(),
+            nullifier: derive_nullifier(&secret, b"web4:human-lct"),
+            proof,
+        };
+
+        assert!(uniqueness.verify_membership(sha256(b"a different root")).is_err());
+    }
+}
@@ -0,0 +1,348 @@
+// Copyright (c) 2026 MetaLINXX Inc.
+// SPDX-License-Identifier: AGPL-3.0-only
+
+//! SCALE codec support for on-chain storage of Web4 primitives.
+//!
+//! Gated behind the `scale` feature. SCALE is the compact,
+//! non-self-describing binary format used by Substrate: fixed structs encode
+//! as the concatenation of their fields and enums as a one-byte discriminant
+//! followed by the variant payload. `scale_info::TypeInfo` additionally emits a
+//! type-definition registry so external tooling can decode the bytes without
+//! the Rust source.
+//!
+//! # Float representation
+//!
+//! `Coherence` factors and `HardwareBinding::trust_ceiling` are `f64` in
+//! `[0.0, 1.0]`, which SCALE cannot encode directly. We store each as a
+//! **fixed-point `u32` scaled by 1e9** so the encoding is byte-for-byte
+//! deterministic across platforms (IEEE-754 bit patterns are not) and
+//! round-trips to nine decimal places. The `TypeInfo` describes these fields as
+//! `FixedU32` so decoders divide by 1e9.
+
+use parity_scale_codec::{Decode, Encode, Error as CodecError, Input, Output};
+use scale_info::{build::Fields, Path, Type, TypeInfo};
+
+use crate::coherence::Coherence;
+use crate::crypto::{PublicKey, SignatureBytes};
+use crate::lct::{HardwareBinding, Lct, RotationRecord};
+use crate::personhood::HumanUniqueness;
+
+/// Scaling factor applied to unit-interval `f64` fields.
+pub(crate) const FIXED_SCALE: f64 = 1_000_000_000.0;
+
+/// Encode a unit-interval `f64` as a fixed-point `u32` (`value * 1e9`).
+pub(crate) fn f64_to_fixed(value: f64) -> u32 {
+    (value.clamp(0.0, 1.0) * FIXED_SCALE).round() as u32
+}
+
+/// Decode a fixed-point `u32` back to an `f64` in `[0.0, 1.0]`.
+pub(crate) fn fixed_to_f64(fixed: u32) -> f64 {
+    (fixed as f64 / FIXED_SCALE).clamp(0.0, 1.0)
+}
+
+impl Encode for Coherence {
+    fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+        f64_to_fixed(self.continuity).encode_to(dest);
+        f64_to_fixed(self.stability).encode_to(dest);
+        f64_to_fixed(self.phi).encode_to(dest);
+        f64_to_fixed(self.reachability).encode_to(dest);
+    }
+}
+
+impl Decode for Coherence {
+    fn decode<I: Input>(input: &mut I) -> Result<Self, CodecError> {
+        Ok(Self {
+            continuity: fixed_to_f64(u32::decode(input)?),
+            stability: fixed_to_f64(u32::decode(input)?),
+            phi: fixed_to_f64(u32::decode(input)?),
+            reachability: fixed_to_f64(u32::decode(input)?),
+        })
+    }
+}
+
+impl TypeInfo for Coherence {
+    type Identity = Self;
+
+    fn type_info() -> Type {
+        Type::builder()
+            .path(Path::new("Coherence", "web4_core::coherence"))
+            .docs(&["Identity coherence factors, each a FixedU32 = value * 1e9"])
+            .composite(
+                Fields::named()
+                    .field(|f| f.ty::<u32>().name("continuity").type_name("FixedU32"))
+                    .field(|f| f.ty::<u32>().name("stability").type_name("FixedU32"))
+                    .field(|f| f.ty::<u32>().name("phi").type_name("FixedU32"))
+                    .field(|f| f.ty::<u32>().name("reachability").type_name("FixedU32")),
+            )
+    }
+}
+
+impl Encode for HardwareBinding {
+    fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+        self.level.encode_to(dest);
+        self.description.encode_to(dest);
+        f64_to_fixed(self.trust_ceiling).encode_to(dest);
+        self.attestation_chain.encode_to(dest);
+        self.root_public_key
+            .as_ref()
+            .map(|k| k.to_bytes())
+            .encode_to(dest);
+    }
+}
+
+impl Decode for HardwareBinding {
+    fn decode<I: Input>(input: &mut I) -> Result<Self, CodecError> {
+        Ok(Self {
+            level: u8::decode(input)?,
+            description: String::decode(input)?,
+            trust_ceiling: fixed_to_f64(u32::decode(input)?),
+            attestation_chain: Vec::<Vec<u8>>::decode(input)?,
+            root_public_key: Option::<[u8; 32]>::decode(input)?
+                .map(|bytes| PublicKey::from_bytes(&bytes))
+                .transpose()
+                .map_err(|_| CodecError::from("invalid root public key bytes"))?,
+        })
+    }
+}
+
+impl TypeInfo for HardwareBinding {
+    type Identity = Self;
+
+    fn type_info() -> Type {
+        Type::builder()
+            .path(Path::new("HardwareBinding", "web4_core::lct"))
+            .composite(
+                Fields::named()
+                    .field(|f| f.ty::<u8>().name("level").type_name("u8"))
+                    .field(|f| f.ty::<String>().name("description").type_name("String"))
+                    .field(|f| f.ty::<u32>().name("trust_ceiling").type_name("FixedU32"))
+                    .field(|f| {
+                        f.ty::<Vec<Vec<u8>>>()
+                            .name("attestation_chain")
+                            .type_name("Vec<Vec<u8>>")
+                    })
+                    .field(|f| {
+                        f.ty::<Option<[u8; 32]>>()
+                            .name("root_public_key")
+                            .type_name("Option<Ed25519PublicKey>")
+                    }),
+            )
+    }
+}
+
+impl Encode for RotationRecord {
+    fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+        self.previous_key.to_bytes().encode_to(dest);
+        self.new_key.to_bytes().encode_to(dest);
+        self.proof.bytes.encode_to(dest);
+        self.rotated_at.timestamp_millis().encode_to(dest);
+    }
+}
+
+impl Decode for RotationRecord {
+    fn decode<I: Input>(input: &mut I) -> Result<Self, CodecError> {
+        let previous_key = PublicKey::from_bytes(&<[u8; 32]>::decode(input)?)
+            .map_err(|_| CodecError::from("invalid previous public key bytes"))?;
+        let new_key = PublicKey::from_bytes(&<[u8; 32]>::decode(input)?)
+            .map_err(|_| CodecError::from("invalid new public key bytes"))?;
+        let proof = SignatureBytes::from_bytes(<[u8; 64]>::decode(input)?);
+        let rotated_at = chrono::DateTime::from_timestamp_millis(i64::decode(input)?)
+            .ok_or(CodecError::from("timestamp out of range"))?;
+
+        Ok(Self {
+            previous_key,
+            new_key,
+            proof,
+            rotated_at,
+        })
+    }
+}
+
+impl TypeInfo for RotationRecord {
+    type Identity = Self;
+
+    fn type_info() -> Type {
+        Type::builder()
+            .path(Path::new("RotationRecord", "web4_core::lct"))
+            .composite(
+                Fields::named()
+                    .field(|f| {
+                        f.ty::<[u8; 32]>()
+                            .name("previous_key")
+                            .type_name("Ed25519PublicKey")
+                    })
+                    .field(|f| f.ty::<[u8; 32]>().name("new_key").type_name("Ed25519PublicKey"))
+                    .field(|f| f.ty::<[u8; 64]>().name("proof").type_name("Ed25519Signature"))
+                    .field(|f| f.ty::<i64>().name("rotated_at").type_name("TimestampMillis")),
+            )
+    }
+}
+
+impl Encode for Lct {
+    fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+        self.id.as_bytes().encode_to(dest);
+        self.entity_type.encode_to(dest);
+        self.status.encode_to(dest);
+        self.public_key.to_bytes().encode_to(dest);
+        self.created_at.timestamp_millis().encode_to(dest);
+        self.created_by.map(|id| *id.as_bytes()).encode_to(dest);
+        self.hardware_binding.encode_to(dest);
+        self.parent_id.map(|id| *id.as_bytes()).encode_to(dest);
+        self.lineage_depth.encode_to(dest);
+        self.previous_key.as_ref().map(|k| k.to_bytes()).encode_to(dest);
+        self.rotation_proof.as_ref().map(|s| s.bytes).encode_to(dest);
+        self.rotated_at.map(|t| t.timestamp_millis()).encode_to(dest);
+        self.rotation_history.encode_to(dest);
+        self.human_uniqueness
+            .as_ref()
+            .map(|h| serde_json::to_vec(h).expect("HumanUniqueness always serializes"))
+            .encode_to(dest);
+        self.parent_attestation
+            .as_ref()
+            .map(|s| s.bytes)
+            .encode_to(dest);
+    }
+}
+
+impl Decode for Lct {
+    fn decode<I: Input>(input: &mut I) -> Result<Self, CodecError> {
+        let id = uuid::Uuid::from_bytes(<[u8; 16]>::decode(input)?);
+        let entity_type = crate::lct::EntityType::decode(input)?;
+        let status = crate::lct::LctStatus::decode(input)?;
+        let public_key = PublicKey::from_bytes(&<[u8; 32]>::decode(input)?)
+            .map_err(|_| CodecError::from("invalid public key bytes"))?;
+        let created_at = chrono::DateTime::from_timestamp_millis(i64::decode(input)?)
+            .ok_or(CodecError::from("timestamp out of range"))?;
+        let created_by = Option::<[u8; 16]>::decode(input)?.map(uuid::Uuid::from_bytes);
+        let hardware_binding = HardwareBinding::decode(input)?;
+        let parent_id = Option::<[u8; 16]>::decode(input)?.map(uuid::Uuid::from_bytes);
+        let lineage_depth = u32::decode(input)?;
+        let previous_key = Option::<[u8; 32]>::decode(input)?
+            .map(|bytes| PublicKey::from_bytes(&bytes))
+            .transpose()
+            .map_err(|_| CodecError::from("invalid previous public key bytes"))?;
+        let rotation_proof = Option::<[u8; 64]>::decode(input)?.map(SignatureBytes::from_bytes);
+        let rotated_at = Option::<i64>::decode(input)?
+            .map(|ms| {
+                chrono::DateTime::from_timestamp_millis(ms)
+                    .ok_or(CodecError::from("timestamp out of range"))
+            })
+            .transpose()?;
+        let rotation_history = Vec::<RotationRecord>::decode(input)?;
+        let human_uniqueness = Option::<Vec<u8>>::decode(input)?
+            .map(|bytes| serde_json::from_slice::<HumanUniqueness>(&bytes))
+            .transpose()
+            .map_err(|_| CodecError::from("invalid human uniqueness bytes"))?;
+        let parent_attestation =
+            Option::<[u8; 64]>::decode(input)?.map(SignatureBytes::from_bytes);
+
+        Ok(Self {
+            id,
+            entity_type,
+            status,
+            public_key,
+            created_at,
+            created_by,
+            hardware_binding,
+            parent_id,
+            lineage_depth,
+            previous_key,
+            rotation_proof,
+            rotated_at,
+            rotation_history,
+            human_uniqueness,
+            parent_attestation,
+        })
+    }
+}
+
+impl TypeInfo for Lct {
+    type Identity = Self;
+
+    fn type_info() -> Type {
+        Type::builder()
+            .path(Path::new("Lct", "web4_core::lct"))
+            .docs(&["Linked Context Token; UUIDs as [u8;16], timestamps as i64 millis"])
+            .composite(
+                Fields::named()
+                    .field(|f| f.ty::<[u8; 16]>().name("id").type_name("Uuid"))
+                    .field(|f| f.ty::<crate::lct::EntityType>().name("entity_type"))
+                    .field(|f| f.ty::<crate::lct::LctStatus>().name("status"))
+                    .field(|f| f.ty::<[u8; 32]>().name("public_key").type_name("Ed25519PublicKey"))
+                    .field(|f| f.ty::<i64>().name("created_at").type_name("TimestampMillis"))
+                    .field(|f| f.ty::<Option<[u8; 16]>>().name("created_by").type_name("Option<Uuid>"))
+                    .field(|f| f.ty::<HardwareBinding>().name("hardware_binding"))
+                    .field(|f| f.ty::<Option<[u8; 16]>>().name("parent_id").type_name("Option<Uuid>"))
+                    .field(|f| f.ty::<u32>().name("lineage_depth").type_name("u32"))
+                    .field(|f| {
+                        f.ty::<Option<[u8; 32]>>()
+                            .name("previous_key")
+                            .type_name("Option<Ed25519PublicKey>")
+                    })
+                    .field(|f| {
+                        f.ty::<Option<[u8; 64]>>()
+                            .name("rotation_proof")
+                            .type_name("Option<Ed25519Signature>")
+                    })
+                    .field(|f| {
+                        f.ty::<Option<i64>>()
+                            .name("rotated_at")
+                            .type_name("Option<TimestampMillis>")
+                    })
+                    .field(|f| {
+                        f.ty::<Vec<RotationRecord>>()
+                            .name("rotation_history")
+                            .type_name("Vec<RotationRecord>")
+                    })
+                    .field(|f| {
+                        f.ty::<Option<Vec<u8>>>()
+                            .name("human_uniqueness")
+                            .type_name("Option<Vec<u8>>")
+                    })
+                    .field(|f| {
+                        f.ty::<Option<[u8; 64]>>()
+                            .name("parent_attestation")
+                            .type_name("Option<Ed25519Signature>")
+                    }),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lct::EntityType;
+    use scale_info::TypeDef;
+
+    #[test]
+    fn coherence_round_trips_through_scale() {
+        let c = Coherence::with_values(0.9, 0.8, 0.7, 0.6).unwrap();
+        let decoded = Coherence::decode(&mut &c.encode()[..]).unwrap();
+        assert!((decoded.continuity - c.continuity).abs() < 1e-9);
+        assert!((decoded.reachability - c.reachability).abs() < 1e-9);
+    }
+
+    #[test]
+    fn lct_round_trips_through_scale() {
+        let (lct, _kp) = Lct::new(EntityType::AiSoftware, None);
+        let bytes = lct.encode();
+        let decoded = Lct::decode(&mut &bytes[..]).unwrap();
+        assert_eq!(decoded.id, lct.id);
+        assert_eq!(decoded.entity_type, lct.entity_type);
+        assert_eq!(decoded.public_key.to_bytes(), lct.public_key.to_bytes());
+        assert_eq!(decoded.lineage_depth, lct.lineage_depth);
+    }
+
+    #[test]
+    fn coherence_metadata_snapshot() {
+        let ty = <Coherence as TypeInfo>::type_info();
+        assert_eq!(ty.path.ident(), Some("Coherence"));
+        match &ty.type_def {
+            TypeDef::Composite(c) => {
+                let names: Vec<_> = c.fields.iter().filter_map(|f| f.name).collect();
+                assert_eq!(names, vec!["continuity", "stability", "phi", "reachability"]);
+            }
+            _ => panic!("expected composite"),
+        }
+    }
+}
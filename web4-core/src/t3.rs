@@ -28,6 +28,10 @@ pub const T3_DIMENSIONS: usize = 3;
 
 /// The three root dimensions of trust
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "scale",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode, scale_info::TypeInfo)
+)]
 #[repr(usize)]
 pub enum TrustDimension {
     /// Natural aptitude and capability for a specific role
@@ -58,6 +62,210 @@ impl TrustDimension {
     }
 }
 
+/// Number of fixed-duration intervals retained in each dimension's history ring.
+pub const TRUST_HISTORY_INTERVALS: usize = 12;
+
+/// Tuning for the per-dimension trust history ring buffer.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct HistoryConfig {
+    /// Wall-clock duration of a single interval, in seconds.
+    pub interval_secs: i64,
+    /// Fading factor `f ∈ (0, 1)`; older intervals are weighted by `f^k`.
+    pub fading_factor: f64,
+    /// Weight of the proportional (current-interval) term in the effective score.
+    pub weight_proportional: f64,
+    /// Weight of the integral (fading-history) term; `weight_proportional + weight_integral == 1`.
+    pub weight_integral: f64,
+    /// Reward gain applied when behaviour is improving (`d > 0`).
+    pub reward_gain: f64,
+    /// Penalty gain applied when behaviour is worsening (`d < 0`); kept well
+    /// above `reward_gain` so a drop sinks the score faster than it rose.
+    pub penalty_gain: f64,
+}
+
+impl Default for HistoryConfig {
+    fn default() -> Self {
+        // A day per interval over twelve intervals: ~two weeks of memory, with
+        // recency strongly favoured.
+        Self {
+            interval_secs: 86_400,
+            fading_factor: 0.9,
+            weight_proportional: 0.4,
+            weight_integral: 0.6,
+            reward_gain: 0.1,
+            penalty_gain: 0.5,
+        }
+    }
+}
+
+/// Good/bad tallies and the aggregated score for one time interval.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+pub struct IntervalScore {
+    /// Observations at or above the neutral midpoint (0.5).
+    pub good: u64,
+    /// Observations below the neutral midpoint.
+    pub bad: u64,
+    /// Running sum of observed scores, averaged lazily in [`value`](Self::value).
+    sum: f64,
+}
+
+impl IntervalScore {
+    fn count(&self) -> u64 {
+        self.good + self.bad
+    }
+
+    fn is_empty(&self) -> bool {
+        self.count() == 0
+    }
+
+    /// Mean observed score in this interval (meaningless when empty).
+    fn value(&self) -> f64 {
+        if self.is_empty() {
+            0.0
+        } else {
+            self.sum / self.count() as f64
+        }
+    }
+
+    fn record(&mut self, score: f64) {
+        if score >= 0.5 {
+            self.good += 1;
+        } else {
+            self.bad += 1;
+        }
+        self.sum += score;
+    }
+}
+
+/// A ring buffer of time intervals for one trust dimension.
+///
+/// `intervals[head]` is the current (newest) interval; older intervals run
+/// backwards modulo the ring length. As each interval's window elapses the ring
+/// rolls forward, clearing the slot that wraps into the newest position.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct DimensionHistory {
+    intervals: [IntervalScore; TRUST_HISTORY_INTERVALS],
+    head: usize,
+    /// Unix timestamp (seconds) at which the current interval began.
+    current_start: Option<i64>,
+}
+
+impl Default for DimensionHistory {
+    fn default() -> Self {
+        Self {
+            intervals: [IntervalScore::default(); TRUST_HISTORY_INTERVALS],
+            head: 0,
+            current_start: None,
+        }
+    }
+}
+
+impl DimensionHistory {
+    fn record(&mut self, score: f64, now: i64, interval_secs: i64) {
+        match self.current_start {
+            None => self.current_start = Some(now),
+            Some(start) if interval_secs > 0 && now > start => {
+                let elapsed = (now - start) / interval_secs;
+                if elapsed > 0 {
+                    // Roll forward, never advancing more than the ring holds.
+                    let steps = (elapsed as usize).min(TRUST_HISTORY_INTERVALS);
+                    for _ in 0..steps {
+                        self.head = (self.head + 1) % TRUST_HISTORY_INTERVALS;
+                        self.intervals[self.head] = IntervalScore::default();
+                    }
+                    self.current_start = Some(start + elapsed * interval_secs);
+                }
+            }
+            Some(_) => {}
+        }
+        self.intervals[self.head].record(score);
+    }
+
+    fn all_empty(&self) -> bool {
+        self.intervals.iter().all(IntervalScore::is_empty)
+    }
+
+    /// Good/bad ratio of the current (newest) interval, `None` when it is empty.
+    fn current_ratio(&self) -> Option<f64> {
+        let interval = &self.intervals[self.head];
+        if interval.is_empty() {
+            None
+        } else {
+            Some(interval.good as f64 / interval.count() as f64)
+        }
+    }
+
+    /// TrustGuard fading aggregate `H = (Σ f^k·R_k) / (Σ f^k)`, newest first,
+    /// with empty intervals dropped from both sums.
+    fn history_value(&self, fading_factor: f64) -> f64 {
+        let mut numerator = 0.0;
+        let mut denominator = 0.0;
+        let mut fk = 1.0;
+        for k in 0..TRUST_HISTORY_INTERVALS {
+            let idx = (self.head + TRUST_HISTORY_INTERVALS - k) % TRUST_HISTORY_INTERVALS;
+            let interval = &self.intervals[idx];
+            if !interval.is_empty() {
+                numerator += fk * interval.value();
+                denominator += fk;
+            }
+            fk *= fading_factor;
+        }
+        if denominator == 0.0 {
+            0.5 // No history yet → neutral.
+        } else {
+            numerator / denominator
+        }
+    }
+}
+
+/// Number of distinct-witness depth buckets tracked per dimension before
+/// everything at or beyond that depth is merged into the final bucket.
+pub const CONFIRMATION_DEPTH_BUCKETS: usize = 5;
+
+/// Per-dimension histogram of observation weight by corroboration depth.
+///
+/// `by_depth[c]` is the aggregate observer-weight of observations backed by
+/// exactly `c` distinct witnesses, for `c < CONFIRMATION_DEPTH_BUCKETS - 1`;
+/// the last bucket catches everything at or above that depth.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+struct ConfirmationHistogram {
+    by_depth: [f64; CONFIRMATION_DEPTH_BUCKETS],
+}
+
+impl ConfirmationHistogram {
+    fn record(&mut self, witness_count: usize, weight: f64) {
+        let bucket = witness_count.min(CONFIRMATION_DEPTH_BUCKETS - 1);
+        self.by_depth[bucket] += weight;
+    }
+
+    fn total(&self) -> f64 {
+        self.by_depth.iter().sum()
+    }
+
+    /// Weight backed by at least `min_depth` distinct witnesses.
+    fn at_least(&self, min_depth: usize) -> f64 {
+        let start = min_depth.min(CONFIRMATION_DEPTH_BUCKETS - 1);
+        self.by_depth[start..].iter().sum()
+    }
+
+    /// Multiplier in `[1.0, 2.0]` derived from the corroboration-weighted mean
+    /// depth, normalized so a dimension where every observation sits in the
+    /// deepest bucket doubles its weight in [`T3::aggregate`].
+    fn multiplier(&self) -> f64 {
+        let total = self.total();
+        if total <= 0.0 {
+            return 1.0;
+        }
+        let weighted_depth: f64 = self
+            .by_depth
+            .iter()
+            .enumerate()
+            .map(|(depth, weight)| depth as f64 * weight)
+            .sum();
+        1.0 + (weighted_depth / total) / (CONFIRMATION_DEPTH_BUCKETS - 1) as f64
+    }
+}
+
 /// Score data for a sub-dimension
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SubDimensionScore {
@@ -88,6 +296,28 @@ pub struct T3 {
     /// Anyone can extend the dimension tree without modifying the core.
     #[serde(default, skip_serializing_if = "HashMap::is_empty")]
     sub_dimensions: HashMap<String, SubDimensionScore>,
+
+    /// Per-dimension ring buffer of time intervals, powering [`history_value`].
+    ///
+    /// [`observe`] collapses into the EMA above; this keeps the raw time profile
+    /// so a peer that was briefly bad long ago reads differently from one
+    /// degrading right now.
+    ///
+    /// [`observe`]: T3::observe
+    /// [`history_value`]: T3::history_value
+    #[serde(default)]
+    history: [DimensionHistory; T3_DIMENSIONS],
+
+    /// Tuning for the history ring (interval length and fading factor).
+    #[serde(default)]
+    history_config: HistoryConfig,
+
+    /// Per-dimension corroboration-depth histogram, powering
+    /// [`confirmation_weight`](Self::confirmation_weight). Untouched by plain
+    /// [`observe`](Self::observe); only [`observe_corroborated`](Self::observe_corroborated)
+    /// records into it.
+    #[serde(default)]
+    confirmation: [ConfirmationHistogram; T3_DIMENSIONS],
 }
 
 impl Default for T3 {
@@ -104,6 +334,9 @@ impl T3 {
             weights: [0.0; T3_DIMENSIONS],
             observation_counts: [0; T3_DIMENSIONS],
             sub_dimensions: HashMap::new(),
+            history: Default::default(),
+            history_config: HistoryConfig::default(),
+            confirmation: Default::default(),
         }
     }
 
@@ -121,6 +354,9 @@ impl T3 {
             weights: [0.0; T3_DIMENSIONS],
             observation_counts: [0; T3_DIMENSIONS],
             sub_dimensions: HashMap::new(),
+            history: Default::default(),
+            history_config: HistoryConfig::default(),
+            confirmation: Default::default(),
         })
     }
 
@@ -153,6 +389,53 @@ impl T3 {
     ///
     /// Uses exponential moving average with decay factor based on observation count
     pub fn observe(&mut self, dimension: TrustDimension, observed_score: f64) -> Result<()> {
+        self.observe_at(dimension, observed_score, chrono::Utc::now().timestamp())
+    }
+
+    /// Record a root-dimension observation and emit a `tracing` span plus
+    /// [`TensorMeter`](crate::telemetry::TensorMeter) metrics through
+    /// `meter`. `observer_id`/`subject_id` are carried on the span only —
+    /// `T3` itself doesn't store per-observation provenance.
+    #[cfg(feature = "telemetry")]
+    pub fn observe_instrumented(
+        &mut self,
+        dimension: TrustDimension,
+        observed_score: f64,
+        observer_id: Uuid,
+        subject_id: Uuid,
+        meter: &crate::telemetry::TensorMeter,
+    ) -> Result<()> {
+        let _span = tracing::info_span!(
+            "t3.observe",
+            dimension = dimension.name(),
+            observer_id = %observer_id,
+            subject_id = %subject_id,
+            observed_score,
+        )
+        .entered();
+        self.observe(dimension, observed_score)?;
+        meter.record_observation(
+            "t3",
+            dimension.name(),
+            "root",
+            observed_score,
+            self.score(dimension),
+            self.weight(dimension),
+        );
+        Ok(())
+    }
+
+    /// Record an observation as if it happened at `now` (Unix seconds).
+    ///
+    /// Splitting out the timestamp keeps [`observe`](Self::observe) ergonomic
+    /// while letting the interval ring roll forward deterministically — callers
+    /// replaying history or writing tests pass their own clock.
+    pub fn observe_at(
+        &mut self,
+        dimension: TrustDimension,
+        observed_score: f64,
+        now: i64,
+    ) -> Result<()> {
         if !(0.0..=1.0).contains(&observed_score) {
             return Err(Web4Error::InvalidInput(
                 "Observed score must be in range [0.0, 1.0]".into(),
@@ -172,9 +455,103 @@ impl T3 {
         self.weights[idx] = (1.0 + self.observation_counts[idx] as f64).ln() / 10.0_f64.ln();
         self.weights[idx] = self.weights[idx].min(1.0);
 
+        // Accumulate into the time-interval ring for recency-aware history.
+        self.history[idx].record(observed_score, now, self.history_config.interval_secs);
+
         Ok(())
     }
 
+    /// Record an observation corroborated by `witness_count` other independent
+    /// witnesses, in addition to the primary observer.
+    ///
+    /// Updates the dimension exactly like [`observe`](Self::observe), then adds
+    /// one unit of observer-weight to the corroboration-depth bucket for
+    /// `witness_count` (clamped to [`CONFIRMATION_DEPTH_BUCKETS`] - 1). A repeat
+    /// observer calling this with `witness_count: 0` over and over never moves
+    /// past the shallowest bucket, so [`aggregate`](Self::aggregate) can favour
+    /// dimensions genuinely confirmed by many distinct parties.
+    pub fn observe_corroborated(
+        &mut self,
+        dimension: TrustDimension,
+        observed_score: f64,
+        witness_count: usize,
+    ) -> Result<()> {
+        self.observe(dimension, observed_score)?;
+        self.confirmation[dimension as usize].record(witness_count, 1.0);
+        Ok(())
+    }
+
+    /// Observer-weight of `dimension`'s observations backed by at least
+    /// `min_depth` distinct witnesses.
+    ///
+    /// `0.0` if nothing has been recorded via
+    /// [`observe_corroborated`](Self::observe_corroborated) at that depth or
+    /// deeper.
+    pub fn confirmation_weight(&self, dimension: TrustDimension, min_depth: usize) -> f64 {
+        self.confirmation[dimension as usize].at_least(min_depth)
+    }
+
+    /// Recency-weighted trust for a dimension from its interval history.
+    ///
+    /// Computes the TrustGuard fading aggregate over the retained intervals:
+    /// `H = (Σ_k f^k · R_k) / (Σ_k f^k)`, newest first (`k = 0`), with empty
+    /// intervals skipped from both sums. Unlike [`score`](Self::score)'s single
+    /// EMA, this still remembers long-run behaviour while favouring the present.
+    /// Returns the neutral 0.5 when no observations have been recorded.
+    pub fn history_value(&self, dimension: TrustDimension) -> f64 {
+        self.history[dimension as usize].history_value(self.history_config.fading_factor)
+    }
+
+    /// Fluctuation-aware trust for a dimension.
+    ///
+    /// Combines a proportional term (the current interval's good/bad ratio) and
+    /// an integral term (the fading [`history_value`](Self::history_value)) as
+    /// `tv = w_p·proportional + w_i·integral`, then applies a derivative
+    /// correction `d = proportional − integral`. When behaviour is worsening
+    /// (`d < 0`) the penalty `k·|d|` uses a gain well above the reward for
+    /// improvement, so a peer that builds a high score and then flips malicious
+    /// is punished far faster than the smooth EMA would react. Falls back to the
+    /// stored EMA score while no interval history exists.
+    pub fn effective_score(&self, dimension: TrustDimension) -> f64 {
+        let idx = dimension as usize;
+        let history = &self.history[idx];
+        if history.all_empty() {
+            return self.dimensions[idx];
+        }
+
+        let cfg = &self.history_config;
+        let integral = history.history_value(cfg.fading_factor);
+        let proportional = history.current_ratio().unwrap_or(integral);
+
+        let mut tv = cfg.weight_proportional * proportional + cfg.weight_integral * integral;
+        let derivative = proportional - integral;
+        if derivative < 0.0 {
+            tv -= cfg.penalty_gain * derivative.abs();
+        } else {
+            tv += cfg.reward_gain * derivative;
+        }
+        tv.clamp(0.0, 1.0)
+    }
+
+    /// Aggregate trust using [`effective_score`](Self::effective_score) per
+    /// dimension, so admission and soft-security checks feel the fluctuation
+    /// penalty. Mirrors [`aggregate`](Self::aggregate)'s weighted geometric mean,
+    /// but weights each dimension by its plain confidence weight, without the
+    /// corroboration-depth multiplier.
+    pub fn effective_aggregate(&self) -> f64 {
+        let total_weight: f64 = self.weights.iter().sum();
+        if total_weight == 0.0 {
+            return 0.5; // No observations, return neutral
+        }
+
+        let log_sum: f64 = TrustDimension::all()
+            .iter()
+            .map(|&dim| self.weights[dim as usize] * (self.effective_score(dim) + 1e-10).ln())
+            .sum();
+
+        (log_sum / total_weight).exp()
+    }
+
     /// Record an observation for a sub-dimension
     ///
     /// Sub-dimensions are keyed by name and linked to a root dimension.
@@ -209,12 +586,57 @@ impl T3 {
         Ok(())
     }
 
+    /// Record a sub-dimension observation and emit a `tracing` span plus
+    /// [`TensorMeter`](crate::telemetry::TensorMeter) metrics through
+    /// `meter`. See [`Self::observe_instrumented`] for the root-dimension
+    /// equivalent.
+    #[cfg(feature = "telemetry")]
+    pub fn observe_sub_dimension_instrumented(
+        &mut self,
+        name: &str,
+        parent: TrustDimension,
+        observed_score: f64,
+        observer_id: Uuid,
+        subject_id: Uuid,
+        meter: &crate::telemetry::TensorMeter,
+    ) -> Result<()> {
+        let _span = tracing::info_span!(
+            "t3.observe_sub_dimension",
+            name,
+            parent = parent.name(),
+            observer_id = %observer_id,
+            subject_id = %subject_id,
+            observed_score,
+        )
+        .entered();
+        self.observe_sub_dimension(name, parent, observed_score)?;
+        let sub = &self.sub_dimensions[name];
+        meter.record_observation(
+            "t3",
+            name,
+            "sub",
+            observed_score,
+            sub.score,
+            sub.weight,
+        );
+        Ok(())
+    }
+
     /// Compute the aggregate trust score (weighted geometric mean)
     ///
     /// Geometric mean ensures that a zero in any dimension zeros the total,
-    /// reflecting that trust requires all dimensions to be positive.
+    /// reflecting that trust requires all dimensions to be positive. Each
+    /// dimension's weight is scaled by its corroboration-depth multiplier, so
+    /// a dimension built from many independently-witnessed observations
+    /// dominates one resting on a single repeated observer. Dimensions never
+    /// fed through
+    /// [`observe_corroborated`](Self::observe_corroborated) get multiplier
+    /// `1.0`, leaving plain [`observe`](Self::observe) usage unaffected.
     pub fn aggregate(&self) -> f64 {
-        let total_weight: f64 = self.weights.iter().sum();
+        let corroborated_weights: [f64; T3_DIMENSIONS] =
+            std::array::from_fn(|i| self.weights[i] * self.confirmation[i].multiplier());
+
+        let total_weight: f64 = corroborated_weights.iter().sum();
         if total_weight == 0.0 {
             return 0.5; // No observations, return neutral
         }
@@ -223,7 +645,7 @@ impl T3 {
         let log_sum: f64 = self
             .dimensions
             .iter()
-            .zip(self.weights.iter())
+            .zip(corroborated_weights.iter())
             .map(|(score, weight)| {
                 // Add small epsilon to avoid log(0)
                 weight * (score + 1e-10).ln()
@@ -289,6 +711,17 @@ impl T3 {
         result
     }
 
+    /// Merge with another T3 and emit a `tracing` span plus
+    /// [`TensorMeter`](crate::telemetry::TensorMeter) metrics for the
+    /// resulting aggregate through `meter`.
+    #[cfg(feature = "telemetry")]
+    pub fn merge_instrumented(&self, other: &T3, meter: &crate::telemetry::TensorMeter) -> Self {
+        let _span = tracing::info_span!("t3.merge").entered();
+        let result = self.merge(other);
+        meter.record_aggregate("t3", result.aggregate());
+        result
+    }
+
     /// Apply time decay to the tensor
     ///
     /// Trust that isn't reinforced decays toward neutral (0.5) over time.
@@ -311,12 +744,44 @@ impl T3 {
         }
     }
 
+    /// Apply time decay and emit a `tracing` span plus
+    /// [`TensorMeter`](crate::telemetry::TensorMeter) metrics for the
+    /// resulting aggregate through `meter`.
+    #[cfg(feature = "telemetry")]
+    pub fn decay_instrumented(&mut self, decay_factor: f64, meter: &crate::telemetry::TensorMeter) {
+        let _span = tracing::info_span!("t3.decay", decay_factor).entered();
+        self.decay(decay_factor);
+        meter.record_aggregate("t3", self.aggregate());
+    }
+
     /// Check if trust meets minimum thresholds
+    ///
+    /// Uses [`effective_score`](Self::effective_score) so the fluctuation
+    /// penalty gates admission: an entity that recently turned malicious fails
+    /// the check even while its smooth EMA is still catching up. With no
+    /// interval history recorded this reduces to the stored dimension scores.
     pub fn meets_thresholds(&self, min_scores: &[f64; T3_DIMENSIONS]) -> bool {
-        self.dimensions
+        TrustDimension::all()
             .iter()
             .zip(min_scores.iter())
-            .all(|(score, min)| score >= min)
+            .all(|(&dim, min)| self.effective_score(dim) >= *min)
+    }
+
+    /// Like [`meets_thresholds`](Self::meets_thresholds), but additionally
+    /// requires each dimension to carry at least `min_depth` witnesses' worth
+    /// of corroborated evidence (see
+    /// [`confirmation_weight`](Self::confirmation_weight)). Guards against a
+    /// single observer repeating itself inflating a score nobody else has
+    /// confirmed.
+    pub fn meets_thresholds_corroborated(
+        &self,
+        min_scores: &[f64; T3_DIMENSIONS],
+        min_depth: usize,
+    ) -> bool {
+        self.meets_thresholds(min_scores)
+            && TrustDimension::all()
+                .iter()
+                .all(|&dim| self.confirmation_weight(dim, min_depth) > 0.0)
     }
 }
 
@@ -512,6 +977,110 @@ mod tests {
         assert!(!t3.meets_thresholds(&[0.9, 0.7, 0.6]));
     }
 
+    #[test]
+    fn test_confirmation_weight_buckets_by_depth() {
+        let mut t3 = T3::new();
+        t3.observe_corroborated(TrustDimension::Talent, 0.9, 0)
+            .unwrap();
+        t3.observe_corroborated(TrustDimension::Talent, 0.9, 3)
+            .unwrap();
+        t3.observe_corroborated(TrustDimension::Talent, 0.9, 3)
+            .unwrap();
+
+        assert_eq!(t3.confirmation_weight(TrustDimension::Talent, 0), 3.0);
+        assert_eq!(t3.confirmation_weight(TrustDimension::Talent, 3), 2.0);
+        assert_eq!(t3.confirmation_weight(TrustDimension::Talent, 4), 0.0);
+        // Never corroborated at all.
+        assert_eq!(t3.confirmation_weight(TrustDimension::Training, 0), 0.0);
+    }
+
+    #[test]
+    fn test_deep_corroboration_raises_dimension_weight_in_aggregate() {
+        // Same scores in both tensors; only Temperament's corroboration depth
+        // differs. A low, deeply-corroborated dimension should drag the
+        // aggregate down further than the same low score backed by a single
+        // repeat observer.
+        let mut shallow = T3::new();
+        let mut deep = T3::new();
+        for _ in 0..5 {
+            for t3 in [&mut shallow, &mut deep] {
+                t3.observe_corroborated(TrustDimension::Talent, 0.9, 0)
+                    .unwrap();
+                t3.observe_corroborated(TrustDimension::Training, 0.9, 0)
+                    .unwrap();
+            }
+            shallow
+                .observe_corroborated(TrustDimension::Temperament, 0.3, 0)
+                .unwrap();
+            deep.observe_corroborated(
+                TrustDimension::Temperament,
+                0.3,
+                CONFIRMATION_DEPTH_BUCKETS - 1,
+            )
+            .unwrap();
+        }
+
+        assert!(deep.aggregate() < shallow.aggregate());
+    }
+
+    #[test]
+    fn test_meets_thresholds_corroborated_requires_depth() {
+        let mut t3 = T3::with_scores([0.9, 0.9, 0.9]).unwrap();
+        for dim in TrustDimension::all() {
+            t3.observe_corroborated(dim, 0.9, 0).unwrap();
+        }
+
+        assert!(t3.meets_thresholds_corroborated(&[0.5, 0.5, 0.5], 0));
+        // No dimension has been corroborated by 2+ distinct witnesses yet.
+        assert!(!t3.meets_thresholds_corroborated(&[0.5, 0.5, 0.5], 2));
+
+        for dim in TrustDimension::all() {
+            t3.observe_corroborated(dim, 0.9, 2).unwrap();
+        }
+        assert!(t3.meets_thresholds_corroborated(&[0.5, 0.5, 0.5], 2));
+    }
+
+    #[test]
+    fn test_history_value_neutral_when_empty() {
+        let t3 = T3::new();
+        assert_eq!(t3.history_value(TrustDimension::Talent), 0.5);
+    }
+
+    #[test]
+    fn test_history_favours_recent_intervals() {
+        let mut t3 = T3::new();
+        let day = 86_400;
+
+        // Bad a while back, good lately — spread across distinct intervals.
+        t3.observe_at(TrustDimension::Talent, 0.2, 0).unwrap();
+        t3.observe_at(TrustDimension::Talent, 0.2, day).unwrap();
+        t3.observe_at(TrustDimension::Talent, 0.9, 5 * day).unwrap();
+        t3.observe_at(TrustDimension::Talent, 0.9, 6 * day).unwrap();
+
+        let history = t3.history_value(TrustDimension::Talent);
+        // Fading factor pulls the aggregate toward the recent good behaviour,
+        // above the flat mean of 0.55.
+        assert!(history > 0.55, "history {history} should favour recent good");
+        assert!(history < 0.9);
+    }
+
+    #[test]
+    fn test_history_rolls_forward_and_forgets() {
+        let mut t3 = T3::new();
+        let day = 86_400;
+
+        // One bad observation, then good observations far past the ring length.
+        t3.observe_at(TrustDimension::Talent, 0.1, 0).unwrap();
+        for i in 1..=TRUST_HISTORY_INTERVALS {
+            t3.observe_at(TrustDimension::Talent, 0.9, (i as i64 + 20) * day)
+                .unwrap();
+        }
+
+        // The ancient bad interval has rolled off the ring entirely.
+        let history = t3.history_value(TrustDimension::Talent);
+        assert!((history - 0.9).abs() < 1e-9);
+    }
+
     #[test]
     fn test_sub_dimension_observation() {
         let mut t3 = T3::new();
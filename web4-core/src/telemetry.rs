@@ -0,0 +1,90 @@
+// Copyright (c) 2026 MetaLINXX Inc.
+// SPDX-License-Identifier: MIT
+//
+// This software is covered by US Patents 11,477,027 and 12,278,913,
+// and pending application 19/178,619. See PATENTS.md for details.
+
+//! OpenTelemetry instrumentation for `T3`/`V3` tensor updates and
+//! `TrustValueScore` combination (behind the `telemetry` feature).
+//!
+//! Plain tensor updates are silent in-memory float arithmetic — an
+//! operator watching a running Web4 service has no way to see how an
+//! entity's trust or value is evolving short of periodically snapshotting
+//! it. [`TensorMeter`] wraps an [`opentelemetry::metrics::Meter`] with the
+//! instruments this crate cares about; callers install one meter (from
+//! whatever exporter their service already runs) and pass it to the
+//! `_instrumented` sibling of each hot-path method (`observe`,
+//! `observe_sub_dimension`, `merge`, `decay`, `combined`).
+//!
+//! This crate depends only on the `opentelemetry` API crate, not a
+//! specific exporter — wiring up OTLP, Prometheus, stdout, or anything
+//! else is the host application's job.
+
+use opentelemetry::metrics::{Counter, Histogram, Meter};
+use opentelemetry::KeyValue;
+
+/// Instruments recording `T3`/`V3` tensor updates, keyed by `tensor`
+/// (`"t3"`/`"v3"`), `dimension` name, and `scope` (`"root"`/`"sub"`).
+///
+/// There is no OTel "gauge" API for synchronous recording at a call site
+/// (gauges are observable/async-callback based), so `score`/`weight`/
+/// `aggregate`/`combined` — point-in-time values rather than increments —
+/// are recorded as single-sample histograms instead; an exporter can still
+/// chart their latest value per dimension.
+pub struct TensorMeter {
+    observations_total: Counter<u64>,
+    observed_score: Histogram<f64>,
+    score: Histogram<f64>,
+    weight: Histogram<f64>,
+    aggregate: Histogram<f64>,
+    combined: Histogram<f64>,
+}
+
+impl TensorMeter {
+    /// Build the instrument set on top of a caller-supplied [`Meter`].
+    pub fn new(meter: &Meter) -> Self {
+        Self {
+            observations_total: meter.u64_counter("web4.tensor.observations_total").build(),
+            observed_score: meter.f64_histogram("web4.tensor.observed_score").build(),
+            score: meter.f64_histogram("web4.tensor.score").build(),
+            weight: meter.f64_histogram("web4.tensor.weight").build(),
+            aggregate: meter.f64_histogram("web4.tensor.aggregate").build(),
+            combined: meter.f64_histogram("web4.tensor.combined").build(),
+        }
+    }
+
+    /// Record an `observe`/`observe_sub_dimension` call: increments
+    /// `web4.tensor.observations_total` and records the raw observed score
+    /// plus the resulting score/weight, all labelled by `tensor`,
+    /// `dimension`, and `scope`.
+    pub(crate) fn record_observation(
+        &self,
+        tensor: &'static str,
+        dimension: &str,
+        scope: &'static str,
+        observed_score: f64,
+        resulting_score: f64,
+        resulting_weight: f64,
+    ) {
+        let attrs = [
+            KeyValue::new("tensor", tensor),
+            KeyValue::new("dimension", dimension.to_string()),
+            KeyValue::new("scope", scope),
+        ];
+        self.observations_total.add(1, &attrs);
+        self.observed_score.record(observed_score, &attrs);
+        self.score.record(resulting_score, &attrs);
+        self.weight.record(resulting_weight, &attrs);
+    }
+
+    /// Record the aggregate score resulting from a `merge`/`decay` call.
+    pub(crate) fn record_aggregate(&self, tensor: &'static str, aggregate: f64) {
+        self.aggregate
+            .record(aggregate, &[KeyValue::new("tensor", tensor)]);
+    }
+
+    /// Record a [`TrustValueScore::combined`](crate::v3::TrustValueScore::combined) call.
+    pub(crate) fn record_combined(&self, combined: f64) {
+        self.combined.record(combined, &[]);
+    }
+}
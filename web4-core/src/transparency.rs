@@ -0,0 +1,502 @@
+// Copyright (c) 2026 MetaLINXX Inc.
+// SPDX-License-Identifier: AGPL-3.0-only
+//
+// This software is covered by US Patents 11,477,027 and 12,278,913,
+// and pending application 19/178,619. A royalty-free license is granted
+// under AGPL-3.0 terms for non-commercial and research use.
+// For commercial licensing: dp@metalinxx.io
+// See PATENTS.md for details.
+
+//! Append-only transparency log with Merkle inclusion/consistency proofs
+//! for LCT issuance.
+//!
+//! An issuer (or a compromised one) can mint two conflicting LCTs with the
+//! same identity and nothing in [`crate::lct`] would notice — equivocation
+//! is invisible to the entities who trust the issuer. [`LctTransparencyLog`]
+//! gives issuance a tamper-evident, publicly-auditable home: every issued
+//! LCT becomes a leaf in an append-only Merkle tree, and the log operator
+//! periodically signs a Signed Tree Head ([`SignedTreeHead`]) over the
+//! current root. A verifier holding only a published STH can still prove a
+//! specific LCT is committed under it ([`verify_inclusion`]) or that a
+//! newer STH is an append-only extension of an older one
+//! ([`verify_consistency`]), without trusting the log operator further than
+//! its signing key.
+//!
+//! The tree follows the RFC 6962 Merkle Tree Hash construction: leaves are
+//! domain-separated with a `0x00` prefix and internal nodes with `0x01`, and
+//! a range of `n` leaves is split at the largest power of two strictly less
+//! than `n` — the split that makes every earlier root a genuine subtree of
+//! every later one, which is what makes consistency proofs possible.
+
+use std::collections::VecDeque;
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::crypto::{sha256, KeyPair, PublicKey, SignatureBytes};
+use crate::error::{Result, Web4Error};
+
+/// Hash a leaf input `d` as `H(0x00 || d)`, per RFC 6962 §2.1.
+pub(crate) fn leaf_hash(data: &[u8]) -> [u8; 32] {
+    let mut buf = Vec::with_capacity(data.len() + 1);
+    buf.push(0x00);
+    buf.extend_from_slice(data);
+    sha256(&buf)
+}
+
+/// Hash an internal node as `H(0x01 || left || right)`, per RFC 6962 §2.1.
+fn node_hash(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut buf = Vec::with_capacity(65);
+    buf.push(0x01);
+    buf.extend_from_slice(left);
+    buf.extend_from_slice(right);
+    sha256(&buf)
+}
+
+/// The largest power of two strictly less than `n`. Only called with `n > 1`.
+fn split_point(n: usize) -> usize {
+    let mut k = 1;
+    while k * 2 < n {
+        k *= 2;
+    }
+    k
+}
+
+/// Merkle Tree Hash of a leaf-hash slice, per RFC 6962 §2.1.
+pub(crate) fn merkle_root(leaves: &[[u8; 32]]) -> [u8; 32] {
+    match leaves.len() {
+        0 => sha256(&[]),
+        1 => leaves[0],
+        n => {
+            let k = split_point(n);
+            node_hash(&merkle_root(&leaves[..k]), &merkle_root(&leaves[k..]))
+        }
+    }
+}
+
+/// The audit path for leaf `index`, per RFC 6962's `PATH` algorithm.
+pub(crate) fn build_path(index: usize, leaves: &[[u8; 32]]) -> Vec<[u8; 32]> {
+    let n = leaves.len();
+    if n <= 1 {
+        return Vec::new();
+    }
+    let k = split_point(n);
+    if index < k {
+        let mut path = build_path(index, &leaves[..k]);
+        path.push(merkle_root(&leaves[k..]));
+        path
+    } else {
+        let mut path = build_path(index - k, &leaves[k..]);
+        path.push(merkle_root(&leaves[..k]));
+        path
+    }
+}
+
+/// Recompute the root implied by an audit path, mirroring [`build_path`]'s
+/// recursion so the two stay in lockstep. Returns `None` if `proof` is the
+/// wrong length for `(index, tree_size)`.
+pub(crate) fn recompute_root(
+    index: usize,
+    tree_size: usize,
+    leaf: [u8; 32],
+    proof: &mut std::slice::Iter<[u8; 32]>,
+) -> Option<[u8; 32]> {
+    if tree_size <= 1 {
+        return Some(leaf);
+    }
+    let k = split_point(tree_size);
+    if index < k {
+        let left = recompute_root(index, k, leaf, proof)?;
+        let right = proof.next()?;
+        Some(node_hash(&left, right))
+    } else {
+        let right = recompute_root(index - k, tree_size - k, leaf, proof)?;
+        let left = proof.next()?;
+        Some(node_hash(left, &right))
+    }
+}
+
+/// The subproof construction behind [`LctTransparencyLog::consistency_proof`],
+/// per RFC 6962's `SUBPROOF` algorithm. `b` tracks whether this subrange is
+/// still an exact, untouched prefix of the old tree (in which case no proof
+/// element is needed — the caller already knows its hash as `old_root`).
+fn build_subproof(m: usize, leaves: &[[u8; 32]], b: bool) -> Vec<[u8; 32]> {
+    let n = leaves.len();
+    if m == n {
+        if b {
+            Vec::new()
+        } else {
+            vec![merkle_root(leaves)]
+        }
+    } else {
+        let k = split_point(n);
+        if m <= k {
+            let mut proof = build_subproof(m, &leaves[..k], b);
+            proof.push(merkle_root(&leaves[k..]));
+            proof
+        } else {
+            let mut proof = build_subproof(m - k, &leaves[k..], false);
+            proof.push(merkle_root(&leaves[..k]));
+            proof
+        }
+    }
+}
+
+/// Mirrors [`build_subproof`] to recompute `(old_subrange_root, new_subrange_root)`
+/// for verification, consuming proof elements in the same order they were
+/// produced.
+fn verify_subproof(
+    m: usize,
+    n: usize,
+    b: bool,
+    old_root: [u8; 32],
+    proof: &mut VecDeque<[u8; 32]>,
+) -> Option<([u8; 32], [u8; 32])> {
+    if m == n {
+        if b {
+            Some((old_root, old_root))
+        } else {
+            let h = proof.pop_front()?;
+            Some((h, h))
+        }
+    } else {
+        let k = split_point(n);
+        if m <= k {
+            let (old_l, new_l) = verify_subproof(m, k, b, old_root, proof)?;
+            let right = proof.pop_front()?;
+            Some((old_l, node_hash(&new_l, &right)))
+        } else {
+            let (old_r, new_r) = verify_subproof(m - k, n - k, false, old_root, proof)?;
+            let left = proof.pop_front()?;
+            Some((node_hash(&left, &old_r), node_hash(&left, &new_r)))
+        }
+    }
+}
+
+/// The leaf an issued LCT contributes to an [`LctTransparencyLog`]:
+/// `sha256(public_key || id || created_by)`. `created_by` is omitted from
+/// the input entirely for root LCTs (`None`), rather than hashing a
+/// placeholder, so a root LCT's leaf can't collide with a child LCT whose
+/// `created_by` happens to be all zero bytes.
+pub fn lct_leaf(public_key: &PublicKey, id: Uuid, created_by: Option<Uuid>) -> [u8; 32] {
+    let mut buf = Vec::with_capacity(32 + 16 + 16);
+    buf.extend_from_slice(&public_key.to_bytes());
+    buf.extend_from_slice(id.as_bytes());
+    if let Some(created_by) = created_by {
+        buf.extend_from_slice(created_by.as_bytes());
+    }
+    leaf_hash(&buf)
+}
+
+/// A Signed Tree Head: the log operator's attestation that the tree had
+/// `tree_size` leaves and `root_hash` as its root at the time of signing.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SignedTreeHead {
+    /// Number of leaves committed to the tree.
+    pub tree_size: usize,
+    /// The tree's Merkle root at `tree_size`.
+    pub root_hash: [u8; 32],
+    /// Signature over `(tree_size, root_hash)`, produced with the log
+    /// operator's private key.
+    pub signature: SignatureBytes,
+}
+
+impl SignedTreeHead {
+    fn canonical_bytes(tree_size: usize, root_hash: &[u8; 32]) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(8 + 32);
+        buf.extend_from_slice(&(tree_size as u64).to_be_bytes());
+        buf.extend_from_slice(root_hash);
+        buf
+    }
+
+    fn sign(tree_size: usize, root_hash: [u8; 32], operator_keypair: &KeyPair) -> Self {
+        let signature = operator_keypair.sign(&Self::canonical_bytes(tree_size, &root_hash));
+        Self {
+            tree_size,
+            root_hash,
+            signature,
+        }
+    }
+
+    /// Verify this STH was signed by `operator_key`.
+    pub fn verify(&self, operator_key: &PublicKey) -> Result<()> {
+        let bytes = Self::canonical_bytes(self.tree_size, &self.root_hash);
+        operator_key
+            .verify(&bytes, &self.signature)
+            .map_err(|e| Web4Error::TransparencyInvalid(format!("STH signature invalid: {}", e)))
+    }
+}
+
+/// The audit path proving a leaf is committed under a [`SignedTreeHead`]'s
+/// root, returned by [`LctTransparencyLog::prove_inclusion`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct InclusionProof {
+    /// Sibling hashes from the leaf up to the root.
+    pub audit_path: Vec<[u8; 32]>,
+}
+
+/// An append-only Merkle log of issued LCTs' leaves (see [`lct_leaf`]).
+///
+/// Leaves go in with [`append`](Self::append); [`sign_tree_head`](Self::sign_tree_head)
+/// is what the log operator publishes. Anyone holding a published
+/// [`SignedTreeHead`] can later check a specific LCT is committed under it
+/// ([`verify_inclusion`]) or that a newer STH is a strict extension of an
+/// older one ([`verify_consistency`]), without trusting the log operator
+/// beyond its signing key.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct LctTransparencyLog {
+    leaves: Vec<[u8; 32]>,
+}
+
+impl LctTransparencyLog {
+    /// Create an empty log.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of leaves (issued LCTs) committed so far.
+    pub fn len(&self) -> usize {
+        self.leaves.len()
+    }
+
+    /// Whether the log has no leaves yet.
+    pub fn is_empty(&self) -> bool {
+        self.leaves.is_empty()
+    }
+
+    /// Append `leaf`, returning its index in the tree.
+    pub fn append(&mut self, leaf: [u8; 32]) -> usize {
+        self.leaves.push(leaf);
+        self.leaves.len() - 1
+    }
+
+    /// The current Merkle root.
+    pub fn root(&self) -> [u8; 32] {
+        merkle_root(&self.leaves)
+    }
+
+    /// Sign the current tree size and root with the log operator's key.
+    pub fn sign_tree_head(&self, operator_keypair: &KeyPair) -> SignedTreeHead {
+        SignedTreeHead::sign(self.len(), self.root(), operator_keypair)
+    }
+
+    /// The audit path proving leaf `leaf_index` is committed under
+    /// [`root`](Self::root). `None` if `leaf_index` is out of range.
+    pub fn prove_inclusion(&self, leaf_index: usize) -> Option<InclusionProof> {
+        if leaf_index >= self.leaves.len() {
+            return None;
+        }
+        Some(InclusionProof {
+            audit_path: build_path(leaf_index, &self.leaves),
+        })
+    }
+
+    /// A proof that the first `old_size` leaves of the tree at `new_size`
+    /// are exactly the tree that existed when it had `old_size` leaves —
+    /// i.e. that growing from `old_size` to `new_size` only appended.
+    ///
+    /// Returns an empty proof for the trivial cases (`old_size == 0` or
+    /// `old_size == new_size`), which need no evidence.
+    pub fn consistency_proof(&self, old_size: usize, new_size: usize) -> Vec<[u8; 32]> {
+        if old_size == 0 || old_size == new_size || new_size == 0 {
+            return Vec::new();
+        }
+        let size = new_size.min(self.leaves.len());
+        build_subproof(old_size, &self.leaves[..size], true)
+    }
+}
+
+/// Verify an inclusion proof produced by [`LctTransparencyLog::prove_inclusion`]
+/// against a [`SignedTreeHead`] signed by `operator_key`.
+pub fn verify_inclusion(
+    leaf: [u8; 32],
+    index: usize,
+    proof: &InclusionProof,
+    sth: &SignedTreeHead,
+    operator_key: &PublicKey,
+) -> Result<()> {
+    sth.verify(operator_key)?;
+
+    if index >= sth.tree_size {
+        return Err(Web4Error::TransparencyInvalid(
+            "leaf index out of range for the signed tree size".into(),
+        ));
+    }
+
+    let mut it = proof.audit_path.iter();
+    let recomputed = recompute_root(index, sth.tree_size, leaf, &mut it);
+    if it.next().is_some() || recomputed != Some(sth.root_hash) {
+        return Err(Web4Error::TransparencyInvalid(
+            "inclusion proof does not recompute the signed root".into(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Verify a consistency proof produced by [`LctTransparencyLog::consistency_proof`]:
+/// that `new_sth` is an append-only extension of `old_sth`, never a
+/// rewrite, given both were signed by `operator_key`.
+pub fn verify_consistency(
+    old_sth: &SignedTreeHead,
+    new_sth: &SignedTreeHead,
+    proof: &[[u8; 32]],
+    operator_key: &PublicKey,
+) -> Result<()> {
+    old_sth.verify(operator_key)?;
+    new_sth.verify(operator_key)?;
+
+    if old_sth.tree_size > new_sth.tree_size {
+        return Err(Web4Error::TransparencyInvalid(
+            "old tree is larger than the new tree".into(),
+        ));
+    }
+    if old_sth.tree_size == 0 {
+        return Ok(());
+    }
+    if old_sth.tree_size == new_sth.tree_size {
+        return if proof.is_empty() && old_sth.root_hash == new_sth.root_hash {
+            Ok(())
+        } else {
+            Err(Web4Error::TransparencyInvalid(
+                "equal-size trees have different roots".into(),
+            ))
+        };
+    }
+
+    let mut queue: VecDeque<[u8; 32]> = proof.iter().copied().collect();
+    match verify_subproof(
+        old_sth.tree_size,
+        new_sth.tree_size,
+        true,
+        old_sth.root_hash,
+        &mut queue,
+    ) {
+        Some((computed_old, computed_new))
+            if queue.is_empty()
+                && computed_old == old_sth.root_hash
+                && computed_new == new_sth.root_hash =>
+        {
+            Ok(())
+        }
+        _ => Err(Web4Error::TransparencyInvalid(
+            "consistency proof does not recompute both roots".into(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::KeyPair;
+
+    fn lct_leaf_n(n: u64) -> [u8; 32] {
+        let keypair = KeyPair::from_secret_bytes(&sha256(format!("lct-{}", n).as_bytes()));
+        lct_leaf(&keypair.verifying_key(), Uuid::new_v4(), None)
+    }
+
+    #[test]
+    fn test_append_returns_growing_index() {
+        let mut log = LctTransparencyLog::new();
+        assert_eq!(log.append(lct_leaf_n(0)), 0);
+        assert_eq!(log.append(lct_leaf_n(1)), 1);
+        assert_eq!(log.append(lct_leaf_n(2)), 2);
+        assert_eq!(log.len(), 3);
+    }
+
+    #[test]
+    fn test_inclusion_proof_verifies_for_every_leaf() {
+        let mut log = LctTransparencyLog::new();
+        let operator = KeyPair::generate();
+        let leaves: Vec<[u8; 32]> = (0..13)
+            .map(|n| {
+                let leaf = lct_leaf_n(n);
+                log.append(leaf);
+                leaf
+            })
+            .collect();
+
+        let sth = log.sign_tree_head(&operator);
+        for (index, leaf) in leaves.iter().enumerate() {
+            let proof = log.prove_inclusion(index).unwrap();
+            assert!(verify_inclusion(*leaf, index, &proof, &sth, &operator.verifying_key()).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_inclusion_proof_rejects_wrong_leaf_or_operator() {
+        let mut log = LctTransparencyLog::new();
+        let operator = KeyPair::generate();
+        let wrong_operator = KeyPair::generate();
+        for n in 0..7 {
+            log.append(lct_leaf_n(n));
+        }
+        let sth = log.sign_tree_head(&operator);
+        let proof = log.prove_inclusion(3).unwrap();
+
+        assert!(verify_inclusion(
+            lct_leaf_n(99),
+            3,
+            &proof,
+            &sth,
+            &operator.verifying_key()
+        )
+        .is_err());
+
+        assert!(verify_inclusion(
+            lct_leaf_n(3),
+            3,
+            &proof,
+            &sth,
+            &wrong_operator.verifying_key()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_consistency_proof_across_growth_sizes() {
+        let mut log = LctTransparencyLog::new();
+        let operator = KeyPair::generate();
+        let mut sths = Vec::new();
+        for n in 0..20 {
+            log.append(lct_leaf_n(n));
+            sths.push(log.sign_tree_head(&operator));
+        }
+
+        for old_sth in &sths {
+            for new_sth in &sths {
+                if new_sth.tree_size < old_sth.tree_size {
+                    continue;
+                }
+                let proof = log.consistency_proof(old_sth.tree_size, new_sth.tree_size);
+                assert!(
+                    verify_consistency(old_sth, new_sth, &proof, &operator.verifying_key()).is_ok(),
+                    "consistency failed for old_size={} new_size={}",
+                    old_sth.tree_size,
+                    new_sth.tree_size
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_consistency_proof_rejects_rewritten_history() {
+        let mut log = LctTransparencyLog::new();
+        let operator = KeyPair::generate();
+        for n in 0..5 {
+            log.append(lct_leaf_n(n));
+        }
+        let old_sth = log.sign_tree_head(&operator);
+
+        // Simulate a rewrite: mutate a leaf already committed in the old
+        // tree (not merely append past it), then grow the log further.
+        log.leaves[2] = sha256(b"tampered");
+        for n in 5..10 {
+            log.append(lct_leaf_n(n));
+        }
+        let new_sth = log.sign_tree_head(&operator);
+
+        let proof = log.consistency_proof(old_sth.tree_size, new_sth.tree_size);
+        assert!(verify_consistency(&old_sth, &new_sth, &proof, &operator.verifying_key()).is_err());
+    }
+}
@@ -0,0 +1,230 @@
+// Copyright (c) 2026 MetaLINXX Inc.
+// SPDX-License-Identifier: MIT
+//
+// This software is covered by US Patents 11,477,027 and 12,278,913,
+// and pending application 19/178,619. See PATENTS.md for details.
+
+//! Network-level trust aggregation over many [`TrustRelation`](crate::t3::TrustRelation)s.
+//!
+//! A [`T3`] tensor is a direct, per-pair observation: what one observer thinks
+//! of one subject. [`TrustRegistry`] builds a reputation-style estimate on top
+//! of that by collecting every observer's tensor for a subject and combining
+//! them with a stake-weighted geometric mean, where an observer's stake is its
+//! own aggregate trust in the network. This keeps a swarm of sybil observers
+//! with near-zero trust from meaningfully moving a subject's score, since their
+//! contribution is weighted by how little the network trusts them.
+
+use crate::t3::{TrustDimension, T3, T3_DIMENSIONS};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// Per-subject, stake-weighted network trust.
+#[derive(Clone, Debug, Default)]
+pub struct TrustRegistry {
+    /// Every observer's tensor for a subject, keyed by subject LCT ID.
+    observations: HashMap<Uuid, Vec<(Uuid, T3)>>,
+    /// Each observer's stake (its own aggregate trust), keyed by observer LCT ID.
+    stakes: HashMap<Uuid, f64>,
+    /// Sum of all known observer stakes, kept in step with `stakes`.
+    total_stake: f64,
+}
+
+/// Stake-weighted trust estimate for a subject.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NetworkTrust {
+    /// Stake-weighted geometric mean per root dimension.
+    pub dimensions: [f64; T3_DIMENSIONS],
+    /// Stake-weighted geometric mean across all root dimensions.
+    pub aggregate: f64,
+    /// Fraction of total network stake that has observed this subject,
+    /// `0.0` when the subject has never been observed.
+    pub confidence: f64,
+}
+
+impl TrustRegistry {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set (or update) an observer's stake — its own aggregate trust in the
+    /// network. Stake is looked up at aggregation time rather than copied
+    /// into each entry, so it may be set before or after
+    /// [`observe`](Self::observe), and updating it re-weights all of that
+    /// observer's past contributions too.
+    pub fn set_stake(&mut self, observer_id: Uuid, stake: f64) {
+        let previous = self.stakes.insert(observer_id, stake).unwrap_or(0.0);
+        self.total_stake += stake - previous;
+    }
+
+    /// An observer's current stake, `0.0` if never set.
+    pub fn stake(&self, observer_id: Uuid) -> f64 {
+        self.stakes.get(&observer_id).copied().unwrap_or(0.0)
+    }
+
+    /// Sum of every known observer's stake.
+    pub fn total_stake(&self) -> f64 {
+        self.total_stake
+    }
+
+    /// Record `observer_id`'s trust tensor for `subject_id`.
+    ///
+    /// Replaces any tensor the same observer previously recorded for this
+    /// subject, so a peer's contribution always reflects its latest view.
+    pub fn observe(&mut self, subject_id: Uuid, observer_id: Uuid, tensor: T3) {
+        let entries = self.observations.entry(subject_id).or_default();
+        match entries.iter_mut().find(|(id, _)| *id == observer_id) {
+            Some((_, existing)) => *existing = tensor,
+            None => entries.push((observer_id, tensor)),
+        }
+    }
+
+    /// Stake-weighted network trust estimate for `subject_id`.
+    ///
+    /// `None` if nobody has observed this subject. Observers with zero stake
+    /// contribute nothing to `dimensions`/`aggregate` but still count toward
+    /// `confidence`'s denominator via `total_stake`.
+    pub fn aggregate_trust(&self, subject_id: Uuid) -> Option<NetworkTrust> {
+        let entries = self.observations.get(&subject_id)?;
+        if entries.is_empty() {
+            return None;
+        }
+
+        let mut observed_stake = 0.0;
+        let mut log_sums = [0.0; T3_DIMENSIONS];
+        let mut weight_sums = [0.0; T3_DIMENSIONS];
+
+        for (observer_id, tensor) in entries {
+            let stake = self.stake(*observer_id);
+            observed_stake += stake;
+            if stake <= 0.0 {
+                continue;
+            }
+            for dim in TrustDimension::all() {
+                let idx = dim as usize;
+                log_sums[idx] += stake * (tensor.score(dim) + 1e-10).ln();
+                weight_sums[idx] += stake;
+            }
+        }
+
+        let mut dimensions = [0.5; T3_DIMENSIONS];
+        let mut log_sum_total = 0.0;
+        let mut weight_total = 0.0;
+        for idx in 0..T3_DIMENSIONS {
+            if weight_sums[idx] > 0.0 {
+                dimensions[idx] = (log_sums[idx] / weight_sums[idx]).exp();
+                log_sum_total += log_sums[idx];
+                weight_total += weight_sums[idx];
+            }
+        }
+
+        let aggregate = if weight_total > 0.0 {
+            (log_sum_total / weight_total).exp()
+        } else {
+            0.5 // Only zero-stake observers — no weight to form an opinion.
+        };
+
+        let confidence = if self.total_stake > 0.0 {
+            (observed_stake / self.total_stake).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+
+        Some(NetworkTrust {
+            dimensions,
+            aggregate,
+            confidence,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tensor_with(scores: [f64; T3_DIMENSIONS]) -> T3 {
+        T3::with_scores(scores).unwrap()
+    }
+
+    #[test]
+    fn test_unobserved_subject_returns_none() {
+        let registry = TrustRegistry::new();
+        assert!(registry.aggregate_trust(Uuid::new_v4()).is_none());
+    }
+
+    #[test]
+    fn test_single_observer_matches_its_tensor() {
+        let mut registry = TrustRegistry::new();
+        let observer = Uuid::new_v4();
+        let subject = Uuid::new_v4();
+
+        registry.set_stake(observer, 1.0);
+        registry.observe(subject, observer, tensor_with([0.8, 0.7, 0.6]));
+
+        let trust = registry.aggregate_trust(subject).unwrap();
+        assert!((trust.dimensions[0] - 0.8).abs() < 1e-6);
+        assert!((trust.confidence - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_high_stake_observer_dominates_low_stake_observer() {
+        let mut registry = TrustRegistry::new();
+        let whale = Uuid::new_v4();
+        let sybil = Uuid::new_v4();
+        let subject = Uuid::new_v4();
+
+        registry.set_stake(whale, 100.0);
+        registry.set_stake(sybil, 0.001);
+        registry.observe(subject, whale, tensor_with([0.9, 0.9, 0.9]));
+        registry.observe(subject, sybil, tensor_with([0.0, 0.0, 0.0]));
+
+        let trust = registry.aggregate_trust(subject).unwrap();
+        // The whale's opinion should dominate despite the sybil's zero score.
+        assert!(trust.aggregate > 0.8);
+    }
+
+    #[test]
+    fn test_zero_stake_observer_does_not_move_score() {
+        let mut registry = TrustRegistry::new();
+        let real = Uuid::new_v4();
+        let sybil = Uuid::new_v4();
+        let subject = Uuid::new_v4();
+
+        registry.set_stake(real, 1.0);
+        registry.set_stake(sybil, 0.0);
+        registry.observe(subject, real, tensor_with([0.9, 0.9, 0.9]));
+        registry.observe(subject, sybil, tensor_with([0.0, 0.0, 0.0]));
+
+        let trust = registry.aggregate_trust(subject).unwrap();
+        assert!((trust.aggregate - 0.9).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_confidence_reflects_observed_fraction_of_stake() {
+        let mut registry = TrustRegistry::new();
+        let a = Uuid::new_v4();
+        let b = Uuid::new_v4();
+        let subject = Uuid::new_v4();
+
+        registry.set_stake(a, 3.0);
+        registry.set_stake(b, 1.0);
+        registry.observe(subject, a, tensor_with([0.6, 0.6, 0.6]));
+
+        let trust = registry.aggregate_trust(subject).unwrap();
+        assert!((trust.confidence - 0.75).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_re_observing_replaces_previous_tensor() {
+        let mut registry = TrustRegistry::new();
+        let observer = Uuid::new_v4();
+        let subject = Uuid::new_v4();
+
+        registry.set_stake(observer, 1.0);
+        registry.observe(subject, observer, tensor_with([0.2, 0.2, 0.2]));
+        registry.observe(subject, observer, tensor_with([0.8, 0.8, 0.8]));
+
+        let trust = registry.aggregate_trust(subject).unwrap();
+        assert!((trust.dimensions[0] - 0.8).abs() < 1e-6);
+    }
+}
@@ -27,6 +27,10 @@ pub const V3_DIMENSIONS: usize = 3;
 
 /// The three root dimensions of value
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "scale",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode, scale_info::TypeInfo)
+)]
 #[repr(usize)]
 pub enum ValueDimension {
     /// Subjective worth as perceived by recipients
@@ -169,6 +173,39 @@ impl V3 {
         Ok(())
     }
 
+    /// Record a root-dimension observation and emit a `tracing` span plus
+    /// [`TensorMeter`](crate::telemetry::TensorMeter) metrics through
+    /// `meter`. `observer_id`/`contributor_id` are carried on the span
+    /// only — `V3` itself doesn't store per-observation provenance.
+    #[cfg(feature = "telemetry")]
+    pub fn observe_instrumented(
+        &mut self,
+        dimension: ValueDimension,
+        observed_score: f64,
+        observer_id: Uuid,
+        contributor_id: Uuid,
+        meter: &crate::telemetry::TensorMeter,
+    ) -> Result<()> {
+        let _span = tracing::info_span!(
+            "v3.observe",
+            dimension = dimension.name(),
+            observer_id = %observer_id,
+            contributor_id = %contributor_id,
+            observed_score,
+        )
+        .entered();
+        self.observe(dimension, observed_score)?;
+        meter.record_observation(
+            "v3",
+            dimension.name(),
+            "root",
+            observed_score,
+            self.score(dimension),
+            self.weight(dimension),
+        );
+        Ok(())
+    }
+
     /// Record an observation for a sub-dimension
     pub fn observe_sub_dimension(
         &mut self,
@@ -200,6 +237,35 @@ impl V3 {
         Ok(())
     }
 
+    /// Record a sub-dimension observation and emit a `tracing` span plus
+    /// [`TensorMeter`](crate::telemetry::TensorMeter) metrics through
+    /// `meter`. See [`Self::observe_instrumented`] for the root-dimension
+    /// equivalent.
+    #[cfg(feature = "telemetry")]
+    pub fn observe_sub_dimension_instrumented(
+        &mut self,
+        name: &str,
+        parent: ValueDimension,
+        observed_score: f64,
+        observer_id: Uuid,
+        contributor_id: Uuid,
+        meter: &crate::telemetry::TensorMeter,
+    ) -> Result<()> {
+        let _span = tracing::info_span!(
+            "v3.observe_sub_dimension",
+            name,
+            parent = parent.name(),
+            observer_id = %observer_id,
+            contributor_id = %contributor_id,
+            observed_score,
+        )
+        .entered();
+        self.observe_sub_dimension(name, parent, observed_score)?;
+        let sub = &self.sub_dimensions[name];
+        meter.record_observation("v3", name, "sub", observed_score, sub.score, sub.weight);
+        Ok(())
+    }
+
     /// Compute the aggregate value score (weighted arithmetic mean)
     ///
     /// Unlike trust (geometric mean), value uses arithmetic mean because
@@ -284,6 +350,17 @@ impl V3 {
         result
     }
 
+    /// Merge with another V3 and emit a `tracing` span plus
+    /// [`TensorMeter`](crate::telemetry::TensorMeter) metrics for the
+    /// resulting aggregate through `meter`.
+    #[cfg(feature = "telemetry")]
+    pub fn merge_instrumented(&self, other: &V3, meter: &crate::telemetry::TensorMeter) -> Self {
+        let _span = tracing::info_span!("v3.merge").entered();
+        let result = self.merge(other);
+        meter.record_aggregate("v3", result.aggregate());
+        result
+    }
+
     /// Apply time decay
     pub fn decay(&mut self, decay_factor: f64) {
         for i in 0..V3_DIMENSIONS {
@@ -298,6 +375,463 @@ impl V3 {
             sub.weight *= decay_factor;
         }
     }
+
+    /// Apply time decay and emit a `tracing` span plus
+    /// [`TensorMeter`](crate::telemetry::TensorMeter) metrics for the
+    /// resulting aggregate through `meter`.
+    #[cfg(feature = "telemetry")]
+    pub fn decay_instrumented(&mut self, decay_factor: f64, meter: &crate::telemetry::TensorMeter) {
+        let _span = tracing::info_span!("v3.decay", decay_factor).entered();
+        self.decay(decay_factor);
+        meter.record_aggregate("v3", self.aggregate());
+    }
+
+    /// Serialize this tensor as RDF/Turtle, materializing the ontology the
+    /// module docs describe: each root [`ValueDimension`] becomes a
+    /// `web4:` resource, and each sub-dimension a blank node linked to its
+    /// parent via `web4:subDimensionOf`.
+    ///
+    /// Only emits the subset this type actually represents — it is a
+    /// self-contained serialization, not a general-purpose Turtle writer.
+    #[cfg(feature = "rdf")]
+    pub fn to_turtle(&self) -> String {
+        rdf::to_turtle(self)
+    }
+
+    /// Parse the Turtle produced by [`V3::to_turtle`] back into a `V3`.
+    ///
+    /// This is the inverse of `to_turtle` for its own output, not a
+    /// general Turtle parser — it expects exactly the triple shapes
+    /// `to_turtle` emits.
+    #[cfg(feature = "rdf")]
+    pub fn from_turtle(turtle: &str) -> Result<Self> {
+        rdf::from_turtle(turtle)
+    }
+
+    /// Serialize this tensor as PROV-flavored JSON-LD using the same
+    /// `web4:` vocabulary as [`V3::to_turtle`].
+    #[cfg(feature = "rdf")]
+    pub fn to_jsonld(&self) -> serde_json::Value {
+        rdf::to_jsonld(self)
+    }
+
+    /// Parse the JSON-LD produced by [`V3::to_jsonld`] back into a `V3`.
+    #[cfg(feature = "rdf")]
+    pub fn from_jsonld(value: &serde_json::Value) -> Result<Self> {
+        rdf::from_jsonld(value)
+    }
+
+    /// Record a sub-dimension observation, validating `name` against
+    /// `registry` first.
+    ///
+    /// If `name` is registered but under a different parent than `parent`,
+    /// this always errors — that's a real conflict, not an unregistered
+    /// name. If `name` isn't registered at all: in `strict` mode this
+    /// errors rather than silently coining a new sub-dimension; otherwise
+    /// it proceeds and returns [`SubDimensionValidation::Unregistered`] so
+    /// the caller can flag it (e.g. log a warning) without losing the
+    /// observation.
+    pub fn observe_sub_dimension_validated(
+        &mut self,
+        name: &str,
+        parent: ValueDimension,
+        observed_score: f64,
+        registry: &SubDimensionRegistry,
+        strict: bool,
+    ) -> Result<SubDimensionValidation> {
+        match registry.get(name) {
+            Some(meta) if meta.parent != parent => Err(Web4Error::InvalidInput(format!(
+                "sub-dimension {name:?} is registered under {:?}, not {:?}",
+                meta.parent, parent
+            ))),
+            Some(_) => {
+                self.observe_sub_dimension(name, parent, observed_score)?;
+                Ok(SubDimensionValidation::Registered)
+            }
+            None if strict => Err(Web4Error::InvalidInput(format!(
+                "sub-dimension {name:?} is not registered in the SubDimensionRegistry"
+            ))),
+            None => {
+                self.observe_sub_dimension(name, parent, observed_score)?;
+                Ok(SubDimensionValidation::Unregistered)
+            }
+        }
+    }
+}
+
+/// RDF/Turtle and JSON-LD (de)serialization for [`V3`] (behind the `rdf`
+/// feature). See the module docs for why: sub-dimensions are meant to be
+/// an open-ended RDF sub-graph, but without this, `V3` never actually
+/// emits one.
+///
+/// Both formats round-trip only what `to_turtle`/`to_jsonld` themselves
+/// produce — this is self-consistent serialization for `V3`, not a
+/// general-purpose RDF toolkit.
+#[cfg(feature = "rdf")]
+mod rdf {
+    use super::{SubDimensionScore, ValueDimension, V3};
+    use crate::error::{Result, Web4Error};
+    use std::fmt::Write as _;
+
+    /// Base IRI for the `web4:` namespace used by this tensor's ontology.
+    /// See `web4-standard/ontology/t3v3-ontology.ttl` for the canonical
+    /// definition this mirrors.
+    pub(super) const WEB4_NS: &str = "https://web4.foundation/ontology#";
+    const XSD_NS: &str = "http://www.w3.org/2001/XMLSchema#";
+
+    fn parse_dimension(name: &str) -> Result<ValueDimension> {
+        ValueDimension::all()
+            .into_iter()
+            .find(|d| d.name() == name)
+            .ok_or_else(|| Web4Error::InvalidInput(format!("unknown value dimension: {name}")))
+    }
+
+    fn blank_label(name: &str) -> String {
+        name.chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect()
+    }
+
+    pub(super) fn to_turtle(v3: &V3) -> String {
+        let mut out = String::new();
+        let _ = writeln!(out, "@prefix web4: <{WEB4_NS}> .");
+        let _ = writeln!(out, "@prefix xsd: <{XSD_NS}> .\n");
+
+        for dim in ValueDimension::all() {
+            let idx = dim as usize;
+            let _ = writeln!(
+                out,
+                "web4:{} web4:score \"{}\"^^xsd:double ;\n    web4:weight \"{}\"^^xsd:double ;\n    web4:observationCount \"{}\"^^xsd:long .\n",
+                dim.name(),
+                v3.dimensions[idx],
+                v3.weights[idx],
+                v3.observation_counts[idx],
+            );
+        }
+
+        let mut names: Vec<&String> = v3.sub_dimensions.keys().collect();
+        names.sort();
+        for name in names {
+            let sub = &v3.sub_dimensions[name];
+            let _ = writeln!(
+                out,
+                "_:sub_{label} a web4:SubDimension ;\n    web4:name \"{name}\" ;\n    web4:subDimensionOf web4:{parent} ;\n    web4:score \"{score}\"^^xsd:double ;\n    web4:weight \"{weight}\"^^xsd:double ;\n    web4:observationCount \"{count}\"^^xsd:long .\n",
+                label = blank_label(name),
+                name = name,
+                parent = sub.parent.name(),
+                score = sub.score,
+                weight = sub.weight,
+                count = sub.observation_count,
+            );
+        }
+
+        out
+    }
+
+    /// Pull out `predicate "value"` from a turtle-ish record's body.
+    fn literal_field<'a>(body: &'a str, predicate: &str) -> Option<&'a str> {
+        let needle = format!("{predicate} \"");
+        let start = body.find(&needle)? + needle.len();
+        let end = start + body[start..].find('"')?;
+        Some(&body[start..end])
+    }
+
+    pub(super) fn from_turtle(turtle: &str) -> Result<V3> {
+        let mut v3 = V3::new();
+
+        // Each record is a `subject predicate "lit" ; ... .` block; split on
+        // the `.` terminator that `to_turtle` always places at record end.
+        for record in turtle.split(" .") {
+            let record = record.trim();
+            if record.is_empty() || record.starts_with("@prefix") {
+                continue;
+            }
+
+            let Some(score) = literal_field(record, "web4:score") else {
+                continue;
+            };
+            let score: f64 = score
+                .parse()
+                .map_err(|_| Web4Error::InvalidInput(format!("invalid score literal: {score}")))?;
+            let weight: f64 = literal_field(record, "web4:weight")
+                .ok_or_else(|| Web4Error::InvalidInput("missing web4:weight".into()))?
+                .parse()
+                .map_err(|_| Web4Error::InvalidInput("invalid weight literal".into()))?;
+            let observation_count: u64 = literal_field(record, "web4:observationCount")
+                .ok_or_else(|| Web4Error::InvalidInput("missing web4:observationCount".into()))?
+                .parse()
+                .map_err(|_| Web4Error::InvalidInput("invalid observationCount literal".into()))?;
+
+            if record.contains("a web4:SubDimension") {
+                let name = literal_field(record, "web4:name")
+                    .ok_or_else(|| Web4Error::InvalidInput("missing web4:name".into()))?
+                    .to_string();
+                let parent_prefix = record
+                    .find("web4:subDimensionOf web4:")
+                    .map(|i| i + "web4:subDimensionOf web4:".len())
+                    .ok_or_else(|| Web4Error::InvalidInput("missing web4:subDimensionOf".into()))?;
+                let parent_name_end = record[parent_prefix..]
+                    .find(|c: char| c == ' ' || c == ';')
+                    .map(|i| parent_prefix + i)
+                    .unwrap_or(record.len());
+                let parent = parse_dimension(record[parent_prefix..parent_name_end].trim())?;
+
+                v3.sub_dimensions.insert(
+                    name,
+                    SubDimensionScore {
+                        score,
+                        weight,
+                        observation_count,
+                        parent,
+                    },
+                );
+            } else if let Some(rest) = record.strip_prefix("web4:") {
+                let dim_name = rest
+                    .find(' ')
+                    .map(|i| &rest[..i])
+                    .ok_or_else(|| Web4Error::InvalidInput("malformed dimension record".into()))?;
+                let dim = parse_dimension(dim_name)?;
+                let idx = dim as usize;
+                v3.dimensions[idx] = score;
+                v3.weights[idx] = weight;
+                v3.observation_counts[idx] = observation_count;
+            }
+        }
+
+        Ok(v3)
+    }
+
+    pub(super) fn to_jsonld(v3: &V3) -> serde_json::Value {
+        let mut graph = Vec::new();
+
+        for dim in ValueDimension::all() {
+            let idx = dim as usize;
+            graph.push(serde_json::json!({
+                "@id": format!("web4:{}", dim.name()),
+                "web4:score": v3.dimensions[idx],
+                "web4:weight": v3.weights[idx],
+                "web4:observationCount": v3.observation_counts[idx],
+            }));
+        }
+
+        let mut names: Vec<&String> = v3.sub_dimensions.keys().collect();
+        names.sort();
+        for name in names {
+            let sub = &v3.sub_dimensions[name];
+            graph.push(serde_json::json!({
+                "@id": format!("_:sub_{}", blank_label(name)),
+                "@type": "web4:SubDimension",
+                "web4:name": name,
+                "web4:subDimensionOf": { "@id": format!("web4:{}", sub.parent.name()) },
+                "web4:score": sub.score,
+                "web4:weight": sub.weight,
+                "web4:observationCount": sub.observation_count,
+            }));
+        }
+
+        serde_json::json!({
+            "@context": {
+                "web4": WEB4_NS,
+                "xsd": XSD_NS,
+            },
+            "@graph": graph,
+        })
+    }
+
+    pub(super) fn from_jsonld(value: &serde_json::Value) -> Result<V3> {
+        let mut v3 = V3::new();
+
+        let graph = value
+            .get("@graph")
+            .and_then(|g| g.as_array())
+            .ok_or_else(|| Web4Error::InvalidInput("missing @graph array".into()))?;
+
+        for node in graph {
+            let get_f64 = |key: &str| -> Result<f64> {
+                node.get(key)
+                    .and_then(|v| v.as_f64())
+                    .ok_or_else(|| Web4Error::InvalidInput(format!("missing {key}")))
+            };
+            let get_u64 = |key: &str| -> Result<u64> {
+                node.get(key)
+                    .and_then(|v| v.as_u64())
+                    .ok_or_else(|| Web4Error::InvalidInput(format!("missing {key}")))
+            };
+
+            if node.get("@type").and_then(|t| t.as_str()) == Some("web4:SubDimension") {
+                let name = node
+                    .get("web4:name")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| Web4Error::InvalidInput("missing web4:name".into()))?
+                    .to_string();
+                let parent_id = node
+                    .get("web4:subDimensionOf")
+                    .and_then(|v| v.get("@id"))
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| Web4Error::InvalidInput("missing web4:subDimensionOf".into()))?;
+                let parent = parse_dimension(
+                    parent_id
+                        .strip_prefix("web4:")
+                        .ok_or_else(|| Web4Error::InvalidInput("malformed parent IRI".into()))?,
+                )?;
+
+                v3.sub_dimensions.insert(
+                    name,
+                    SubDimensionScore {
+                        score: get_f64("web4:score")?,
+                        weight: get_f64("web4:weight")?,
+                        observation_count: get_u64("web4:observationCount")?,
+                        parent,
+                    },
+                );
+            } else {
+                let id = node
+                    .get("@id")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| Web4Error::InvalidInput("missing @id".into()))?;
+                let dim_name = id
+                    .strip_prefix("web4:")
+                    .ok_or_else(|| Web4Error::InvalidInput("malformed dimension IRI".into()))?;
+                let dim = parse_dimension(dim_name)?;
+                let idx = dim as usize;
+                v3.dimensions[idx] = get_f64("web4:score")?;
+                v3.weights[idx] = get_f64("web4:weight")?;
+                v3.observation_counts[idx] = get_u64("web4:observationCount")?;
+            }
+        }
+
+        Ok(v3)
+    }
+}
+
+/// Outcome of validating a sub-dimension name against a
+/// [`SubDimensionRegistry`] in [`V3::observe_sub_dimension_validated`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SubDimensionValidation {
+    /// The name is registered, and its parent matched.
+    Registered,
+    /// The name isn't registered, but validation wasn't strict so the
+    /// observation proceeded anyway.
+    Unregistered,
+}
+
+/// Metadata describing one registered sub-dimension.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SubDimensionMeta {
+    /// Root dimension this sub-dimension belongs under.
+    pub parent: ValueDimension,
+    /// Canonical IRI this sub-dimension resolves to in the `web4:` ontology.
+    pub ontology_iri: String,
+    /// Human-readable description of what this sub-dimension measures.
+    pub description: String,
+    /// Advisory hint for how observations in this sub-dimension should be
+    /// aggregated upstream (e.g. `"arithmetic_mean"`, `"max"`). `V3` itself
+    /// ignores this — [`V3::observe_sub_dimension`] always uses its own EMA.
+    pub aggregation_hint: Option<String>,
+}
+
+/// One sub-dimension's entry in a [`TensorSchema`] reflection dump.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SubDimensionSchema {
+    /// The sub-dimension's name.
+    pub name: String,
+    /// Name of the root dimension it's linked to via `web4:subDimensionOf`.
+    pub parent: String,
+    /// Canonical ontology IRI.
+    pub ontology_iri: String,
+    /// Human-readable description.
+    pub description: String,
+    /// Advisory aggregation hint, if any.
+    pub aggregation_hint: Option<String>,
+}
+
+/// Self-describing schema for the `V3` tensor shape: root dimensions plus
+/// every registered sub-dimension and its parent link — the same
+/// "discover the shape without hard-coding it" goal SCALE metadata serves
+/// for runtime types (see the `scale` feature).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TensorSchema {
+    /// Root dimension names, in [`ValueDimension::all`] order.
+    pub root_dimensions: Vec<String>,
+    /// Every registered sub-dimension, sorted alphabetically by name.
+    pub sub_dimensions: Vec<SubDimensionSchema>,
+}
+
+/// Registry of known sub-dimension names.
+///
+/// The sub-dimension space is otherwise free-form strings
+/// (`"market_demand"`, ...) with no shared schema, so two systems can coin
+/// conflicting names with nothing to reconcile them. A `SubDimensionRegistry`
+/// gives each registered name a canonical parent, ontology IRI, description,
+/// and optional aggregation hint, and [`V3::observe_sub_dimension_validated`]
+/// can check observations against it.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct SubDimensionRegistry {
+    entries: HashMap<String, SubDimensionMeta>,
+}
+
+impl SubDimensionRegistry {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register (or overwrite) a sub-dimension's metadata
+    pub fn register(
+        &mut self,
+        name: impl Into<String>,
+        parent: ValueDimension,
+        ontology_iri: impl Into<String>,
+        description: impl Into<String>,
+        aggregation_hint: Option<String>,
+    ) {
+        self.entries.insert(
+            name.into(),
+            SubDimensionMeta {
+                parent,
+                ontology_iri: ontology_iri.into(),
+                description: description.into(),
+                aggregation_hint,
+            },
+        );
+    }
+
+    /// Look up a registered sub-dimension's metadata by name
+    pub fn get(&self, name: &str) -> Option<&SubDimensionMeta> {
+        self.entries.get(name)
+    }
+
+    /// Whether `name` is registered
+    pub fn is_registered(&self, name: &str) -> bool {
+        self.entries.contains_key(name)
+    }
+
+    /// Dump the full tensor shape — root dimensions plus every registered
+    /// sub-dimension and its parent link — as a self-describing schema.
+    pub fn schema(&self) -> TensorSchema {
+        let root_dimensions = ValueDimension::all()
+            .iter()
+            .map(|d| d.name().to_string())
+            .collect();
+
+        let mut sub_dimensions: Vec<SubDimensionSchema> = self
+            .entries
+            .iter()
+            .map(|(name, meta)| SubDimensionSchema {
+                name: name.clone(),
+                parent: meta.parent.name().to_string(),
+                ontology_iri: meta.ontology_iri.clone(),
+                description: meta.description.clone(),
+                aggregation_hint: meta.aggregation_hint.clone(),
+            })
+            .collect();
+        sub_dimensions.sort_by(|a, b| a.name.cmp(&b.name));
+
+        TensorSchema {
+            root_dimensions,
+            sub_dimensions,
+        }
+    }
 }
 
 /// A value contribution observation
@@ -309,9 +843,14 @@ pub struct ValueObservation {
     /// The contributor's LCT ID
     pub contributor_id: Uuid,
 
-    /// The dimension being observed
+    /// The dimension being observed. If `sub_dimension` is set, this is
+    /// that sub-dimension's parent rather than a root observation.
     pub dimension: ValueDimension,
 
+    /// Name of the sub-dimension this observation targets, if any.
+    #[serde(default)]
+    pub sub_dimension: Option<String>,
+
     /// The observed score (0.0 to 1.0)
     pub score: f64,
 
@@ -323,7 +862,7 @@ pub struct ValueObservation {
 }
 
 impl ValueObservation {
-    /// Create a new value observation
+    /// Create a new observation of a root dimension
     pub fn new(
         observer_id: Uuid,
         contributor_id: Uuid,
@@ -340,6 +879,32 @@ impl ValueObservation {
             observer_id,
             contributor_id,
             dimension,
+            sub_dimension: None,
+            score,
+            context: context.into(),
+            timestamp: chrono::Utc::now(),
+        })
+    }
+
+    /// Create a new observation of a named sub-dimension under `parent`
+    pub fn new_sub_dimension(
+        observer_id: Uuid,
+        contributor_id: Uuid,
+        name: impl Into<String>,
+        parent: ValueDimension,
+        score: f64,
+        context: impl Into<String>,
+    ) -> Result<Self> {
+        if !(0.0..=1.0).contains(&score) {
+            return Err(Web4Error::InvalidInput(
+                "Score must be in range [0.0, 1.0]".into(),
+            ));
+        }
+        Ok(Self {
+            observer_id,
+            contributor_id,
+            dimension: parent,
+            sub_dimension: Some(name.into()),
             score,
             context: context.into(),
             timestamp: chrono::Utc::now(),
@@ -347,6 +912,103 @@ impl ValueObservation {
     }
 }
 
+/// One append-only entry in an [`ObservationLog`]: either a value
+/// observation or a decay event, each carrying its own transaction
+/// timestamp.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum LogEntry {
+    /// A value observation for a root or sub-dimension.
+    Observation(ValueObservation),
+    /// A decay event applied to the whole tensor.
+    Decay {
+        /// When the decay was applied.
+        timestamp: chrono::DateTime<chrono::Utc>,
+        /// The decay factor passed to [`V3::decay`].
+        factor: f64,
+    },
+}
+
+impl LogEntry {
+    fn timestamp(&self) -> chrono::DateTime<chrono::Utc> {
+        match self {
+            LogEntry::Observation(obs) => obs.timestamp,
+            LogEntry::Decay { timestamp, .. } => *timestamp,
+        }
+    }
+}
+
+/// Append-only, bitemporal log of [`ValueObservation`]s and decay events.
+///
+/// `V3::observe` folds each observation into an EMA in place and discards
+/// it, so there is no audit trail and no way to ask what a tensor looked
+/// like in the past. An `ObservationLog` keeps every entry instead, and
+/// [`reconstruct_as_of`] replays it to rebuild a `V3` as a materialized
+/// view — the log is the source of truth, and any `V3` is just a snapshot
+/// derivable from it.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ObservationLog {
+    entries: Vec<LogEntry>,
+}
+
+impl ObservationLog {
+    /// Create an empty log
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+
+    /// Append a value observation
+    pub fn record_observation(&mut self, observation: ValueObservation) {
+        self.entries.push(LogEntry::Observation(observation));
+    }
+
+    /// Append a decay event, timestamped now
+    pub fn record_decay(&mut self, factor: f64) {
+        self.entries.push(LogEntry::Decay {
+            timestamp: chrono::Utc::now(),
+            factor,
+        });
+    }
+
+    /// All entries, oldest first (insertion order)
+    pub fn entries(&self) -> &[LogEntry] {
+        &self.entries
+    }
+}
+
+/// Rebuild a `V3` as it stood at or before `as_of`, by replaying `log`'s
+/// entries with `timestamp <= as_of` in commit order — ties broken by
+/// insertion index, i.e. the log's own order — through the exact same
+/// recurrence [`V3::observe`]/[`V3::observe_sub_dimension`]/[`V3::decay`]
+/// use internally. A sub-dimension first observed mid-stream initializes
+/// at 0.5, same as `observe_sub_dimension` does on a live `V3`.
+pub fn reconstruct_as_of(log: &ObservationLog, as_of: chrono::DateTime<chrono::Utc>) -> V3 {
+    let mut indexed: Vec<(usize, &LogEntry)> = log
+        .entries
+        .iter()
+        .enumerate()
+        .filter(|(_, entry)| entry.timestamp() <= as_of)
+        .collect();
+    indexed.sort_by_key(|(index, entry)| (entry.timestamp(), *index));
+
+    let mut v3 = V3::new();
+    for (_, entry) in indexed {
+        match entry {
+            LogEntry::Observation(obs) => {
+                let result = match &obs.sub_dimension {
+                    Some(name) => v3.observe_sub_dimension(name, obs.dimension, obs.score),
+                    None => v3.observe(obs.dimension, obs.score),
+                };
+                let _ = result;
+            }
+            LogEntry::Decay { factor, .. } => v3.decay(*factor),
+        }
+    }
+
+    v3
+}
+
 /// Combined trust-value score
 ///
 /// Represents the overall quality of an entity's participation in Web4.
@@ -383,6 +1045,16 @@ impl TrustValueScore {
         (trust_agg * value_agg).sqrt()
     }
 
+    /// Compute the combined score and record it through
+    /// [`TensorMeter`](crate::telemetry::TensorMeter) as
+    /// `web4.tensor.combined`.
+    #[cfg(feature = "telemetry")]
+    pub fn combined_instrumented(&self, meter: &crate::telemetry::TensorMeter) -> f64 {
+        let combined = self.combined();
+        meter.record_combined(combined);
+        combined
+    }
+
     /// Check if entity meets minimum requirements for a role
     pub fn meets_requirements(
         &self,
@@ -479,4 +1151,204 @@ mod tests {
         assert!(subs["market_demand"].score > 0.5);
         assert_eq!(subs["market_demand"].parent, ValueDimension::Valuation);
     }
+
+    #[test]
+    fn test_reconstruct_as_of_matches_live_replay() {
+        let observer = Uuid::new_v4();
+        let contributor = Uuid::new_v4();
+
+        let mut log = ObservationLog::new();
+        let mut live = V3::new();
+
+        for i in 0..5 {
+            let mut obs = ValueObservation::new(
+                observer,
+                contributor,
+                ValueDimension::Valuation,
+                0.6 + i as f64 * 0.05,
+                "progress report",
+            )
+            .unwrap();
+            obs.timestamp = chrono::Utc::now() - chrono::Duration::days(5 - i);
+            live.observe(obs.dimension, obs.score).unwrap();
+            log.record_observation(obs);
+        }
+
+        let as_of = chrono::Utc::now();
+        let reconstructed = reconstruct_as_of(&log, as_of);
+
+        assert_eq!(
+            reconstructed.score(ValueDimension::Valuation),
+            live.score(ValueDimension::Valuation)
+        );
+        assert_eq!(
+            reconstructed.weight(ValueDimension::Valuation),
+            live.weight(ValueDimension::Valuation)
+        );
+    }
+
+    #[test]
+    fn test_reconstruct_as_of_respects_cutoff() {
+        let observer = Uuid::new_v4();
+        let contributor = Uuid::new_v4();
+        let mut log = ObservationLog::new();
+
+        let mut early = ValueObservation::new(
+            observer,
+            contributor,
+            ValueDimension::Veracity,
+            0.9,
+            "early",
+        )
+        .unwrap();
+        early.timestamp = chrono::Utc::now() - chrono::Duration::days(10);
+        log.record_observation(early);
+
+        let cutoff = chrono::Utc::now() - chrono::Duration::days(5);
+
+        let mut late = ValueObservation::new(
+            observer,
+            contributor,
+            ValueDimension::Veracity,
+            0.1,
+            "late",
+        )
+        .unwrap();
+        late.timestamp = chrono::Utc::now();
+        log.record_observation(late);
+
+        let snapshot = reconstruct_as_of(&log, cutoff);
+        assert!(snapshot.score(ValueDimension::Veracity) > 0.5);
+    }
+
+    #[test]
+    fn test_reconstruct_as_of_initializes_sub_dimension_at_neutral() {
+        let observer = Uuid::new_v4();
+        let contributor = Uuid::new_v4();
+        let mut log = ObservationLog::new();
+
+        log.record_observation(
+            ValueObservation::new_sub_dimension(
+                observer,
+                contributor,
+                "market_demand",
+                ValueDimension::Valuation,
+                0.9,
+                "mid-stream debut",
+            )
+            .unwrap(),
+        );
+
+        let snapshot = reconstruct_as_of(&log, chrono::Utc::now());
+        let subs = snapshot.sub_dimensions();
+        assert_eq!(subs.len(), 1);
+        // Single observation from neutral 0.5 with alpha=0.5 lands halfway to 0.9.
+        assert!((subs["market_demand"].score - 0.7).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_registered_sub_dimension_observation_succeeds() {
+        let mut registry = SubDimensionRegistry::new();
+        registry.register(
+            "market_demand",
+            ValueDimension::Valuation,
+            "https://web4.foundation/ontology#marketDemand",
+            "Observed market demand for the contribution",
+            Some("arithmetic_mean".to_string()),
+        );
+
+        let mut v3 = V3::new();
+        let outcome = v3
+            .observe_sub_dimension_validated(
+                "market_demand",
+                ValueDimension::Valuation,
+                0.8,
+                &registry,
+                true,
+            )
+            .unwrap();
+
+        assert_eq!(outcome, SubDimensionValidation::Registered);
+        assert!(v3.sub_dimensions()["market_demand"].score > 0.5);
+    }
+
+    #[test]
+    fn test_strict_mode_rejects_unregistered_sub_dimension() {
+        let registry = SubDimensionRegistry::new();
+        let mut v3 = V3::new();
+
+        let err = v3
+            .observe_sub_dimension_validated("rogue_name", ValueDimension::Veracity, 0.5, &registry, true)
+            .unwrap_err();
+        assert!(matches!(err, Web4Error::InvalidInput(_)));
+        assert!(v3.sub_dimensions().is_empty());
+    }
+
+    #[test]
+    fn test_non_strict_mode_flags_but_allows_unregistered_sub_dimension() {
+        let registry = SubDimensionRegistry::new();
+        let mut v3 = V3::new();
+
+        let outcome = v3
+            .observe_sub_dimension_validated("rogue_name", ValueDimension::Veracity, 0.9, &registry, false)
+            .unwrap();
+
+        assert_eq!(outcome, SubDimensionValidation::Unregistered);
+        assert!(v3.sub_dimensions().contains_key("rogue_name"));
+    }
+
+    #[test]
+    fn test_validation_rejects_parent_mismatch_even_when_registered() {
+        let mut registry = SubDimensionRegistry::new();
+        registry.register(
+            "market_demand",
+            ValueDimension::Valuation,
+            "https://web4.foundation/ontology#marketDemand",
+            "Observed market demand",
+            None,
+        );
+
+        let mut v3 = V3::new();
+        let err = v3
+            .observe_sub_dimension_validated(
+                "market_demand",
+                ValueDimension::Veracity,
+                0.5,
+                &registry,
+                false,
+            )
+            .unwrap_err();
+        assert!(matches!(err, Web4Error::InvalidInput(_)));
+    }
+
+    #[test]
+    fn test_registry_schema_reflects_root_and_sub_dimensions() {
+        let mut registry = SubDimensionRegistry::new();
+        registry.register(
+            "market_demand",
+            ValueDimension::Valuation,
+            "https://web4.foundation/ontology#marketDemand",
+            "Observed market demand",
+            Some("arithmetic_mean".to_string()),
+        );
+        registry.register(
+            "claim_accuracy",
+            ValueDimension::Veracity,
+            "https://web4.foundation/ontology#claimAccuracy",
+            "Fraction of claims later confirmed true",
+            None,
+        );
+
+        let schema = registry.schema();
+        assert_eq!(schema.root_dimensions, vec!["valuation", "veracity", "validity"]);
+        assert_eq!(schema.sub_dimensions.len(), 2);
+        // Sorted alphabetically by name.
+        assert_eq!(schema.sub_dimensions[0].name, "claim_accuracy");
+        assert_eq!(schema.sub_dimensions[0].parent, "veracity");
+        assert_eq!(schema.sub_dimensions[1].name, "market_demand");
+        assert_eq!(
+            schema.sub_dimensions[1].aggregation_hint.as_deref(),
+            Some("arithmetic_mean")
+        );
+    }
 }
@@ -0,0 +1,453 @@
+//! WASM bindings for web4-core
+//!
+//! Provides browser/Node access to Web4 primitives via wasm-bindgen,
+//! mirroring the surface exposed to Python by the `web4-core/python` crate.
+
+use js_sys::{Array, Uint8Array};
+use wasm_bindgen::prelude::*;
+use web4_core::{self as core};
+
+/// Map a core error into a JS exception.
+fn to_js_err(e: core::Web4Error) -> JsValue {
+    JsValue::from_str(&e.to_string())
+}
+
+/// Entity type that an LCT can represent
+#[wasm_bindgen]
+#[derive(Clone, Copy)]
+pub enum EntityType {
+    Human,
+    AiSoftware,
+    AiEmbodied,
+    Organization,
+    Role,
+    Task,
+    Resource,
+    Hybrid,
+}
+
+impl From<EntityType> for core::EntityType {
+    fn from(t: EntityType) -> Self {
+        match t {
+            EntityType::Human => core::EntityType::Human,
+            EntityType::AiSoftware => core::EntityType::AiSoftware,
+            EntityType::AiEmbodied => core::EntityType::AiEmbodied,
+            EntityType::Organization => core::EntityType::Organization,
+            EntityType::Role => core::EntityType::Role,
+            EntityType::Task => core::EntityType::Task,
+            EntityType::Resource => core::EntityType::Resource,
+            EntityType::Hybrid => core::EntityType::Hybrid,
+        }
+    }
+}
+
+impl From<core::EntityType> for EntityType {
+    fn from(t: core::EntityType) -> Self {
+        match t {
+            core::EntityType::Human => EntityType::Human,
+            core::EntityType::AiSoftware => EntityType::AiSoftware,
+            core::EntityType::AiEmbodied => EntityType::AiEmbodied,
+            core::EntityType::Organization => EntityType::Organization,
+            core::EntityType::Role => EntityType::Role,
+            core::EntityType::Task => EntityType::Task,
+            core::EntityType::Resource => EntityType::Resource,
+            core::EntityType::Hybrid => EntityType::Hybrid,
+        }
+    }
+}
+
+/// Trust dimension for the T3 tensor
+#[wasm_bindgen]
+#[derive(Clone, Copy)]
+pub enum TrustDimension {
+    Competence,
+    Integrity,
+    Benevolence,
+    Predictability,
+    Transparency,
+    Accountability,
+}
+
+impl From<TrustDimension> for core::TrustDimension {
+    fn from(d: TrustDimension) -> Self {
+        match d {
+            TrustDimension::Competence => core::TrustDimension::Competence,
+            TrustDimension::Integrity => core::TrustDimension::Integrity,
+            TrustDimension::Benevolence => core::TrustDimension::Benevolence,
+            TrustDimension::Predictability => core::TrustDimension::Predictability,
+            TrustDimension::Transparency => core::TrustDimension::Transparency,
+            TrustDimension::Accountability => core::TrustDimension::Accountability,
+        }
+    }
+}
+
+/// Value dimension for the V3 tensor
+#[wasm_bindgen]
+#[derive(Clone, Copy)]
+pub enum ValueDimension {
+    Utility,
+    Novelty,
+    Quality,
+    Timeliness,
+    Relevance,
+    Leverage,
+}
+
+impl From<ValueDimension> for core::ValueDimension {
+    fn from(d: ValueDimension) -> Self {
+        match d {
+            ValueDimension::Utility => core::ValueDimension::Utility,
+            ValueDimension::Novelty => core::ValueDimension::Novelty,
+            ValueDimension::Quality => core::ValueDimension::Quality,
+            ValueDimension::Timeliness => core::ValueDimension::Timeliness,
+            ValueDimension::Relevance => core::ValueDimension::Relevance,
+            ValueDimension::Leverage => core::ValueDimension::Leverage,
+        }
+    }
+}
+
+/// A keypair for signing and verification
+#[wasm_bindgen]
+pub struct KeyPair {
+    inner: core::KeyPair,
+}
+
+#[wasm_bindgen]
+impl KeyPair {
+    /// Generate a new random keypair
+    pub fn generate() -> KeyPair {
+        Self { inner: core::KeyPair::generate() }
+    }
+
+    /// Create from secret key bytes (32 bytes)
+    #[wasm_bindgen(js_name = fromSecretBytes)]
+    pub fn from_secret_bytes(bytes: &[u8]) -> Result<KeyPair, JsValue> {
+        let bytes: [u8; 32] = bytes
+            .try_into()
+            .map_err(|_| JsValue::from_str("Secret key must be 32 bytes"))?;
+        Ok(Self { inner: core::KeyPair::from_secret_bytes(&bytes) })
+    }
+
+    /// Get the public key bytes
+    #[wasm_bindgen(js_name = publicKeyBytes)]
+    pub fn public_key_bytes(&self) -> Uint8Array {
+        Uint8Array::from(&self.inner.public_key_bytes()[..])
+    }
+
+    /// Get the secret key bytes (handle with care!)
+    #[wasm_bindgen(js_name = secretKeyBytes)]
+    pub fn secret_key_bytes(&self) -> Uint8Array {
+        Uint8Array::from(&self.inner.secret_key_bytes()[..])
+    }
+
+    /// Sign a message, returning the 64-byte signature
+    pub fn sign(&self, message: &[u8]) -> Uint8Array {
+        Uint8Array::from(&self.inner.sign(message).bytes[..])
+    }
+}
+
+/// Linked Context Token - the fundamental identity primitive
+#[wasm_bindgen]
+pub struct Lct {
+    inner: core::Lct,
+}
+
+#[wasm_bindgen]
+impl Lct {
+    /// Create a new LCT, returning a `[Lct, KeyPair]` tuple
+    pub fn new(entity_type: EntityType) -> Array {
+        let (lct, keypair) = core::Lct::new(entity_type.into(), None);
+        let result = Array::new();
+        result.push(&JsValue::from(Lct { inner: lct }));
+        result.push(&JsValue::from(KeyPair { inner: keypair }));
+        result
+    }
+
+    /// Get the LCT ID as a string
+    #[wasm_bindgen(getter)]
+    pub fn id(&self) -> String {
+        self.inner.id.to_string()
+    }
+
+    /// Get the entity type
+    #[wasm_bindgen(getter, js_name = entityType)]
+    pub fn entity_type(&self) -> EntityType {
+        self.inner.entity_type.clone().into()
+    }
+
+    /// Check if LCT is active
+    #[wasm_bindgen(js_name = isActive)]
+    pub fn is_active(&self) -> bool {
+        self.inner.is_active()
+    }
+
+    /// Get trust ceiling based on hardware binding
+    #[wasm_bindgen(js_name = trustCeiling)]
+    pub fn trust_ceiling(&self) -> f64 {
+        self.inner.trust_ceiling()
+    }
+
+    /// Get coherence threshold for this entity type
+    #[wasm_bindgen(js_name = coherenceThreshold)]
+    pub fn coherence_threshold(&self) -> f64 {
+        self.inner.coherence_threshold()
+    }
+
+    /// Get the LCT fingerprint (short identifier)
+    pub fn fingerprint(&self) -> String {
+        self.inner.fingerprint()
+    }
+
+    /// Verify a signature over `message`
+    #[wasm_bindgen(js_name = verifySignature)]
+    pub fn verify_signature(&self, message: &[u8], signature: &[u8]) -> Result<bool, JsValue> {
+        let sig_bytes: [u8; 64] = signature
+            .try_into()
+            .map_err(|_| JsValue::from_str("Signature must be 64 bytes"))?;
+        let sig = core::SignatureBytes::from_bytes(sig_bytes);
+        Ok(self.inner.verify_signature(message, &sig).is_ok())
+    }
+
+    /// Create a child LCT under this parent, cross-signed with
+    /// `parent_keypair` (this LCT's own keypair), returning a `[Lct, KeyPair]`
+    /// tuple
+    #[wasm_bindgen(js_name = createChild)]
+    pub fn create_child(&self, entity_type: EntityType, parent_keypair: &KeyPair) -> Array {
+        let (lct, keypair) = self.inner.create_child(entity_type.into(), &parent_keypair.inner);
+        let result = Array::new();
+        result.push(&JsValue::from(Lct { inner: lct }));
+        result.push(&JsValue::from(KeyPair { inner: keypair }));
+        result
+    }
+
+    /// Void this LCT
+    pub fn void(&mut self) {
+        self.inner.void();
+    }
+
+    /// Slash this LCT
+    pub fn slash(&mut self) {
+        self.inner.slash();
+    }
+
+    /// Get parent ID (if any)
+    #[wasm_bindgen(getter, js_name = parentId)]
+    pub fn parent_id(&self) -> Option<String> {
+        self.inner.parent_id.map(|id| id.to_string())
+    }
+
+    /// Get lineage depth
+    #[wasm_bindgen(getter, js_name = lineageDepth)]
+    pub fn lineage_depth(&self) -> u32 {
+        self.inner.lineage_depth
+    }
+}
+
+/// Trust Tensor (T3) - 6-dimensional trust measurement
+#[wasm_bindgen]
+pub struct T3 {
+    inner: core::T3,
+}
+
+#[wasm_bindgen]
+impl T3 {
+    /// Create a new T3 with neutral trust
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> T3 {
+        Self { inner: core::T3::new() }
+    }
+
+    /// Create a T3 with specific scores
+    #[wasm_bindgen(js_name = withScores)]
+    pub fn with_scores(scores: &[f64]) -> Result<T3, JsValue> {
+        let scores: [f64; 6] = scores
+            .try_into()
+            .map_err(|_| JsValue::from_str("Expected 6 scores"))?;
+        Ok(Self { inner: core::T3::with_scores(scores).map_err(to_js_err)? })
+    }
+
+    /// Get the score for a dimension
+    pub fn score(&self, dimension: TrustDimension) -> f64 {
+        self.inner.score(dimension.into())
+    }
+
+    /// Get the weight for a dimension
+    pub fn weight(&self, dimension: TrustDimension) -> f64 {
+        self.inner.weight(dimension.into())
+    }
+
+    /// Get all dimension scores
+    pub fn scores(&self) -> Vec<f64> {
+        self.inner.scores().to_vec()
+    }
+
+    /// Record an observation
+    pub fn observe(&mut self, dimension: TrustDimension, score: f64) -> Result<(), JsValue> {
+        self.inner.observe(dimension.into(), score).map_err(to_js_err)
+    }
+
+    /// Compute aggregate trust score
+    pub fn aggregate(&self) -> f64 {
+        self.inner.aggregate()
+    }
+
+    /// Apply time decay
+    pub fn decay(&mut self, decay_factor: f64) {
+        self.inner.decay(decay_factor);
+    }
+
+    /// Check if trust meets thresholds
+    #[wasm_bindgen(js_name = meetsThresholds)]
+    pub fn meets_thresholds(&self, min_scores: &[f64]) -> Result<bool, JsValue> {
+        let min_scores: [f64; 6] = min_scores
+            .try_into()
+            .map_err(|_| JsValue::from_str("Expected 6 thresholds"))?;
+        Ok(self.inner.meets_thresholds(&min_scores))
+    }
+}
+
+impl Default for T3 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Value Tensor (V3) - 6-dimensional value measurement
+#[wasm_bindgen]
+pub struct V3 {
+    inner: core::V3,
+}
+
+#[wasm_bindgen]
+impl V3 {
+    /// Create a new V3 with neutral value
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> V3 {
+        Self { inner: core::V3::new() }
+    }
+
+    /// Get the score for a dimension
+    pub fn score(&self, dimension: ValueDimension) -> f64 {
+        self.inner.score(dimension.into())
+    }
+
+    /// Get all dimension scores
+    pub fn scores(&self) -> Vec<f64> {
+        self.inner.scores().to_vec()
+    }
+
+    /// Record an observation
+    pub fn observe(&mut self, dimension: ValueDimension, score: f64) -> Result<(), JsValue> {
+        self.inner.observe(dimension.into(), score).map_err(to_js_err)
+    }
+
+    /// Compute aggregate value score
+    pub fn aggregate(&self) -> f64 {
+        self.inner.aggregate()
+    }
+
+    /// Apply time decay
+    pub fn decay(&mut self, decay_factor: f64) {
+        self.inner.decay(decay_factor);
+    }
+}
+
+impl Default for V3 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Identity Coherence score (C × S × Φ × R)
+#[wasm_bindgen]
+pub struct Coherence {
+    inner: core::Coherence,
+}
+
+#[wasm_bindgen]
+impl Coherence {
+    /// Create a new coherence score with neutral values
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Coherence {
+        Self { inner: core::Coherence::new() }
+    }
+
+    /// Create with specific values
+    #[wasm_bindgen(js_name = withValues)]
+    pub fn with_values(
+        continuity: f64,
+        stability: f64,
+        phi: f64,
+        reachability: f64,
+    ) -> Result<Coherence, JsValue> {
+        Ok(Self {
+            inner: core::Coherence::with_values(continuity, stability, phi, reachability)
+                .map_err(to_js_err)?,
+        })
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn continuity(&self) -> f64 {
+        self.inner.continuity
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn stability(&self) -> f64 {
+        self.inner.stability
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn phi(&self) -> f64 {
+        self.inner.phi
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn reachability(&self) -> f64 {
+        self.inner.reachability
+    }
+
+    /// Compute total coherence (C × S × Φ × R)
+    pub fn total(&self) -> f64 {
+        self.inner.total()
+    }
+
+    /// Check if coherence meets threshold
+    #[wasm_bindgen(js_name = meetsThreshold)]
+    pub fn meets_threshold(&self, threshold: f64) -> bool {
+        self.inner.meets_threshold(threshold)
+    }
+
+    /// Get the limiting factor as a `[name, value]` tuple
+    #[wasm_bindgen(js_name = limitingFactor)]
+    pub fn limiting_factor(&self) -> Array {
+        let (name, value) = self.inner.limiting_factor();
+        let result = Array::new();
+        result.push(&JsValue::from_str(name));
+        result.push(&JsValue::from_f64(value));
+        result
+    }
+}
+
+impl Default for Coherence {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Compute SHA-256 hash of data
+#[wasm_bindgen]
+pub fn sha256(data: &[u8]) -> Uint8Array {
+    Uint8Array::from(&core::sha256(data)[..])
+}
+
+/// Compute SHA-256 hash and return as a hex string
+#[wasm_bindgen]
+pub fn sha256_hex(data: &[u8]) -> String {
+    core::sha256_hex(data)
+}
+
+/// Get the library version
+#[wasm_bindgen]
+pub fn version() -> String {
+    core::VERSION.to_string()
+}
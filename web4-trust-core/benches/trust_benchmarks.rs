@@ -51,5 +51,66 @@ fn store_operations(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, tensor_average, tensor_update, entity_trust_operations, store_operations);
+#[cfg(feature = "sled-store")]
+fn sled_store_operations(c: &mut Criterion) {
+    use web4_trust_core::storage::SledStore;
+
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let store = SledStore::new(temp_dir.path()).unwrap();
+
+    c.bench_function("sled_store_get_or_create", |b| {
+        b.iter(|| {
+            store.get(black_box("mcp:test")).unwrap()
+        })
+    });
+
+    c.bench_function("sled_store_witness", |b| {
+        b.iter(|| {
+            store.witness(
+                black_box("session:a"),
+                black_box("mcp:test"),
+                black_box(true),
+                black_box(0.1)
+            ).unwrap()
+        })
+    });
+}
+
+fn decay_100k_states(c: &mut Criterion) {
+    use web4_trust_core::decay::{current_batch, DecayConfig, DecayState};
+
+    const N: usize = 100_000;
+    let config = DecayConfig::default();
+
+    // Build 100k states with deterministically "randomized" last_update stamps
+    // (a cheap LCG keeps the bench reproducible without a rand dependency).
+    let mut seed: u64 = 0x9E3779B97F4A7C15;
+    let states: Vec<DecayState> = (0..N)
+        .map(|_| {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            let days_ago = (seed >> 40) % 400; // 0..400 days
+            DecayState::new(0.9, -(days_ago as i64) * 86400)
+        })
+        .collect();
+
+    let now = 0i64;
+    let mut out = vec![0.0; N];
+
+    c.bench_function("decay_100k_states", |b| {
+        b.iter(|| {
+            current_batch(black_box(&states), black_box(now), &config, &mut out);
+            black_box(&out);
+        })
+    });
+}
+
+criterion_group!(benches, tensor_average, tensor_update, entity_trust_operations, store_operations, decay_100k_states);
+
+#[cfg(feature = "sled-store")]
+criterion_group!(sled_benches, sled_store_operations);
+
+#[cfg(not(feature = "sled-store"))]
 criterion_main!(benches);
+
+#[cfg(feature = "sled-store")]
+criterion_main!(benches, sled_benches);
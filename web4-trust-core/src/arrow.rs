@@ -0,0 +1,276 @@
+//! Apache Arrow export of `EntityTrust` records (behind the `arrow` feature).
+//!
+//! Persisted `EntityTrust` records are naturally row-oriented (one JSON
+//! object per entity), which is fine for a single lookup but miserable for
+//! an analyst who wants to load a whole trust store into a dataframe and
+//! filter/aggregate across thousands of entities. [`to_record_batch`] lays
+//! the T3/V3 tensor dimensions and counters out as columns instead, and
+//! [`from_record_batch`] reconstructs `EntityTrust`s from that columnar
+//! form.
+//!
+//! The Arrow schema only covers scalar fields — `witnessed_by` and
+//! `has_witnessed` (unbounded lists of peer IDs) don't fit a fixed column
+//! layout and are dropped. A round trip through [`to_record_batch`] /
+//! [`from_record_batch`] is therefore lossy for those two fields; everything
+//! else survives exactly. `entity_type` is dictionary-encoded (few distinct
+//! values repeated across many rows), which is both smaller on the wire and
+//! what pandas/Polars turn into a `category` column automatically.
+//!
+//! [`TrustStore::export_record_batch`](crate::TrustStore::export_record_batch)
+//! builds a batch from a whole store (optionally filtered by
+//! [`EntityType`](crate::EntityType)) in one call; the `python` bindings'
+//! `PyTrustStore::to_arrow` exposes that to Python over the Arrow C Data
+//! Interface.
+
+use std::sync::Arc;
+
+use arrow::array::{
+    Array, DictionaryArray, Float64Array, StringArray, TimestampMillisecondArray, UInt64Array,
+};
+use arrow::compute::cast;
+use arrow::datatypes::{DataType, Field, Int32Type, Schema, TimeUnit};
+use arrow::record_batch::RecordBatch;
+use chrono::{DateTime, Utc};
+
+use crate::entity::EntityTrust;
+use crate::tensor::{T3Tensor, V3Tensor};
+use crate::{Error, Result};
+
+/// The fixed column layout produced by [`to_record_batch`].
+pub fn schema() -> Schema {
+    Schema::new(vec![
+        Field::new("entity_id", DataType::Utf8, false),
+        Field::new(
+            "entity_type",
+            DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8)),
+            false,
+        ),
+        Field::new("talent", DataType::Float64, false),
+        Field::new("training", DataType::Float64, false),
+        Field::new("temperament", DataType::Float64, false),
+        Field::new("valuation", DataType::Float64, false),
+        Field::new("veracity", DataType::Float64, false),
+        Field::new("validity", DataType::Float64, false),
+        Field::new("action_count", DataType::UInt64, false),
+        Field::new("success_count", DataType::UInt64, false),
+        Field::new("witness_count", DataType::UInt64, false),
+        Field::new("success_rate", DataType::Float64, false),
+        Field::new(
+            "last_action",
+            DataType::Timestamp(TimeUnit::Millisecond, None),
+            true,
+        ),
+        Field::new(
+            "created_at",
+            DataType::Timestamp(TimeUnit::Millisecond, None),
+            false,
+        ),
+    ])
+}
+
+/// Map `entities` to a columnar [`RecordBatch`] suitable for vectorized
+/// filtering/aggregation over a whole trust store.
+pub fn to_record_batch(entities: &[EntityTrust]) -> RecordBatch {
+    let entity_id: StringArray = entities.iter().map(|e| Some(e.entity_id.as_str())).collect();
+    let entity_type: DictionaryArray<Int32Type> = entities
+        .iter()
+        .map(|e| Some(e.entity_type.as_str()))
+        .collect();
+    let talent: Float64Array = entities.iter().map(|e| Some(e.t3.talent)).collect();
+    let training: Float64Array = entities.iter().map(|e| Some(e.t3.training)).collect();
+    let temperament: Float64Array = entities.iter().map(|e| Some(e.t3.temperament)).collect();
+    let valuation: Float64Array = entities.iter().map(|e| Some(e.v3.valuation)).collect();
+    let veracity: Float64Array = entities.iter().map(|e| Some(e.v3.veracity)).collect();
+    let validity: Float64Array = entities.iter().map(|e| Some(e.v3.validity)).collect();
+    let action_count: UInt64Array = entities.iter().map(|e| Some(e.action_count)).collect();
+    let success_count: UInt64Array = entities.iter().map(|e| Some(e.success_count)).collect();
+    let witness_count: UInt64Array = entities.iter().map(|e| Some(e.witness_count)).collect();
+    let success_rate: Float64Array = entities.iter().map(|e| Some(e.success_rate())).collect();
+    let last_action: TimestampMillisecondArray = entities
+        .iter()
+        .map(|e| e.last_action.map(|ts| ts.timestamp_millis()))
+        .collect();
+    let created_at: TimestampMillisecondArray = entities
+        .iter()
+        .map(|e| Some(e.created_at.timestamp_millis()))
+        .collect();
+
+    RecordBatch::try_new(
+        Arc::new(schema()),
+        vec![
+            Arc::new(entity_id),
+            Arc::new(entity_type),
+            Arc::new(talent),
+            Arc::new(training),
+            Arc::new(temperament),
+            Arc::new(valuation),
+            Arc::new(veracity),
+            Arc::new(validity),
+            Arc::new(action_count),
+            Arc::new(success_count),
+            Arc::new(witness_count),
+            Arc::new(success_rate),
+            Arc::new(last_action),
+            Arc::new(created_at),
+        ],
+    )
+    .expect("columns built from `schema()` always match its layout")
+}
+
+fn column<'a, T: Array + 'static>(batch: &'a RecordBatch, name: &str) -> Result<&'a T> {
+    batch
+        .column_by_name(name)
+        .ok_or_else(|| Error::Serialization(format!("missing Arrow column {name:?}")))?
+        .as_any()
+        .downcast_ref::<T>()
+        .ok_or_else(|| Error::Serialization(format!("Arrow column {name:?} has the wrong type")))
+}
+
+/// `entity_type` is dictionary-encoded; cast it back to plain `Utf8` rather
+/// than threading dictionary keys/values through the rest of this module.
+fn entity_type_column(batch: &RecordBatch, name: &str) -> Result<StringArray> {
+    let raw = batch
+        .column_by_name(name)
+        .ok_or_else(|| Error::Serialization(format!("missing Arrow column {name:?}")))?;
+    let utf8 = cast(raw, &DataType::Utf8)
+        .map_err(|e| Error::Serialization(format!("Arrow column {name:?} is not castable to Utf8: {e}")))?;
+    Ok(utf8
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .expect("cast to Utf8 always yields a StringArray")
+        .clone())
+}
+
+/// Reconstruct `EntityTrust`s from a [`RecordBatch`] produced by
+/// [`to_record_batch`] (or any batch matching its [`schema`]).
+///
+/// `witnessed_by` and `has_witnessed` are not part of the columnar schema
+/// and come back empty — this is the inverse of [`to_record_batch`] only for
+/// the fields it actually exports.
+pub fn from_record_batch(batch: &RecordBatch) -> Result<Vec<EntityTrust>> {
+    let entity_id = column::<StringArray>(batch, "entity_id")?;
+    let entity_type = entity_type_column(batch, "entity_type")?;
+    let talent = column::<Float64Array>(batch, "talent")?;
+    let training = column::<Float64Array>(batch, "training")?;
+    let temperament = column::<Float64Array>(batch, "temperament")?;
+    let valuation = column::<Float64Array>(batch, "valuation")?;
+    let veracity = column::<Float64Array>(batch, "veracity")?;
+    let validity = column::<Float64Array>(batch, "validity")?;
+    let action_count = column::<UInt64Array>(batch, "action_count")?;
+    let success_count = column::<UInt64Array>(batch, "success_count")?;
+    let witness_count = column::<UInt64Array>(batch, "witness_count")?;
+    let last_action = column::<TimestampMillisecondArray>(batch, "last_action")?;
+    let created_at = column::<TimestampMillisecondArray>(batch, "created_at")?;
+
+    (0..batch.num_rows())
+        .map(|row| {
+            let id = entity_id.value(row).to_string();
+            let entity_type_str = entity_type.value(row).to_string();
+            let (_, entity_name) = id
+                .find(':')
+                .map(|idx| (id[..idx].to_string(), id[idx + 1..].to_string()))
+                .unwrap_or_else(|| (entity_type_str.clone(), id.clone()));
+
+            let created = millis_to_datetime(created_at.value(row)).ok_or_else(|| {
+                Error::Serialization(format!("created_at out of range for row {row}"))
+            })?;
+            let last = if last_action.is_null(row) {
+                None
+            } else {
+                Some(millis_to_datetime(last_action.value(row)).ok_or_else(|| {
+                    Error::Serialization(format!("last_action out of range for row {row}"))
+                })?)
+            };
+
+            Ok(EntityTrust {
+                entity_id: id,
+                entity_type: entity_type_str,
+                entity_name,
+                t3: T3Tensor::new(talent.value(row), training.value(row), temperament.value(row)),
+                v3: V3Tensor::new(valuation.value(row), veracity.value(row), validity.value(row)),
+                witnessed_by: Vec::new(),
+                has_witnessed: Vec::new(),
+                action_count: action_count.value(row),
+                success_count: success_count.value(row),
+                witness_count: witness_count.value(row),
+                last_action: last,
+                created_at: created,
+            })
+        })
+        .collect()
+}
+
+fn millis_to_datetime(millis: i64) -> Option<DateTime<Utc>> {
+    DateTime::from_timestamp_millis(millis)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_entities() -> Vec<EntityTrust> {
+        let mut a = EntityTrust::new("mcp:filesystem");
+        a.update_from_outcome(true, 0.1);
+        a.receive_witness("session:abc", true, 0.2);
+
+        let b = EntityTrust::new("session:xyz");
+
+        vec![a, b]
+    }
+
+    #[test]
+    fn test_to_record_batch_has_expected_shape() {
+        let entities = sample_entities();
+        let batch = to_record_batch(&entities);
+
+        assert_eq!(batch.num_rows(), 2);
+        assert_eq!(batch.num_columns(), schema().fields().len());
+        assert_eq!(
+            column::<StringArray>(&batch, "entity_id").unwrap().value(0),
+            "mcp:filesystem"
+        );
+    }
+
+    #[test]
+    fn test_round_trip_preserves_scalar_fields() {
+        let entities = sample_entities();
+        let batch = to_record_batch(&entities);
+        let restored = from_record_batch(&batch).unwrap();
+
+        assert_eq!(restored.len(), entities.len());
+        for (original, restored) in entities.iter().zip(restored.iter()) {
+            assert_eq!(restored.entity_id, original.entity_id);
+            assert_eq!(restored.entity_type, original.entity_type);
+            assert_eq!(restored.t3.talent, original.t3.talent);
+            assert_eq!(restored.t3.training, original.t3.training);
+            assert_eq!(restored.t3.temperament, original.t3.temperament);
+            assert_eq!(restored.v3.valuation, original.v3.valuation);
+            assert_eq!(restored.v3.veracity, original.v3.veracity);
+            assert_eq!(restored.v3.validity, original.v3.validity);
+            assert_eq!(restored.action_count, original.action_count);
+            assert_eq!(restored.success_count, original.success_count);
+            assert_eq!(restored.witness_count, original.witness_count);
+            assert_eq!(
+                restored.last_action.map(|ts| ts.timestamp_millis()),
+                original.last_action.map(|ts| ts.timestamp_millis())
+            );
+            assert_eq!(
+                restored.created_at.timestamp_millis(),
+                original.created_at.timestamp_millis()
+            );
+            // Not part of the columnar schema — dropped on round trip.
+            assert!(restored.witnessed_by.is_empty());
+            assert!(restored.has_witnessed.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_from_record_batch_rejects_missing_column() {
+        let entities = sample_entities();
+        let batch = to_record_batch(&entities);
+        let truncated = batch.project(&[0, 1]).unwrap();
+
+        let err = from_record_batch(&truncated).unwrap_err();
+        assert!(matches!(err, Error::Serialization(_)));
+    }
+}
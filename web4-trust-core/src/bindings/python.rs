@@ -16,6 +16,47 @@ use crate::storage::FileStore;
 use std::sync::Arc;
 use parking_lot::RwLock;
 
+#[cfg(feature = "otel")]
+use crate::otel::TrustMeter;
+#[cfg(feature = "otel")]
+use std::sync::OnceLock;
+
+/// Set by [`init_otel`]; `None` until a Python caller opts in, after which
+/// [`PyTrustStore`]'s `update` and `witness` record through it automatically.
+#[cfg(feature = "otel")]
+static GLOBAL_METER: OnceLock<TrustMeter> = OnceLock::new();
+
+/// Initialize a process-wide OTLP meter so every [`PyTrustStore`]
+/// automatically records `web4.trust.updates`/
+/// `web4.trust.witness_events`/`web4.trust.t3_average` (see
+/// [`crate::otel::TrustMeter`]) on `update`/`witness`, without Python
+/// needing its own OpenTelemetry wiring. Only a metrics pipeline is
+/// installed: the `trust_store.*` tracing spans are emitted whether or not
+/// this is called, and no tracing subscriber or trace exporter is set up
+/// for them here. Only the first call takes effect; later calls are a
+/// silent no-op.
+#[cfg(feature = "otel")]
+#[pyfunction]
+#[pyo3(signature = (endpoint=None))]
+fn init_otel(endpoint: Option<&str>) -> PyResult<()> {
+    use opentelemetry_otlp::WithExportConfig;
+
+    let mut exporter = opentelemetry_otlp::new_exporter().tonic();
+    if let Some(endpoint) = endpoint {
+        exporter = exporter.with_endpoint(endpoint);
+    }
+
+    let provider = opentelemetry_otlp::new_pipeline()
+        .metrics(opentelemetry_sdk::runtime::Tokio)
+        .with_exporter(exporter)
+        .build()
+        .map_err(|e| PyValueError::new_err(e.to_string()))?;
+
+    let meter = provider.meter("web4_trust_core");
+    let _ = GLOBAL_METER.set(TrustMeter::new(&meter));
+    Ok(())
+}
+
 /// Python-exposed T3 Trust Tensor
 #[pyclass(name = "T3Tensor")]
 #[derive(Clone)]
@@ -447,6 +488,9 @@ impl PyTrustStore {
 
     /// Get entity trust (creates if doesn't exist)
     fn get(&self, entity_id: &str) -> PyResult<PyEntityTrust> {
+        #[cfg(feature = "otel")]
+        let _span = tracing::info_span!("trust_store.get", entity_id = %entity_id).entered();
+
         let store = self.inner.read();
         let trust = store.get(entity_id)
             .map_err(|e| PyValueError::new_err(e.to_string()))?;
@@ -455,6 +499,14 @@ impl PyTrustStore {
 
     /// Save entity trust
     fn save(&self, trust: &PyEntityTrust) -> PyResult<()> {
+        #[cfg(feature = "otel")]
+        let _span = tracing::info_span!(
+            "trust_store.save",
+            entity_id = %trust.inner.entity_id,
+            entity_type = %trust.inner.entity_type
+        )
+        .entered();
+
         let store = self.inner.read();
         store.save(&trust.inner)
             .map_err(|e| PyValueError::new_err(e.to_string()))
@@ -462,9 +514,21 @@ impl PyTrustStore {
 
     /// Update entity from outcome
     fn update(&self, entity_id: &str, success: bool, magnitude: f64) -> PyResult<PyEntityTrust> {
+        #[cfg(feature = "otel")]
+        let _span = tracing::info_span!("trust_store.update", entity_id = %entity_id).entered();
+
         let store = self.inner.read();
-        let trust = store.update(entity_id, success, magnitude)
-            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        let result = store.update(entity_id, success, magnitude);
+
+        #[cfg(feature = "otel")]
+        if let Some(meter) = GLOBAL_METER.get() {
+            match &result {
+                Ok(trust) => meter.record_store_update(&trust.entity_type, true, Some(trust.t3_average())),
+                Err(_) => meter.record_store_update("unknown", false, None),
+            }
+        }
+
+        let trust = result.map_err(|e| PyValueError::new_err(e.to_string()))?;
         Ok(PyEntityTrust { inner: trust })
     }
 
@@ -476,20 +540,46 @@ impl PyTrustStore {
         success: bool,
         magnitude: f64,
     ) -> PyResult<(PyEntityTrust, PyEntityTrust)> {
+        #[cfg(feature = "otel")]
+        let _span = tracing::info_span!(
+            "trust_store.witness",
+            witness_id = %witness_id,
+            target_id = %target_id
+        )
+        .entered();
+
         let store = self.inner.read();
-        let (witness, target) = store.witness(witness_id, target_id, success, magnitude)
-            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        let result = store.witness(witness_id, target_id, success, magnitude);
+
+        #[cfg(feature = "otel")]
+        if let Some(meter) = GLOBAL_METER.get() {
+            match &result {
+                Ok((_witness, target)) => {
+                    meter.record_store_witness(&target.entity_type, true, Some(target.t3_average()))
+                }
+                Err(_) => meter.record_store_witness("unknown", false, None),
+            }
+        }
+
+        let (witness, target) = result.map_err(|e| PyValueError::new_err(e.to_string()))?;
         Ok((PyEntityTrust { inner: witness }, PyEntityTrust { inner: target }))
     }
 
     /// List all entity IDs
     #[pyo3(signature = (entity_type=None))]
     fn list_entities(&self, entity_type: Option<&str>) -> PyResult<Vec<String>> {
-        let store = self.inner.read();
         let etype = entity_type.map(|t| {
             EntityType::from_entity_id(&format!("{}:_", t)).ok()
         }).flatten();
 
+        #[cfg(feature = "otel")]
+        let _span = tracing::info_span!(
+            "trust_store.list",
+            entity_type = %etype.as_ref().map(|t| t.to_string()).unwrap_or_default()
+        )
+        .entered();
+
+        let store = self.inner.read();
         store.list(etype.as_ref())
             .map_err(|e| PyValueError::new_err(e.to_string()))
     }
@@ -503,6 +593,9 @@ impl PyTrustStore {
 
     /// Delete entity
     fn delete(&self, entity_id: &str) -> PyResult<bool> {
+        #[cfg(feature = "otel")]
+        let _span = tracing::info_span!("trust_store.delete", entity_id = %entity_id).entered();
+
         let store = self.inner.read();
         store.delete(entity_id)
             .map_err(|e| PyValueError::new_err(e.to_string()))
@@ -527,6 +620,49 @@ impl PyTrustStore {
         Ok(trusts)
     }
 
+    /// Export the whole store (optionally filtered by `entity_type`) as a
+    /// single Arrow `RecordBatch`, handed across the Arrow C Data Interface
+    /// so pandas/Polars/`pyarrow.Table.from_batches([...])` can read it
+    /// without an intermediate JSON round trip. See
+    /// [`crate::arrow`](crate::arrow) for the column layout.
+    #[cfg(feature = "arrow")]
+    #[pyo3(signature = (entity_type=None))]
+    fn to_arrow(
+        &self,
+        entity_type: Option<&str>,
+    ) -> PyResult<arrow::pyarrow::PyArrowType<arrow::record_batch::RecordBatch>> {
+        let etype = entity_type
+            .map(|t| EntityType::from_entity_id(&format!("{}:_", t)))
+            .transpose()
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+
+        let store = self.inner.read();
+        let batch = store
+            .export_record_batch(etype.as_ref())
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        Ok(arrow::pyarrow::PyArrowType(batch))
+    }
+
+    /// Serialize this store's entities and witnessing history as W3C
+    /// PROV-O Turtle. See [`crate::prov`](crate::prov).
+    #[cfg(feature = "prov")]
+    fn to_prov_turtle(&self) -> PyResult<String> {
+        let store = self.inner.read();
+        store.to_prov_turtle().map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Serialize this store's entities and witnessing history as
+    /// PROV-JSON-LD, returned as a parsed Python object.
+    #[cfg(feature = "prov")]
+    fn to_prov_jsonld(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let store = self.inner.read();
+        let jsonld = store.to_prov_jsonld().map_err(|e| PyValueError::new_err(e.to_string()))?;
+        let json_text = serde_json::to_string(&jsonld)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        let json_module = py.import("json")?;
+        json_module.call_method1("loads", (json_text,))?.extract()
+    }
+
     fn __repr__(&self) -> String {
         #[cfg(feature = "file-store")]
         {
@@ -556,6 +692,8 @@ fn web4_trust(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_class::<PyEntityTrust>()?;
     m.add_class::<PyTrustStore>()?;
     m.add_function(wrap_pyfunction!(create_memory_store, m)?)?;
+    #[cfg(feature = "otel")]
+    m.add_function(wrap_pyfunction!(init_otel, m)?)?;
 
     // Version info
     m.add("__version__", env!("CARGO_PKG_VERSION"))?;
@@ -8,9 +8,11 @@ use js_sys::{Array, Object, Reflect};
 use crate::tensor::{T3Tensor as RustT3, V3Tensor as RustV3};
 use crate::entity::EntityTrust as RustEntityTrust;
 use crate::storage::{TrustStore, InMemoryStore};
+use crate::credential::TrustCredential;
 
 use std::sync::Arc;
 use std::cell::RefCell;
+use web4_core::crypto::{self, KeyPair as RustKeyPair, PublicKey as RustPublicKey, SignatureBytes as RustSignatureBytes};
 
 /// WASM-exposed T3 Trust Tensor
 #[wasm_bindgen]
@@ -360,6 +362,100 @@ impl EntityTrust {
     }
 }
 
+/// WASM-exposed Ed25519 keypair, for signing trust events client-side.
+#[wasm_bindgen]
+pub struct KeyPair {
+    inner: RustKeyPair,
+}
+
+#[wasm_bindgen]
+impl KeyPair {
+    /// Generate a new random keypair.
+    #[wasm_bindgen(constructor)]
+    pub fn generate() -> Self {
+        Self { inner: RustKeyPair::generate() }
+    }
+
+    /// Reconstruct a keypair from a raw 32-byte secret key.
+    #[wasm_bindgen(js_name = fromSecretBytes)]
+    pub fn from_secret_bytes(secret_key_bytes: &[u8]) -> Result<KeyPair, JsValue> {
+        let bytes: [u8; 32] = secret_key_bytes
+            .try_into()
+            .map_err(|_| JsValue::from_str("secret key must be 32 bytes"))?;
+        Ok(Self { inner: RustKeyPair::from_secret_bytes(&bytes) })
+    }
+
+    /// Hex-encoded public key, suitable for `PublicKey.fromHex`.
+    #[wasm_bindgen(js_name = publicKeyHex)]
+    pub fn public_key_hex(&self) -> String {
+        self.inner.verifying_key().to_hex()
+    }
+
+    /// Multibase-encoded public key (a `did:key:`-embeddable suffix),
+    /// suitable for `PublicKey.fromMultibase`.
+    #[wasm_bindgen(js_name = publicKeyMultibase)]
+    pub fn public_key_multibase(&self) -> String {
+        self.inner.verifying_key().to_multibase()
+    }
+
+    /// Raw 32-byte secret key.
+    ///
+    /// WARNING: handle with care; this leaves Rust's custody entirely.
+    #[wasm_bindgen(js_name = secretKeyBytes)]
+    pub fn secret_key_bytes(&self) -> Vec<u8> {
+        self.inner.secret_key_bytes().to_vec()
+    }
+
+    /// Sign a message, returning the raw 64-byte signature.
+    pub fn sign(&self, message: &[u8]) -> Vec<u8> {
+        self.inner.sign(message).bytes.to_vec()
+    }
+}
+
+/// WASM-exposed Ed25519 public key, for verifying signatures client-side.
+#[wasm_bindgen]
+pub struct PublicKey {
+    inner: RustPublicKey,
+}
+
+#[wasm_bindgen]
+impl PublicKey {
+    /// Parse from a hex-encoded public key, as produced by `KeyPair.publicKeyHex`.
+    #[wasm_bindgen(js_name = fromHex)]
+    pub fn from_hex(hex_str: &str) -> Result<PublicKey, JsValue> {
+        let inner = RustPublicKey::from_hex(hex_str).map_err(|e| JsValue::from_str(&e.to_string()))?;
+        Ok(Self { inner })
+    }
+
+    /// Parse from a multibase-encoded public key, as produced by
+    /// `KeyPair.publicKeyMultibase` (or a bare `did:key:` suffix).
+    #[wasm_bindgen(js_name = fromMultibase)]
+    pub fn from_multibase(s: &str) -> Result<PublicKey, JsValue> {
+        let inner = RustPublicKey::from_multibase(s).map_err(|e| JsValue::from_str(&e.to_string()))?;
+        Ok(Self { inner })
+    }
+
+    /// Multibase-encoded public key (a `did:key:`-embeddable suffix).
+    #[wasm_bindgen(js_name = toMultibase)]
+    pub fn to_multibase(&self) -> String {
+        self.inner.to_multibase()
+    }
+
+    /// Verify a 64-byte signature over `message`.
+    pub fn verify(&self, message: &[u8], signature: &[u8]) -> bool {
+        match <[u8; 64]>::try_from(signature) {
+            Ok(bytes) => self.inner.verify(message, &RustSignatureBytes::from_bytes(bytes)).is_ok(),
+            Err(_) => false,
+        }
+    }
+}
+
+/// Compute the SHA-256 hash of `data`, hex-encoded.
+#[wasm_bindgen(js_name = sha256Hex)]
+pub fn sha256_hex(data: &[u8]) -> String {
+    crypto::sha256_hex(data)
+}
+
 /// WASM-exposed TrustStore (in-memory only for WASM)
 #[wasm_bindgen]
 pub struct WasmTrustStore {
@@ -398,6 +494,31 @@ impl WasmTrustStore {
             .map_err(|e| JsValue::from_str(&e.to_string()))
     }
 
+    /// Like `update`, but requires a signature from `signUpdate` over the
+    /// same `(entity_id, success, magnitude, timestamp)` claim under
+    /// `public_key_hex`, rejecting unsigned or badly-signed mutations
+    /// before they reach the store.
+    #[wasm_bindgen(js_name = updateSigned)]
+    pub fn update_signed(
+        &self,
+        entity_id: &str,
+        success: bool,
+        magnitude: f64,
+        timestamp: f64,
+        public_key_hex: &str,
+        signature: &[u8],
+    ) -> Result<EntityTrust, JsValue> {
+        let ok = verify_update(public_key_hex, entity_id, success, magnitude, timestamp, signature)?;
+        if !ok {
+            return Err(JsValue::from_str("invalid signature for trust-store update"));
+        }
+
+        let store = self.inner.borrow();
+        store.update(entity_id, success, magnitude)
+            .map(|t| EntityTrust { inner: t })
+            .map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+
     /// Witness event
     pub fn witness(
         &self,
@@ -465,3 +586,80 @@ pub fn init() {
 pub fn version() -> String {
     env!("CARGO_PKG_VERSION").to_string()
 }
+
+/// Issue a signed W3C Verifiable Credential over `entity`'s current T3/V3
+/// snapshot, keyed by a raw 32-byte Ed25519 secret key. Returns the
+/// credential as a plain JS object (the issuer `did:key` is derived from
+/// the secret key, so no separate public key input is needed).
+#[wasm_bindgen(js_name = issueCredential)]
+pub fn issue_credential(secret_key_bytes: &[u8], entity: &EntityTrust) -> Result<JsValue, JsValue> {
+    let bytes: [u8; 32] = secret_key_bytes
+        .try_into()
+        .map_err(|_| JsValue::from_str("secret key must be 32 bytes"))?;
+    let keypair = RustKeyPair::from_secret_bytes(&bytes);
+    let credential = TrustCredential::issue(&entity.inner, &keypair);
+    let json = serde_json::to_string(&credential).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    js_sys::JSON::parse(&json).map_err(|_| JsValue::from_str("failed to build credential object"))
+}
+
+/// Verify a [`TrustCredential`] (as returned by `issueCredential`): recovers
+/// the issuer's public key from its embedded `did:key` and checks the proof.
+#[wasm_bindgen(js_name = verifyCredential)]
+pub fn verify_credential(credential: JsValue) -> Result<bool, JsValue> {
+    let json = js_sys::JSON::stringify(&credential)
+        .map_err(|_| JsValue::from_str("credential is not serializable"))?
+        .as_string()
+        .ok_or_else(|| JsValue::from_str("credential is not serializable"))?;
+    let credential: TrustCredential =
+        serde_json::from_str(&json).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    Ok(credential.verify().is_ok())
+}
+
+/// Canonical bytes signed by `signUpdate` and checked by `verifyUpdate` /
+/// `WasmTrustStore.updateSigned`: a JSON-canonicalized
+/// `(entity_id, success, magnitude, timestamp)` claim.
+fn update_signing_bytes(entity_id: &str, success: bool, magnitude: f64, timestamp: f64) -> Vec<u8> {
+    serde_json::to_vec(&serde_json::json!({
+        "entity_id": entity_id,
+        "success": success,
+        "magnitude": magnitude,
+        "timestamp": timestamp,
+    }))
+    .expect("serde_json::Value always serializes")
+}
+
+/// Sign a trust-store update claim with a raw 32-byte Ed25519 secret key,
+/// producing the signature bytes to pass to `WasmTrustStore.updateSigned`.
+#[wasm_bindgen(js_name = signUpdate)]
+pub fn sign_update(
+    secret_key_bytes: &[u8],
+    entity_id: &str,
+    success: bool,
+    magnitude: f64,
+    timestamp: f64,
+) -> Result<Vec<u8>, JsValue> {
+    let bytes: [u8; 32] = secret_key_bytes
+        .try_into()
+        .map_err(|_| JsValue::from_str("secret key must be 32 bytes"))?;
+    let keypair = RustKeyPair::from_secret_bytes(&bytes);
+    let message = update_signing_bytes(entity_id, success, magnitude, timestamp);
+    Ok(keypair.sign(&message).bytes.to_vec())
+}
+
+/// Verify a signature produced by `signUpdate`, without applying it to a store.
+#[wasm_bindgen(js_name = verifyUpdate)]
+pub fn verify_update(
+    public_key_hex: &str,
+    entity_id: &str,
+    success: bool,
+    magnitude: f64,
+    timestamp: f64,
+    signature: &[u8],
+) -> Result<bool, JsValue> {
+    let public_key = RustPublicKey::from_hex(public_key_hex).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    let sig_bytes: [u8; 64] = signature
+        .try_into()
+        .map_err(|_| JsValue::from_str("signature must be 64 bytes"))?;
+    let message = update_signing_bytes(entity_id, success, magnitude, timestamp);
+    Ok(public_key.verify(&message, &RustSignatureBytes::from_bytes(sig_bytes)).is_ok())
+}
@@ -0,0 +1,251 @@
+//! Signed W3C Verifiable Credentials wrapping an [`EntityTrust`] snapshot.
+//!
+//! `EntityTrust`'s serde JSON (and the WASM binding's `toJSON`) is plain JSON: a relying
+//! party has no way to confirm the T3/V3 values actually came from a given
+//! keyholder. [`TrustCredential`] instead signs a canonicalized snapshot with
+//! an Ed25519 [`KeyPair`](web4_core::crypto::KeyPair) and identifies the
+//! issuer by a `did:key` derived from that same keypair's public key, so a
+//! relying party can verify the claim without an out-of-band key lookup —
+//! the same shape [`WitnessCredential`](crate::witnessing::WitnessCredential)
+//! uses for individual witness events, applied to a whole trust snapshot.
+//!
+//! The `did:key` method ([w3c-ccg/did-method-key]) used here is the minimal
+//! Ed25519 case: `did:key:` followed by
+//! [`PublicKey::to_multibase`](web4_core::crypto::PublicKey::to_multibase),
+//! which already multicodec-prefixes and base58btc-encodes the raw key.
+//!
+//! The proof is a detached JWS (`alg: "EdDSA"`, `b64: false`) over the
+//! canonicalized credential-minus-proof bytes, following the shape of the
+//! `Ed25519Signature2018` suite — this module implements just enough of that
+//! suite to round-trip through `issue`/`verify`, not a general JOSE/VC
+//! toolkit.
+//!
+//! [w3c-ccg/did-method-key]: https://w3c-ccg.github.io/did-method-key/
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD as BASE64URL, Engine as _};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use web4_core::crypto::{KeyPair, PublicKey, SignatureBytes};
+
+use crate::entity::EntityTrust;
+use crate::{Error, Result};
+
+const CREDENTIAL_CONTEXT: &str = "https://www.w3.org/2018/credentials/v1";
+const CREDENTIAL_TYPE: &str = "VerifiableCredential";
+const TRUST_CREDENTIAL_TYPE: &str = "Web4TrustCredential";
+const JWS_HEADER: &str = r#"{"alg":"EdDSA","b64":false,"crit":["b64"]}"#;
+
+/// `credentialSubject` of a [`TrustCredential`]: the entity's identity and
+/// its T3/V3 tensor values at the moment of issuance.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TrustCredentialSubject {
+    /// The entity's ID (format `"type:name"`).
+    pub id: String,
+    /// T3 (Talent/Training/Temperament) tensor values, in that order.
+    pub t3: [f64; 3],
+    /// V3 (Valuation/Veracity/Validity) tensor values, in that order.
+    pub v3: [f64; 3],
+}
+
+/// Detached-JWS proof binding a [`TrustCredential`] to its issuer's keypair.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TrustCredentialProof {
+    /// Proof suite identifier.
+    #[serde(rename = "type")]
+    pub proof_type: String,
+    /// When the proof was produced.
+    pub created: DateTime<Utc>,
+    /// `did:key` of the issuer, doubling as the verification method.
+    pub verification_method: String,
+    /// Detached JWS: `base64url(header) + ".." + base64url(signature)`.
+    pub jws: String,
+}
+
+/// An [`EntityTrust`] snapshot packaged as a W3C Verifiable Credential,
+/// signed by a `did:key` issuer.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TrustCredential {
+    /// JSON-LD context.
+    #[serde(rename = "@context")]
+    pub context: Vec<String>,
+    /// Credential types; always `["VerifiableCredential", "Web4TrustCredential"]`.
+    #[serde(rename = "type")]
+    pub credential_type: Vec<String>,
+    /// The issuing keyholder's `did:key` identifier.
+    pub issuer: String,
+    /// When the credential was issued.
+    pub issuance_date: DateTime<Utc>,
+    /// The entity and its tensor values.
+    pub credential_subject: TrustCredentialSubject,
+    /// Cryptographic proof binding the snapshot to `issuer`.
+    pub proof: TrustCredentialProof,
+}
+
+impl TrustCredential {
+    /// Issue a credential over `entity`'s current T3/V3 snapshot, signed with
+    /// `keypair`. The issuer DID is derived from `keypair`'s public key.
+    pub fn issue(entity: &EntityTrust, keypair: &KeyPair) -> Self {
+        let issuance_date = Utc::now();
+        let issuer = did_key_from_public_key(&keypair.verifying_key());
+
+        let credential_subject = TrustCredentialSubject {
+            id: entity.entity_id.clone(),
+            t3: [entity.t3.talent, entity.t3.training, entity.t3.temperament],
+            v3: [
+                entity.v3.valuation,
+                entity.v3.veracity,
+                entity.v3.validity,
+            ],
+        };
+
+        let mut unsigned = Self {
+            context: vec![CREDENTIAL_CONTEXT.to_string()],
+            credential_type: vec![CREDENTIAL_TYPE.to_string(), TRUST_CREDENTIAL_TYPE.to_string()],
+            issuer: issuer.clone(),
+            issuance_date,
+            credential_subject,
+            proof: TrustCredentialProof {
+                proof_type: "Ed25519Signature2018".to_string(),
+                created: issuance_date,
+                verification_method: issuer,
+                jws: String::new(),
+            },
+        };
+
+        let signing_input = jws_signing_input(&unsigned);
+        let signature = keypair.sign(&signing_input);
+        unsigned.proof.jws = encode_detached_jws(&signature);
+        unsigned
+    }
+
+    /// Verify the proof: recomputes the signing input, recovers the issuer's
+    /// public key from its `did:key`, and checks the detached JWS signature.
+    pub fn verify(&self) -> Result<()> {
+        let public_key = public_key_from_did_key(&self.issuer)?;
+        if self.proof.verification_method != self.issuer {
+            return Err(Error::InvalidTrustCredential(
+                "proof.verification_method does not match issuer".into(),
+            ));
+        }
+
+        let signature = decode_detached_jws(&self.proof.jws)?;
+        let signing_input = jws_signing_input(self);
+        public_key
+            .verify(&signing_input, &signature)
+            .map_err(|e| Error::InvalidTrustCredential(e.to_string()))
+    }
+}
+
+/// Bytes signed by the JWS: `header_b64 + "." + canonicalized-credential-minus-proof`.
+///
+/// The credential is canonicalized by round-tripping through
+/// `serde_json::Value` (whose object map sorts keys alphabetically) with the
+/// `proof` field removed, so proof generation never signs over itself.
+fn jws_signing_input(credential: &TrustCredential) -> Vec<u8> {
+    let mut value = serde_json::to_value(credential).expect("TrustCredential always serializes");
+    if let Value::Object(ref mut map) = value {
+        map.remove("proof");
+    }
+    let payload = serde_json::to_vec(&value).expect("serde_json::Value always serializes");
+
+    let header_b64 = BASE64URL.encode(JWS_HEADER.as_bytes());
+    let mut signing_input = header_b64.into_bytes();
+    signing_input.push(b'.');
+    signing_input.extend_from_slice(&payload);
+    signing_input
+}
+
+fn encode_detached_jws(signature: &SignatureBytes) -> String {
+    let header_b64 = BASE64URL.encode(JWS_HEADER.as_bytes());
+    let sig_b64 = BASE64URL.encode(signature.bytes);
+    format!("{header_b64}..{sig_b64}")
+}
+
+fn decode_detached_jws(jws: &str) -> Result<SignatureBytes> {
+    let (header_b64, sig_b64) = jws
+        .split_once("..")
+        .ok_or_else(|| Error::InvalidTrustCredential("jws is not in detached form".into()))?;
+    if header_b64 != BASE64URL.encode(JWS_HEADER.as_bytes()) {
+        return Err(Error::InvalidTrustCredential(
+            "unexpected JWS header".into(),
+        ));
+    }
+    let sig_bytes = BASE64URL
+        .decode(sig_b64)
+        .map_err(|e| Error::InvalidTrustCredential(format!("malformed base64url jws: {e}")))?;
+    let sig_bytes: [u8; 64] = sig_bytes
+        .try_into()
+        .map_err(|_| Error::InvalidTrustCredential("jws signature is not 64 bytes".into()))?;
+    Ok(SignatureBytes::from_bytes(sig_bytes))
+}
+
+/// Derive a `did:key` identifier from an Ed25519 public key.
+pub fn did_key_from_public_key(public_key: &PublicKey) -> String {
+    format!("did:key:{}", public_key.to_multibase())
+}
+
+/// Parse a `did:key` identifier back into an Ed25519 [`PublicKey`].
+pub fn public_key_from_did_key(did: &str) -> Result<PublicKey> {
+    let multibase = did
+        .strip_prefix("did:key:")
+        .ok_or_else(|| Error::InvalidTrustCredential(format!("not a did:key: {did}")))?;
+    PublicKey::from_multibase(multibase)
+        .map_err(|e| Error::InvalidTrustCredential(format!("invalid did:key: {e}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::EntityTrust;
+
+    #[test]
+    fn test_did_key_round_trips_through_public_key() {
+        let keypair = KeyPair::generate();
+        let did = did_key_from_public_key(&keypair.verifying_key());
+        assert!(did.starts_with("did:key:z"));
+
+        let recovered = public_key_from_did_key(&did).unwrap();
+        assert_eq!(recovered, keypair.verifying_key());
+    }
+
+    #[test]
+    fn test_issue_and_verify_round_trip() {
+        let keypair = KeyPair::generate();
+        let entity = EntityTrust::new("human:alice");
+
+        let credential = TrustCredential::issue(&entity, &keypair);
+
+        assert_eq!(credential.credential_subject.id, "human:alice");
+        assert_eq!(
+            credential.credential_type,
+            vec!["VerifiableCredential", "Web4TrustCredential"]
+        );
+        assert!(credential.verify().is_ok());
+    }
+
+    #[test]
+    fn test_verify_rejects_tampered_subject() {
+        let keypair = KeyPair::generate();
+        let entity = EntityTrust::new("human:alice");
+        let mut credential = TrustCredential::issue(&entity, &keypair);
+
+        credential.credential_subject.t3[0] = 0.99;
+
+        assert!(credential.verify().is_err());
+    }
+
+    #[test]
+    fn test_verify_rejects_wrong_issuer_key() {
+        let keypair = KeyPair::generate();
+        let other = KeyPair::generate();
+        let entity = EntityTrust::new("human:alice");
+        let mut credential = TrustCredential::issue(&entity, &keypair);
+
+        let wrong_did = did_key_from_public_key(&other.verifying_key());
+        credential.issuer = wrong_did.clone();
+        credential.proof.verification_method = wrong_did;
+
+        assert!(credential.verify().is_err());
+    }
+}
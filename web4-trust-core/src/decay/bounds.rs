@@ -0,0 +1,134 @@
+//! Probabilistic trust bounds that decay toward uncertainty
+//!
+//! A single decayed scalar cannot distinguish "confidently medium trust" from
+//! "no recent evidence" — both read as a middling number. Borrowing the
+//! upper/lower liquidity bounds that probabilistic scorers keep per channel,
+//! [`TrustBounds`] tracks a `[lower, upper]` band. The point estimate is the
+//! midpoint and the band *width* encodes confidence: evidence narrows it,
+//! inactivity widens it back toward the neutral prior `[floor, 1.0]`.
+
+use serde::{Deserialize, Serialize};
+
+use super::{calculate_decay_factor, DecayConfig};
+
+/// An uncertainty band over an entity's trust.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TrustBounds {
+    /// Lower bound — the trust we are confident the entity is *at least* worth.
+    pub lower: f64,
+    /// Upper bound — the trust we are confident the entity is *at most* worth.
+    pub upper: f64,
+    /// Unix timestamp (seconds) of the last observation.
+    pub last_update: i64,
+}
+
+impl TrustBounds {
+    /// Create a maximally-uncertain band `[0.0, 1.0]` as of `last_update`.
+    pub fn new(last_update: i64) -> Self {
+        Self { lower: 0.0, upper: 1.0, last_update }
+    }
+
+    /// Create the neutral prior band `[floor, 1.0]` as of `last_update`.
+    pub fn with_prior(floor: f64, last_update: i64) -> Self {
+        Self { lower: floor.clamp(0.0, 1.0), upper: 1.0, last_update }
+    }
+
+    /// Point estimate: the midpoint of the band.
+    pub fn point(&self) -> f64 {
+        (self.lower + self.upper) / 2.0
+    }
+
+    /// Band width `upper - lower`. Narrow means high confidence.
+    pub fn width(&self) -> f64 {
+        self.upper - self.lower
+    }
+
+    /// Fold in a new observation of trust `value` with `weight` in `[0, 1]`.
+    ///
+    /// Positive evidence (`value` above the current lower bound) raises `lower`
+    /// toward the observed value; negative evidence (`value` below the current
+    /// upper bound) lowers `upper` toward it. Either way the band tightens
+    /// around recent behavior. `timestamp` becomes the new baseline.
+    pub fn observe(&mut self, value: f64, weight: f64, timestamp: i64) {
+        let value = value.clamp(0.0, 1.0);
+        let weight = weight.clamp(0.0, 1.0);
+
+        if value > self.lower {
+            self.lower += weight * (value - self.lower);
+        }
+        if value < self.upper {
+            self.upper -= weight * (self.upper - value);
+        }
+        // Keep the band well-formed.
+        if self.lower > self.upper {
+            let mid = (self.lower + self.upper) / 2.0;
+            self.lower = mid;
+            self.upper = mid;
+        }
+        self.last_update = timestamp;
+    }
+
+    /// Return the band as it would read at `timestamp`, widened by inactivity.
+    ///
+    /// Without mutating `self`, this pulls `lower` down toward `config.floor`
+    /// and `upper` up toward `1.0` by the crate's decay factor, so that a long
+    /// gap regresses the band to the neutral prior `[floor, 1.0]`.
+    pub fn decayed_at(&self, timestamp: i64, config: &DecayConfig) -> TrustBounds {
+        let days = (timestamp - self.last_update) as f64 / 86400.0;
+        let factor = if days <= config.grace_period_days {
+            1.0
+        } else {
+            calculate_decay_factor(days - config.grace_period_days, config.rate_per_day)
+        };
+
+        TrustBounds {
+            lower: config.floor + (self.lower - config.floor) * factor,
+            upper: 1.0 - (1.0 - self.upper) * factor,
+            last_update: self.last_update,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_observe_narrows_band() {
+        let mut b = TrustBounds::new(0);
+        let before = b.width();
+        b.observe(0.8, 0.5, 100);
+        assert!(b.width() < before);
+        assert!(b.lower > 0.0);
+        assert!(b.upper < 1.0);
+    }
+
+    #[test]
+    fn test_point_is_midpoint() {
+        let b = TrustBounds { lower: 0.4, upper: 0.8, last_update: 0 };
+        assert!((b.point() - 0.6).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_inactivity_widens_toward_prior() {
+        let mut b = TrustBounds::new(0);
+        b.observe(0.7, 1.0, 0); // collapse band to 0.7
+        let config = DecayConfig::new(0.05, 0.3, 0.0);
+        // One year later the band should be much wider, approaching [floor, 1.0].
+        let decayed = b.decayed_at(365 * 86400, &config);
+        assert!(decayed.width() > b.width());
+        assert!(decayed.lower < b.lower);
+        assert!(decayed.upper > b.upper);
+        assert!(decayed.lower >= config.floor - 1e-9);
+    }
+
+    #[test]
+    fn test_no_decay_within_grace() {
+        let mut b = TrustBounds::new(0);
+        b.observe(0.6, 1.0, 0);
+        let config = DecayConfig::default(); // grace 1 day
+        let same = b.decayed_at(12 * 3600, &config);
+        assert_eq!(same.lower, b.lower);
+        assert_eq!(same.upper, b.upper);
+    }
+}
@@ -4,5 +4,12 @@
 //! This ensures trust reflects recency and prevents stale trust.
 
 mod temporal;
+mod bounds;
+mod state;
 
-pub use temporal::{DecayConfig, calculate_decay_factor};
+pub use bounds::TrustBounds;
+pub use state::{current_batch, DecayState};
+pub use temporal::{
+    apply_decay_batch, apply_decay_to_value, apply_decay_to_value_with, calculate_decay_factor,
+    DecayConfig, DecayStrategy, Exponential, Gompertz, Linear,
+};
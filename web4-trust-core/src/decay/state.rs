@@ -0,0 +1,103 @@
+//! Lazy on-access decay
+//!
+//! Calling [`apply_decay_to_value`](super::apply_decay_to_value) eagerly and
+//! storing the result forces a periodic sweep over every entity to keep values
+//! current. Following the redesign where scorers stopped decaying on a timer
+//! and instead compute decay lazily at lookup time, [`DecayState`] stores the
+//! raw value plus the timestamp of the last update and decays *on read*. Decay
+//! is only paid for on the entities actually queried — no O(N) background sweep
+//! — and the stored state stays deterministic regardless of when it's read.
+
+use serde::{Deserialize, Serialize};
+
+use super::{apply_decay_to_value, DecayConfig};
+
+/// A value paired with the timestamp of its last update, decayed on access.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DecayState {
+    /// The raw (undecayed) value as of `last_update`.
+    pub value: f64,
+    /// Unix timestamp (seconds) when `value` was last recorded.
+    pub last_update: i64,
+}
+
+impl DecayState {
+    /// Create a state holding `value` as of `last_update`.
+    pub fn new(value: f64, last_update: i64) -> Self {
+        Self { value, last_update }
+    }
+
+    /// Compute the decayed value as of `now` without mutating stored state.
+    pub fn current(&self, now: i64, config: &DecayConfig) -> f64 {
+        let days_inactive = (now - self.last_update) as f64 / 86400.0;
+        apply_decay_to_value(self.value, days_inactive, config)
+    }
+
+    /// Reset the baseline on new activity: store `value` as of `now`.
+    pub fn record(&mut self, value: f64, now: i64) {
+        self.value = value;
+        self.last_update = now;
+    }
+}
+
+/// Compute the decayed value of each [`DecayState`] as of `now` in one pass.
+///
+/// The slice variant of [`DecayState::current`], writing results into `out`.
+/// `out` must be at least as long as `states`; extra slots are left untouched.
+/// Precomputes the per-day base and hoists the floor/grace checks, so decaying
+/// a large population is cache-friendly rather than a per-entity call.
+pub fn current_batch(states: &[DecayState], now: i64, config: &DecayConfig, out: &mut [f64]) {
+    let base = 1.0 - config.rate_per_day;
+    let floor = config.floor;
+    let grace = config.grace_period_days;
+
+    for (state, slot) in states.iter().zip(out.iter_mut()) {
+        let days = (now - state.last_update) as f64 / 86400.0;
+        *slot = if days <= grace {
+            state.value
+        } else {
+            (floor + (state.value - floor) * base.powf(days - grace)).max(floor)
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_current_batch_matches_scalar() {
+        let config = DecayConfig::new(0.01, 0.3, 1.0);
+        let states = [
+            DecayState::new(0.9, 0),
+            DecayState::new(0.6, 0),
+            DecayState::new(0.8, 10 * 86400),
+        ];
+        let mut out = [0.0; 3];
+        current_batch(&states, 40 * 86400, &config, &mut out);
+        for (i, s) in states.iter().enumerate() {
+            assert!((out[i] - s.current(40 * 86400, &config)).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_current_is_non_mutating() {
+        let state = DecayState::new(0.9, 0);
+        let config = DecayConfig::new(0.01, 0.3, 0.0);
+        let decayed = state.current(30 * 86400, &config);
+        assert!(decayed < 0.9);
+        // Stored value is untouched — deterministic regardless of query time.
+        assert_eq!(state.value, 0.9);
+    }
+
+    #[test]
+    fn test_record_resets_baseline() {
+        let mut state = DecayState::new(0.9, 0);
+        state.record(0.5, 1000);
+        assert_eq!(state.value, 0.5);
+        assert_eq!(state.last_update, 1000);
+        let config = DecayConfig::default();
+        // No inactivity since the reset → no decay.
+        assert_eq!(state.current(1000, &config), 0.5);
+    }
+}
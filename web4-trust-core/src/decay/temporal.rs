@@ -13,6 +13,12 @@ pub struct DecayConfig {
 
     /// Days of inactivity before decay starts
     pub grace_period_days: f64,
+
+    /// Inflection point (days) for the [`Gompertz`] reputation half-life curve
+    pub midpoint_days: f64,
+
+    /// Steepness `k` of the [`Gompertz`] curve around `midpoint_days`
+    pub steepness: f64,
 }
 
 impl Default for DecayConfig {
@@ -21,6 +27,8 @@ impl Default for DecayConfig {
             rate_per_day: 0.01,  // 1% per day
             floor: 0.3,          // Never below 0.3
             grace_period_days: 1.0, // 1 day grace period
+            midpoint_days: 30.0, // Gompertz inflection at ~one month
+            steepness: 0.1,      // gentle roll-off
         }
     }
 }
@@ -32,6 +40,37 @@ impl DecayConfig {
             rate_per_day: rate_per_day.clamp(0.0, 1.0),
             floor: floor.clamp(0.0, 1.0),
             grace_period_days: grace_period_days.max(0.0),
+            ..Self::default()
+        }
+    }
+
+    /// Create a decay configuration from a half-life instead of a raw rate.
+    ///
+    /// Operators usually think in terms of "how long until trust has fallen
+    /// halfway to the floor," not a per-day percentage. Since
+    /// `apply_decay_to_value` uses `(1 - rate)^days`, setting
+    /// `rate_per_day = 1 - 0.5^(1/half_life_days)` makes `(current - floor)`
+    /// halve after exactly `half_life_days` of effective (post-grace)
+    /// inactivity.
+    pub fn from_half_life(half_life_days: f64, floor: f64, grace_period_days: f64) -> Self {
+        let rate_per_day = if half_life_days <= 0.0 {
+            1.0
+        } else {
+            1.0 - 0.5_f64.powf(1.0 / half_life_days)
+        };
+        Self::new(rate_per_day, floor, grace_period_days)
+    }
+
+    /// Return the half-life (days) implied by `rate_per_day`.
+    ///
+    /// This is the inverse of [`from_half_life`](Self::from_half_life):
+    /// `ln(0.5) / ln(1 - rate_per_day)`. Returns `f64::INFINITY` when
+    /// `rate_per_day == 0.0` (trust never decays).
+    pub fn half_life_days(&self) -> f64 {
+        if self.rate_per_day == 0.0 {
+            f64::INFINITY
+        } else {
+            (0.5_f64.ln()) / (1.0 - self.rate_per_day).ln()
         }
     }
 
@@ -41,6 +80,7 @@ impl DecayConfig {
             rate_per_day: 0.0,
             floor: 0.0,
             grace_period_days: f64::MAX,
+            ..Self::default()
         }
     }
 
@@ -50,6 +90,7 @@ impl DecayConfig {
             rate_per_day: 0.05,  // 5% per day
             floor: 0.2,
             grace_period_days: 0.0,
+            ..Self::default()
         }
     }
 }
@@ -94,22 +135,122 @@ pub fn calculate_decay_factor(days_inactive: f64, decay_rate: f64) -> f64 {
 /// # Returns
 /// Decayed value (never below floor)
 pub fn apply_decay_to_value(current: f64, days_inactive: f64, config: &DecayConfig) -> f64 {
+    apply_decay_to_value_with(current, days_inactive, config, &Exponential)
+}
+
+/// A pluggable decay curve mapping elapsed (post-grace) inactivity to a factor.
+///
+/// The factor is multiplied against `(value - floor)`, so `1.0` means "no
+/// decay" and `0.0` means "fully decayed to the floor". Implementations read
+/// their tuning parameters from [`DecayConfig`], letting callers swap the decay
+/// model without touching the tensor/entity plumbing that consumes it.
+pub trait DecayStrategy {
+    /// Compute the decay factor for `days_inactive` days past the grace period.
+    fn factor(&self, days_inactive: f64, config: &DecayConfig) -> f64;
+}
+
+/// Classic exponential decay: `factor = (1 - rate_per_day)^days`.
+///
+/// This reproduces the crate's original behavior and is the default strategy.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Exponential;
+
+impl DecayStrategy for Exponential {
+    fn factor(&self, days_inactive: f64, config: &DecayConfig) -> f64 {
+        calculate_decay_factor(days_inactive, config.rate_per_day)
+    }
+}
+
+/// Linear decay: `factor = max(floor, 1 - rate_per_day * days)`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Linear;
+
+impl DecayStrategy for Linear {
+    fn factor(&self, days_inactive: f64, config: &DecayConfig) -> f64 {
+        if days_inactive <= 0.0 {
+            return 1.0;
+        }
+        (1.0 - config.rate_per_day * days_inactive).max(config.floor)
+    }
+}
+
+/// Gompertz "reputation half-life" curve: `factor = exp(-exp(k*(days - midpoint)))`.
+///
+/// Trust holds steady while `days < midpoint_days`, then rolls off sharply once
+/// past the inflection point — useful for reputations that survive short lulls
+/// but collapse after prolonged silence. `k` is [`DecayConfig::steepness`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Gompertz;
+
+impl DecayStrategy for Gompertz {
+    fn factor(&self, days_inactive: f64, config: &DecayConfig) -> f64 {
+        if days_inactive <= 0.0 {
+            return 1.0;
+        }
+        let exponent = config.steepness * (days_inactive - config.midpoint_days);
+        (-exponent.exp()).exp().clamp(0.0, 1.0)
+    }
+}
+
+/// Apply decay to a single value using an explicit [`DecayStrategy`].
+///
+/// Honors the grace period and floor from `config`; `apply_decay_to_value` is
+/// the [`Exponential`] special case.
+pub fn apply_decay_to_value_with<S: DecayStrategy>(
+    current: f64,
+    days_inactive: f64,
+    config: &DecayConfig,
+    strategy: &S,
+) -> f64 {
     if days_inactive <= config.grace_period_days {
         return current;
     }
 
     let effective_days = days_inactive - config.grace_period_days;
-    let decay_factor = calculate_decay_factor(effective_days, config.rate_per_day);
+    let decay_factor = strategy.factor(effective_days, config);
 
     // Decay towards floor: new = floor + (current - floor) * factor
     let decayed = config.floor + (current - config.floor) * decay_factor;
     decayed.max(config.floor)
 }
 
+/// Decay a whole slice of values in one cache-friendly pass.
+///
+/// Equivalent to calling [`apply_decay_to_value`] on each element (the default
+/// [`Exponential`] model), but precomputes `(1 - rate_per_day)` once and hoists
+/// the floor/grace-period checks out of the inner loop. `values` and
+/// `days_inactive` must have the same length; any excess in either slice is
+/// ignored. For large trust populations this is markedly faster than a
+/// per-value call.
+pub fn apply_decay_batch(values: &mut [f64], days_inactive: &[f64], config: &DecayConfig) {
+    let base = 1.0 - config.rate_per_day;
+    let floor = config.floor;
+    let grace = config.grace_period_days;
+
+    for (value, &days) in values.iter_mut().zip(days_inactive) {
+        if days <= grace {
+            continue;
+        }
+        let factor = base.powf(days - grace);
+        *value = (floor + (*value - floor) * factor).max(floor);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_batch_matches_scalar() {
+        let config = DecayConfig::new(0.02, 0.3, 1.0);
+        let days = [0.0, 5.0, 30.0, 365.0];
+        let mut values = [0.9, 0.9, 0.9, 0.9];
+        apply_decay_batch(&mut values, &days, &config);
+        for (i, &d) in days.iter().enumerate() {
+            assert!((values[i] - apply_decay_to_value(0.9, d, &config)).abs() < 1e-12);
+        }
+    }
+
     #[test]
     fn test_decay_factor_no_time() {
         assert_eq!(calculate_decay_factor(0.0, 0.01), 1.0);
@@ -171,4 +312,54 @@ mod tests {
         let decayed = apply_decay_to_value(0.9, 100.0, &config);
         assert_eq!(decayed, 0.9);
     }
+
+    #[test]
+    fn test_half_life_round_trip() {
+        let config = DecayConfig::from_half_life(30.0, 0.3, 0.0);
+        assert!((config.half_life_days() - 30.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_half_life_halves_distance_to_floor() {
+        let config = DecayConfig::from_half_life(30.0, 0.3, 0.0);
+        // Start at 0.9: distance to floor is 0.6, should halve to 0.3 above floor.
+        let decayed = apply_decay_to_value(0.9, 30.0, &config);
+        assert!((decayed - (0.3 + 0.3)).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_half_life_infinite_when_no_decay() {
+        assert_eq!(DecayConfig::no_decay().half_life_days(), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_exponential_is_default_behavior() {
+        let config = DecayConfig::default();
+        let via_trait = apply_decay_to_value_with(0.9, 30.0, &config, &Exponential);
+        let via_default = apply_decay_to_value(0.9, 30.0, &config);
+        assert_eq!(via_trait, via_default);
+    }
+
+    #[test]
+    fn test_linear_strategy() {
+        let config = DecayConfig::new(0.01, 0.3, 0.0);
+        // factor = 1 - 0.01 * 10 = 0.9 → value = 0.3 + (0.9 - 0.3) * 0.9
+        let decayed = apply_decay_to_value_with(0.9, 10.0, &config, &Linear);
+        assert!((decayed - (0.3 + 0.6 * 0.9)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_gompertz_holds_then_drops() {
+        let config = DecayConfig {
+            grace_period_days: 0.0,
+            midpoint_days: 30.0,
+            steepness: 0.3,
+            ..Default::default()
+        };
+        let early = apply_decay_to_value_with(0.9, 5.0, &config, &Gompertz);
+        let late = apply_decay_to_value_with(0.9, 60.0, &config, &Gompertz);
+        // Steady early, collapsed toward floor late.
+        assert!(early > 0.8);
+        assert!(late < early);
+    }
 }
@@ -2,10 +2,19 @@
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use web4_core::crypto::{KeyPair, PublicKey};
+use web4_core::{Result as Web4Result, Web4Error};
 
+use crate::decay::{DecayConfig, DecayStrategy};
 use crate::tensor::{T3Tensor, V3Tensor, TrustLevel};
+use crate::transparency::{TransparencyLog, TrustEvent};
+use crate::witnessing::{WitnessCapability, WitnessCredential, WitnessSlate};
+use crate::{Error, Result};
 use super::EntityType;
 
+#[cfg(feature = "otel")]
+use crate::otel::TrustMeter;
+
 /// Entity trust combining T3 and V3 tensors with witnessing relationships
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct EntityTrust {
@@ -284,6 +293,45 @@ impl EntityTrust {
         self.last_action = Some(Utc::now());
     }
 
+    /// Update trust from a direct action outcome and commit it to `log` as a
+    /// [`TrustEvent::Outcome`], returning the leaf's `(index, log_size)` so
+    /// the caller can later produce an inclusion proof.
+    pub fn update_from_outcome_logged(
+        &mut self,
+        success: bool,
+        magnitude: f64,
+        log: &mut TransparencyLog,
+    ) -> (usize, usize) {
+        self.update_from_outcome(success, magnitude);
+        log.append(&TrustEvent::Outcome {
+            entity_id: self.entity_id.clone(),
+            success,
+            magnitude,
+            timestamp: Utc::now(),
+        })
+    }
+
+    /// Update trust from a direct action outcome and record it through
+    /// `meter` (actions/successes counters, magnitude and resulting
+    /// tensor-average histograms, plus a `success_rate`/
+    /// `days_since_last_action` snapshot).
+    #[cfg(feature = "otel")]
+    pub fn update_from_outcome_instrumented(
+        &mut self,
+        success: bool,
+        magnitude: f64,
+        meter: &TrustMeter,
+    ) {
+        self.update_from_outcome(success, magnitude);
+        meter.record_outcome(&self.entity_type, success, magnitude);
+        meter.record_tensors(&self.entity_type, self.t3_average(), self.v3_average());
+        meter.record_snapshot(
+            &self.entity_type,
+            self.success_rate(),
+            self.days_since_last_action(),
+        );
+    }
+
     /// Receive a witness event (another entity observed this one)
     ///
     /// Being witnessed builds:
@@ -306,6 +354,40 @@ impl EntityTrust {
         self.v3.grow_validity(0.01);
     }
 
+    /// Receive a witness event and commit it to `log` as a
+    /// [`TrustEvent::WitnessReceived`], returning the leaf's `(index, log_size)`.
+    pub fn receive_witness_logged(
+        &mut self,
+        witness_id: &str,
+        success: bool,
+        magnitude: f64,
+        log: &mut TransparencyLog,
+    ) -> (usize, usize) {
+        self.receive_witness(witness_id, success, magnitude);
+        log.append(&TrustEvent::WitnessReceived {
+            entity_id: self.entity_id.clone(),
+            witness_id: witness_id.to_string(),
+            success,
+            magnitude,
+            timestamp: Utc::now(),
+        })
+    }
+
+    /// Receive a witness event and record it through `meter` (witness-event
+    /// counter, magnitude and resulting tensor-average histograms).
+    #[cfg(feature = "otel")]
+    pub fn receive_witness_instrumented(
+        &mut self,
+        witness_id: &str,
+        success: bool,
+        magnitude: f64,
+        meter: &TrustMeter,
+    ) {
+        self.receive_witness(witness_id, success, magnitude);
+        meter.record_witness(&self.entity_type, magnitude);
+        meter.record_tensors(&self.entity_type, self.t3_average(), self.v3_average());
+    }
+
     /// Give a witness event (this entity observed another)
     ///
     /// Being a witness builds:
@@ -324,6 +406,123 @@ impl EntityTrust {
         self.v3.add_contribution(0.005);
     }
 
+    /// Give a witness event and commit it to `log` as a
+    /// [`TrustEvent::WitnessGiven`], returning the leaf's `(index, log_size)`.
+    pub fn give_witness_logged(
+        &mut self,
+        target_id: &str,
+        success: bool,
+        magnitude: f64,
+        log: &mut TransparencyLog,
+    ) -> (usize, usize) {
+        self.give_witness(target_id, success, magnitude);
+        log.append(&TrustEvent::WitnessGiven {
+            entity_id: self.entity_id.clone(),
+            target_id: target_id.to_string(),
+            success,
+            magnitude,
+            timestamp: Utc::now(),
+        })
+    }
+
+    /// Give a witness event and record it through `meter` (witness-event
+    /// counter, magnitude and resulting tensor-average histograms).
+    #[cfg(feature = "otel")]
+    pub fn give_witness_instrumented(
+        &mut self,
+        target_id: &str,
+        success: bool,
+        magnitude: f64,
+        meter: &TrustMeter,
+    ) {
+        self.give_witness(target_id, success, magnitude);
+        meter.record_witness(&self.entity_type, magnitude);
+        meter.record_tensors(&self.entity_type, self.t3_average(), self.v3_average());
+    }
+
+    /// Give a witness event under a delegated [`WitnessCapability`] chain.
+    ///
+    /// Validates the full chain via [`WitnessCapability::validate_chain`]
+    /// (every link's signature verifies, none expired, scope/magnitude only
+    /// narrow down the chain, and the final link authorizes `self.entity_id`
+    /// to witness `target_id` at `magnitude`) before applying the update via
+    /// [`give_witness`](Self::give_witness). Rejects with
+    /// `Web4Error::Unauthorized` without touching the tensors otherwise.
+    pub fn give_witness_with_capability(
+        &mut self,
+        target_id: &str,
+        success: bool,
+        magnitude: f64,
+        chain: &[WitnessCapability],
+    ) -> Web4Result<()> {
+        WitnessCapability::validate_chain(chain, &self.entity_id, target_id, magnitude)?;
+        self.give_witness(target_id, success, magnitude);
+        Ok(())
+    }
+
+    /// Give a witness event and sign it as a [`WitnessCredential`].
+    ///
+    /// Updates local state exactly like [`give_witness`](Self::give_witness),
+    /// then issues a verifiable credential so `target_id` (or any relying
+    /// party) can independently confirm this witnessing happened without
+    /// trusting the raw `witnessed_by`/`has_witnessed` lists.
+    pub fn give_witness_signed(
+        &mut self,
+        target_id: &str,
+        success: bool,
+        magnitude: f64,
+        keypair: &KeyPair,
+    ) -> WitnessCredential {
+        self.give_witness(target_id, success, magnitude);
+        WitnessCredential::issue(&self.entity_id, target_id, success, magnitude, keypair)
+    }
+
+    /// Verify a [`WitnessCredential`] against the witness's public key and,
+    /// only if the proof checks out, apply it via
+    /// [`receive_witness`](Self::receive_witness).
+    ///
+    /// Rejects credentials whose subject doesn't match this entity as well as
+    /// ones with an invalid signature, so a forged or misdirected credential
+    /// can never mutate the tensors.
+    pub fn receive_witness_verified(
+        &mut self,
+        cred: &WitnessCredential,
+        witness_pubkey: &PublicKey,
+    ) -> Result<()> {
+        if cred.credential_subject.id != self.entity_id {
+            return Err(Error::InvalidWitnessSignature(format!(
+                "credential subject {} does not match entity {}",
+                cred.credential_subject.id, self.entity_id
+            )));
+        }
+        cred.verify(witness_pubkey)?;
+
+        self.receive_witness(
+            &cred.issuer,
+            cred.credential_subject.success,
+            cred.credential_subject.magnitude,
+        );
+        Ok(())
+    }
+
+    /// Start round 1 of a mutual-witnessing handshake: describe an
+    /// interaction with `counterparty_id` and sign it, producing a
+    /// [`WitnessSlate`] to hand to the counterparty for round 2.
+    ///
+    /// This only produces the slate — it does not call
+    /// [`give_witness`](Self::give_witness) itself. The T3/V3 update happens
+    /// later, on both sides, via [`WitnessSlate::apply_to`] once the slate
+    /// carries the counterparty's signature too.
+    pub fn new_round1(
+        &self,
+        counterparty_id: &str,
+        success: bool,
+        magnitude: f64,
+        keypair: &KeyPair,
+    ) -> WitnessSlate {
+        WitnessSlate::create(&self.entity_id, counterparty_id, success, magnitude, keypair)
+    }
+
     /// Calculate days since last action
     pub fn days_since_last_action(&self) -> f64 {
         let reference_time = self.last_action.unwrap_or(self.created_at);
@@ -340,6 +539,34 @@ impl EntityTrust {
         t3_decayed
     }
 
+    /// Apply temporal decay and record the resulting tensor averages
+    /// through `meter`.
+    #[cfg(feature = "otel")]
+    pub fn apply_decay_instrumented(
+        &mut self,
+        days_inactive: f64,
+        decay_rate: f64,
+        meter: &TrustMeter,
+    ) -> bool {
+        let decayed = self.apply_decay(days_inactive, decay_rate);
+        meter.record_tensors(&self.entity_type, self.t3_average(), self.v3_average());
+        decayed
+    }
+
+    /// Apply temporal decay using a pluggable [`DecayStrategy`] and `DecayConfig`.
+    ///
+    /// Returns true if meaningful decay occurred on the T3 tensor.
+    pub fn apply_decay_with<S: DecayStrategy>(
+        &mut self,
+        days_inactive: f64,
+        config: &DecayConfig,
+        strategy: &S,
+    ) -> bool {
+        let t3_decayed = self.t3.apply_decay_with(days_inactive, config, strategy);
+        self.v3.apply_decay_with(days_inactive, config, strategy);
+        t3_decayed
+    }
+
     /// Get success rate (0.0 - 1.0)
     pub fn success_rate(&self) -> f64 {
         if self.action_count == 0 {
@@ -394,6 +621,126 @@ mod tests {
         assert!(trust.t3.temperament > 0.5);
     }
 
+    #[test]
+    fn test_give_witness_with_capability_applies_update_when_authorized() {
+        let org = KeyPair::generate();
+        let agent = KeyPair::generate();
+        let cap = WitnessCapability::issue(
+            "org:acme",
+            &org,
+            "session:agent-1",
+            agent.verifying_key(),
+            "mcp:",
+            Some(0.5),
+            Utc::now() + chrono::Duration::hours(1),
+        );
+
+        let mut witness = EntityTrust::new("session:agent-1");
+        witness
+            .give_witness_with_capability("mcp:filesystem", true, 0.2, &[cap])
+            .unwrap();
+
+        assert!(witness.has_witnessed.contains(&"mcp:filesystem".to_string()));
+        assert!(witness.t3.temperament > 0.5);
+    }
+
+    #[test]
+    fn test_give_witness_with_capability_rejects_out_of_scope_target() {
+        let org = KeyPair::generate();
+        let agent = KeyPair::generate();
+        let cap = WitnessCapability::issue(
+            "org:acme",
+            &org,
+            "session:agent-1",
+            agent.verifying_key(),
+            "mcp:",
+            None,
+            Utc::now() + chrono::Duration::hours(1),
+        );
+
+        let mut witness = EntityTrust::new("session:agent-1");
+        let err = witness
+            .give_witness_with_capability("session:other", true, 0.1, &[cap])
+            .unwrap_err();
+
+        assert!(matches!(err, Web4Error::Unauthorized(_)));
+        // Rejected capability must not mutate any state.
+        assert!(witness.has_witnessed.is_empty());
+    }
+
+    #[test]
+    fn test_give_witness_signed_and_receive_verified() {
+        let keypair = KeyPair::generate();
+        let mut witness = EntityTrust::new("session:abc");
+        let mut subject = EntityTrust::new("mcp:test");
+
+        let cred = witness.give_witness_signed("mcp:test", true, 0.1, &keypair);
+        assert!(witness.has_witnessed.contains(&"mcp:test".to_string()));
+
+        subject
+            .receive_witness_verified(&cred, &keypair.verifying_key())
+            .unwrap();
+        assert_eq!(subject.witness_count, 1);
+        assert!(subject.witnessed_by.contains(&"session:abc".to_string()));
+    }
+
+    #[test]
+    fn test_receive_witness_verified_rejects_bad_signature() {
+        let keypair = KeyPair::generate();
+        let other_keypair = KeyPair::generate();
+        let mut witness = EntityTrust::new("session:abc");
+        let mut subject = EntityTrust::new("mcp:test");
+
+        let cred = witness.give_witness_signed("mcp:test", true, 0.1, &keypair);
+
+        let err = subject
+            .receive_witness_verified(&cred, &other_keypair.verifying_key())
+            .unwrap_err();
+        assert!(matches!(err, Error::InvalidWitnessSignature(_)));
+        // Rejected credential must not mutate the subject's tensors.
+        assert_eq!(subject.witness_count, 0);
+    }
+
+    #[test]
+    fn test_receive_witness_verified_rejects_wrong_subject() {
+        let keypair = KeyPair::generate();
+        let mut witness = EntityTrust::new("session:abc");
+        let mut wrong_subject = EntityTrust::new("mcp:other");
+
+        let cred = witness.give_witness_signed("mcp:test", true, 0.1, &keypair);
+
+        let err = wrong_subject
+            .receive_witness_verified(&cred, &keypair.verifying_key())
+            .unwrap_err();
+        assert!(matches!(err, Error::InvalidWitnessSignature(_)));
+    }
+
+    #[test]
+    fn test_logged_variants_append_to_transparency_log() {
+        let mut log = TransparencyLog::new();
+        let mut trust = EntityTrust::new("mcp:test");
+
+        let (idx0, size0) = trust.update_from_outcome_logged(true, 0.1, &mut log);
+        let (idx1, size1) = trust.receive_witness_logged("session:abc", true, 0.1, &mut log);
+        let (idx2, size2) = trust.give_witness_logged("mcp:other", true, 0.1, &mut log);
+
+        assert_eq!((idx0, size0), (0, 1));
+        assert_eq!((idx1, size1), (1, 2));
+        assert_eq!((idx2, size2), (2, 3));
+        assert_eq!(log.len(), 3);
+
+        // The underlying counters update exactly like the unlogged methods.
+        assert_eq!(trust.action_count, 1);
+        assert_eq!(trust.witness_count, 1);
+        assert!(trust.has_witnessed.contains(&"mcp:other".to_string()));
+
+        // Every leaf the calls produced has a valid audit path under the root.
+        for index in 0..log.len() {
+            assert!(log.inclusion_proof(index).is_some());
+        }
+        assert_ne!(log.root(), TransparencyLog::new().root());
+    }
+
     #[test]
     #[cfg(feature = "file-store")]
     fn test_serialization() {
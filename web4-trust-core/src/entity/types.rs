@@ -5,6 +5,10 @@ use crate::{Error, Result};
 
 /// Types of entities in the Web4 ecosystem
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "scale",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode, scale_info::TypeInfo)
+)]
 #[serde(tag = "type", content = "name")]
 pub enum EntityType {
     /// MCP server (e.g., "mcp:filesystem")
@@ -0,0 +1,205 @@
+//! Witness-graph centrality analysis
+//!
+//! `TrustStore::witness()` already records who witnessed whom, but each
+//! update only ever touches the two parties directly involved — an
+//! attestation from an isolated, rarely-witnessed entity counts exactly as
+//! much as one from an entity every other entity routes through. This module
+//! computes closeness and betweenness centrality over the witness graph
+//! (`has_witnessed` edges) so [`TrustStore::propagate_trust`] can weight
+//! attestations by how structurally load-bearing their source is.
+
+use std::collections::{HashMap, VecDeque};
+
+/// How strongly [`TrustStore::propagate_trust`] blends an entity's
+/// temperament toward the centrality-weighted mean of its witnesses'.
+///
+/// [`TrustStore::propagate_trust`]: crate::storage::TrustStore::propagate_trust
+pub(crate) const TEMPERAMENT_BLEND_RATE: f64 = 0.2;
+
+/// Closeness and betweenness centrality for every node in a graph, each
+/// normalized to `[0.0, 1.0]` across the graph.
+#[derive(Clone, Debug, Default)]
+pub struct Centrality {
+    /// `(reachable node count) / (sum of shortest-path distances)`, BFS-based.
+    pub closeness: HashMap<String, f64>,
+    /// Brandes' betweenness centrality: how often a node sits on a shortest
+    /// path between two others.
+    pub betweenness: HashMap<String, f64>,
+}
+
+impl Centrality {
+    /// A single 0-1 centrality weight for `node`, blending closeness and
+    /// betweenness equally. Unknown nodes weight `0.0`.
+    pub fn weight(&self, node: &str) -> f64 {
+        let closeness = self.closeness.get(node).copied().unwrap_or(0.0);
+        let betweenness = self.betweenness.get(node).copied().unwrap_or(0.0);
+        0.5 * closeness + 0.5 * betweenness
+    }
+}
+
+/// Compute closeness and betweenness centrality over a directed graph.
+///
+/// `nodes` is the full vertex set; `edges[v]` lists the nodes `v` points to
+/// (in witness-graph terms, `v`'s `has_witnessed`). Self-loops (`v` appearing
+/// in its own edge list) are ignored. Nodes unreachable from a given source
+/// contribute neither to that source's closeness denominator nor to any
+/// betweenness path; a graph with no edges at all yields all-zero scores.
+pub fn compute_centrality(nodes: &[String], edges: &HashMap<String, Vec<String>>) -> Centrality {
+    let mut closeness = HashMap::new();
+    let mut betweenness: HashMap<String, f64> = nodes.iter().map(|n| (n.clone(), 0.0)).collect();
+
+    for s in nodes {
+        // Single-source BFS: distance, shortest-path counts, predecessors.
+        let mut dist: HashMap<&str, i64> = HashMap::new();
+        let mut sigma: HashMap<&str, f64> = HashMap::new();
+        let mut preds: HashMap<&str, Vec<&str>> = HashMap::new();
+        let mut order: Vec<&str> = Vec::new();
+
+        dist.insert(s.as_str(), 0);
+        sigma.insert(s.as_str(), 1.0);
+        let mut queue = VecDeque::new();
+        queue.push_back(s.as_str());
+
+        while let Some(v) = queue.pop_front() {
+            order.push(v);
+            if let Some(neighbors) = edges.get(v) {
+                for w in neighbors {
+                    if w == v {
+                        continue; // exclude self-loops
+                    }
+                    let w = w.as_str();
+                    if !dist.contains_key(w) {
+                        dist.insert(w, dist[v] + 1);
+                        queue.push_back(w);
+                    }
+                    if dist[w] == dist[v] + 1 {
+                        *sigma.entry(w).or_insert(0.0) += sigma[v];
+                        preds.entry(w).or_default().push(v);
+                    }
+                }
+            }
+        }
+
+        // Closeness: reachable nodes (excluding s) / sum of their distances.
+        let reachable = dist.len() as i64 - 1;
+        let total_distance: i64 = dist.values().sum();
+        let c = if total_distance > 0 {
+            reachable as f64 / total_distance as f64
+        } else {
+            0.0
+        };
+        closeness.insert(s.clone(), c);
+
+        // Brandes dependency accumulation, processing nodes in decreasing
+        // distance from `s` (the reverse of BFS discovery order).
+        let mut delta: HashMap<&str, f64> = HashMap::new();
+        while let Some(w) = order.pop() {
+            if w == s.as_str() {
+                continue;
+            }
+            let dw = delta.get(w).copied().unwrap_or(0.0);
+            if let Some(ps) = preds.get(w) {
+                for &v in ps {
+                    let ratio = sigma[v] / sigma[w];
+                    *delta.entry(v).or_insert(0.0) += ratio * (1.0 + dw);
+                }
+            }
+            *betweenness.get_mut(w).expect("w came from `nodes`") += dw;
+        }
+    }
+
+    normalize(&mut closeness);
+    normalize(&mut betweenness);
+
+    Centrality {
+        closeness,
+        betweenness,
+    }
+}
+
+fn normalize(scores: &mut HashMap<String, f64>) {
+    let max = scores.values().copied().fold(0.0_f64, f64::max);
+    if max > 0.0 {
+        for v in scores.values_mut() {
+            *v /= max;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn path_graph() -> (Vec<String>, HashMap<String, Vec<String>>) {
+        let nodes = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        let mut edges = HashMap::new();
+        edges.insert("a".to_string(), vec!["b".to_string()]);
+        edges.insert("b".to_string(), vec!["c".to_string()]);
+        edges.insert("c".to_string(), vec![]);
+        (nodes, edges)
+    }
+
+    #[test]
+    fn test_middle_node_of_path_has_highest_betweenness() {
+        let (nodes, edges) = path_graph();
+        let centrality = compute_centrality(&nodes, &edges);
+
+        assert_eq!(centrality.betweenness["b"], 1.0);
+        assert_eq!(centrality.betweenness["a"], 0.0);
+        assert_eq!(centrality.betweenness["c"], 0.0);
+    }
+
+    #[test]
+    fn test_empty_graph_yields_empty_centrality() {
+        let nodes: Vec<String> = vec![];
+        let edges = HashMap::new();
+        let centrality = compute_centrality(&nodes, &edges);
+
+        assert!(centrality.closeness.is_empty());
+        assert!(centrality.betweenness.is_empty());
+    }
+
+    #[test]
+    fn test_disconnected_node_has_zero_closeness() {
+        let nodes = vec!["a".to_string(), "b".to_string(), "isolated".to_string()];
+        let mut edges = HashMap::new();
+        edges.insert("a".to_string(), vec!["b".to_string()]);
+        let centrality = compute_centrality(&nodes, &edges);
+
+        assert_eq!(centrality.closeness["isolated"], 0.0);
+        assert_eq!(centrality.weight("isolated"), 0.0);
+    }
+
+    #[test]
+    fn test_self_loop_is_ignored() {
+        let nodes = vec!["a".to_string(), "b".to_string()];
+        let mut edges = HashMap::new();
+        edges.insert("a".to_string(), vec!["a".to_string(), "b".to_string()]);
+        let centrality = compute_centrality(&nodes, &edges);
+
+        // If the self-loop weren't ignored, `a` would gain a spurious
+        // zero-length path to itself that doesn't affect this assertion,
+        // but would break distance bookkeeping for larger graphs; check it
+        // doesn't crash and `b` is still reachable at distance 1.
+        assert_eq!(centrality.closeness["a"], 1.0);
+    }
+
+    #[test]
+    fn test_star_graph_center_has_highest_weight() {
+        let nodes = vec![
+            "center".to_string(),
+            "leaf1".to_string(),
+            "leaf2".to_string(),
+            "leaf3".to_string(),
+        ];
+        let mut edges = HashMap::new();
+        edges.insert(
+            "center".to_string(),
+            vec!["leaf1".to_string(), "leaf2".to_string(), "leaf3".to_string()],
+        );
+        let centrality = compute_centrality(&nodes, &edges);
+
+        assert_eq!(centrality.weight("center"), 0.5);
+        assert_eq!(centrality.weight("leaf1"), 0.0);
+    }
+}
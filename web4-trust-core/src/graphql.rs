@@ -0,0 +1,213 @@
+//! GraphQL query surface over a [`TrustStore`] (behind the `graphql`
+//! feature).
+//!
+//! Dashboards and agents that want to explore trust data interactively
+//! shouldn't have to parse the backend's raw JSON files. [`TrustSchemaQuery`]
+//! exposes a read-only [`async_graphql`] schema over any `TrustStore`: the
+//! top-level `entities` query supports filtering by `entity_type` and
+//! Relay-style forward pagination (`first`/`after`) over
+//! [`TrustStore::list`]; the witnessing relationships on each
+//! [`TrustEntity`] (`witnessedBy`/`hasWitnessed`) resolve into further
+//! `TrustEntity` nodes so a client can traverse the witness graph in one
+//! request instead of one round trip per edge.
+//!
+//! Cursors are the opaque base64 of the entity ID, so pagination is stable
+//! as long as IDs aren't reused — there's no dependence on `list`'s
+//! ordering being stable across calls beyond that.
+
+use std::sync::Arc;
+
+use async_graphql::connection::{Connection, Edge, EmptyFields};
+use async_graphql::{Context, Enum, Object, SimpleObject};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
+
+use crate::entity::{EntityTrust, EntityType};
+use crate::storage::TrustStore;
+use crate::tensor::TrustLevel;
+
+/// A [`TrustStore`] behind the trait object the schema's [`Context`] data
+/// holds, shared across resolvers.
+pub type DynTrustStore = Arc<dyn TrustStore + Send + Sync>;
+
+/// GraphQL mirror of [`TrustLevel`].
+#[derive(Enum, Clone, Copy, PartialEq, Eq)]
+pub enum GraphQLTrustLevel {
+    High,
+    MediumHigh,
+    Medium,
+    Low,
+    Minimal,
+}
+
+impl From<TrustLevel> for GraphQLTrustLevel {
+    fn from(level: TrustLevel) -> Self {
+        match level {
+            TrustLevel::High => Self::High,
+            TrustLevel::MediumHigh => Self::MediumHigh,
+            TrustLevel::Medium => Self::Medium,
+            TrustLevel::Low => Self::Low,
+            TrustLevel::Minimal => Self::Minimal,
+        }
+    }
+}
+
+/// GraphQL-queryable view of an [`EntityTrust`], including resolvable
+/// witnessing edges.
+#[derive(SimpleObject)]
+#[graphql(complex)]
+pub struct TrustEntity {
+    entity_id: String,
+    entity_type: String,
+    talent: f64,
+    training: f64,
+    temperament: f64,
+    valuation: f64,
+    veracity: f64,
+    validity: f64,
+    action_count: u64,
+    success_count: u64,
+    witness_count: u64,
+    trust_level: GraphQLTrustLevel,
+    #[graphql(skip)]
+    witnessed_by: Vec<String>,
+    #[graphql(skip)]
+    has_witnessed: Vec<String>,
+}
+
+impl From<EntityTrust> for TrustEntity {
+    fn from(trust: EntityTrust) -> Self {
+        Self {
+            entity_id: trust.entity_id,
+            entity_type: trust.entity_type,
+            talent: trust.t3.talent,
+            training: trust.t3.training,
+            temperament: trust.t3.temperament,
+            valuation: trust.v3.valuation,
+            veracity: trust.v3.veracity,
+            validity: trust.v3.validity,
+            action_count: trust.action_count,
+            success_count: trust.success_count,
+            witness_count: trust.witness_count,
+            trust_level: trust.trust_level().into(),
+            witnessed_by: trust.witnessed_by,
+            has_witnessed: trust.has_witnessed,
+        }
+    }
+}
+
+#[async_graphql::ComplexObject]
+impl TrustEntity {
+    /// Entities that have witnessed this one, resolved into full nodes.
+    async fn witnessed_by(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<TrustEntity>> {
+        resolve_peers(ctx, &self.witnessed_by)
+    }
+
+    /// Entities this one has witnessed, resolved into full nodes.
+    async fn has_witnessed(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<TrustEntity>> {
+        resolve_peers(ctx, &self.has_witnessed)
+    }
+}
+
+fn resolve_peers(
+    ctx: &Context<'_>,
+    ids: &[String],
+) -> async_graphql::Result<Vec<TrustEntity>> {
+    let store = ctx.data::<DynTrustStore>()?;
+    let mut peers = Vec::with_capacity(ids.len());
+    for id in ids {
+        if let Some(trust) = store
+            .get_existing(id)
+            .map_err(|e| async_graphql::Error::new(e.to_string()))?
+        {
+            peers.push(trust.into());
+        }
+    }
+    Ok(peers)
+}
+
+fn encode_cursor(entity_id: &str) -> String {
+    BASE64.encode(entity_id.as_bytes())
+}
+
+fn decode_cursor(cursor: &str) -> async_graphql::Result<String> {
+    let bytes = BASE64
+        .decode(cursor)
+        .map_err(|e| async_graphql::Error::new(format!("invalid cursor: {e}")))?;
+    String::from_utf8(bytes).map_err(|e| async_graphql::Error::new(format!("invalid cursor: {e}")))
+}
+
+/// Root query type. Pass a [`DynTrustStore`] as schema `Context` data when
+/// building the schema.
+pub struct TrustSchemaQuery;
+
+#[Object]
+impl TrustSchemaQuery {
+    /// A single entity, by ID.
+    async fn entity(
+        &self,
+        ctx: &Context<'_>,
+        entity_id: String,
+    ) -> async_graphql::Result<Option<TrustEntity>> {
+        let store = ctx.data::<DynTrustStore>()?;
+        Ok(store
+            .get_existing(&entity_id)
+            .map_err(|e| async_graphql::Error::new(e.to_string()))?
+            .map(TrustEntity::from))
+    }
+
+    /// All entities, optionally filtered by `entity_type` (e.g. `"mcp"`,
+    /// `"role"`), with Relay-style forward pagination.
+    async fn entities(
+        &self,
+        ctx: &Context<'_>,
+        entity_type: Option<String>,
+        first: Option<i32>,
+        after: Option<String>,
+    ) -> async_graphql::Result<Connection<String, TrustEntity, EmptyFields, EmptyFields>> {
+        let store = ctx.data::<DynTrustStore>()?;
+        let etype = entity_type
+            .map(|t| EntityType::from_entity_id(&format!("{t}:_")))
+            .transpose()
+            .map_err(|e| async_graphql::Error::new(e.to_string()))?;
+
+        let mut ids = store
+            .list(etype.as_ref())
+            .map_err(|e| async_graphql::Error::new(e.to_string()))?;
+        ids.sort();
+
+        let start = match after {
+            Some(cursor) => {
+                let after_id = decode_cursor(&cursor)?;
+                ids.iter().position(|id| *id == after_id).map(|i| i + 1).unwrap_or(ids.len())
+            }
+            None => 0,
+        };
+        let page_size = first.unwrap_or(ids.len() as i32).max(0) as usize;
+        let end = (start + page_size).min(ids.len());
+        let page = &ids[start..end];
+
+        let mut connection = Connection::new(start > 0, end < ids.len());
+        connection.edges.extend(
+            page.iter()
+                .filter_map(|id| store.get_existing(id).ok().flatten())
+                .map(|trust| Edge::new(encode_cursor(&trust.entity_id), TrustEntity::from(trust))),
+        );
+        Ok(connection)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cursor_round_trips_entity_id() {
+        let cursor = encode_cursor("mcp:filesystem");
+        assert_eq!(decode_cursor(&cursor).unwrap(), "mcp:filesystem");
+    }
+
+    #[test]
+    fn test_decode_cursor_rejects_garbage() {
+        assert!(decode_cursor("not valid base64!!").is_err());
+    }
+}
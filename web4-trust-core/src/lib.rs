@@ -35,17 +35,40 @@
 pub mod tensor;
 pub mod entity;
 pub mod witnessing;
+pub mod credential;
 pub mod decay;
+pub mod graph;
 pub mod storage;
+pub mod transparency;
+
+#[cfg(feature = "scale")]
+pub mod scale;
+
+#[cfg(feature = "arrow")]
+pub mod arrow;
+
+#[cfg(feature = "otel")]
+pub mod otel;
+
+#[cfg(feature = "graphql")]
+pub mod graphql;
+
+#[cfg(feature = "prov")]
+pub mod prov;
 
 #[cfg(any(feature = "python", feature = "wasm"))]
 pub mod bindings;
 
 // Re-exports for convenience
-pub use tensor::{T3Tensor, V3Tensor, TrustLevel};
+pub use tensor::{RootDimension, SubScore, T3Graph, T3Tensor, V3Tensor, TrustLevel};
 pub use entity::{EntityTrust, EntityType};
-pub use witnessing::{WitnessEvent, WitnessingChain};
+pub use witnessing::{
+    ChainBuilder, SlateRole, WitnessCapability, WitnessCredential, WitnessEvent, WitnessSlate,
+    WitnessingChain,
+};
+pub use credential::{TrustCredential, TrustCredentialProof, TrustCredentialSubject};
 pub use storage::TrustStore;
+pub use transparency::{TransparencyLog, TrustEvent};
 
 /// Crate-level error type
 #[derive(Debug, thiserror::Error)]
@@ -62,6 +85,15 @@ pub enum Error {
     #[error("Invalid entity ID format: {0}")]
     InvalidEntityId(String),
 
+    #[error("Witness credential signature verification failed: {0}")]
+    InvalidWitnessSignature(String),
+
+    #[error("Witness slate is incomplete or invalid: {0}")]
+    InvalidWitnessSlate(String),
+
+    #[error("Trust credential invalid: {0}")]
+    InvalidTrustCredential(String),
+
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
 }
@@ -0,0 +1,147 @@
+//! OpenTelemetry instrumentation for trust-state transitions (behind the
+//! `otel` feature).
+//!
+//! Plain [`EntityTrust`](crate::EntityTrust) updates happen silently — an
+//! operator watching a running Web4 service has no way to see how trust is
+//! evolving short of diffing stored snapshots. [`TrustMeter`] wraps an
+//! [`opentelemetry::metrics::Meter`] with the instruments this crate cares
+//! about and records through it; callers install one meter (from whatever
+//! exporter their service already runs) and pass it to the `_instrumented`
+//! sibling of each mutating method.
+//!
+//! The instrumentation here depends only on the `opentelemetry` API crate,
+//! not a specific exporter — wiring up OTLP, Prometheus, stdout, or anything
+//! else is the host application's job. The one exporter the crate sets up
+//! itself is the OTLP metrics pipeline behind the Python bindings'
+//! `init_otel`.
+//!
+//! [`crate::storage::InstrumentedStore`] wraps a whole
+//! [`TrustStore`](crate::TrustStore) backend with this same [`TrustMeter`]
+//! plus `tracing` spans, for operators who want store-level visibility
+//! (every `get`/`save`/`update`/`witness`/`list`/`delete` call) rather than
+//! wiring the `_instrumented` methods below into their own call sites.
+
+use opentelemetry::metrics::{Counter, Histogram, Meter};
+use opentelemetry::KeyValue;
+
+/// Instruments recording trust-state transitions, keyed by `entity_type`.
+///
+/// There is no OTel "gauge" API for synchronous recording at a call site
+/// (gauges are observable/async-callback based), so `success_rate` and
+/// `days_since_last_action` — point-in-time values rather than
+/// increments — are recorded as single-sample histograms instead; an
+/// exporter can still chart their latest value per entity type.
+pub struct TrustMeter {
+    actions_total: Counter<u64>,
+    successes_total: Counter<u64>,
+    witness_events_total: Counter<u64>,
+    magnitude: Histogram<f64>,
+    t3_average: Histogram<f64>,
+    v3_average: Histogram<f64>,
+    success_rate: Histogram<f64>,
+    days_since_last_action: Histogram<f64>,
+    /// `update`/`witness` call counts at the [`TrustStore`](crate::TrustStore)
+    /// level, labelled `success`/`failure` — distinct from `actions_total`/
+    /// `witness_events_total` above, which count `EntityTrust`'s own
+    /// transition methods regardless of which store (if any) called them.
+    store_updates: Counter<u64>,
+    store_witness_events: Counter<u64>,
+}
+
+impl TrustMeter {
+    /// Build the instrument set on top of a caller-supplied [`Meter`].
+    pub fn new(meter: &Meter) -> Self {
+        Self {
+            actions_total: meter.u64_counter("web4.trust.actions_total").build(),
+            successes_total: meter.u64_counter("web4.trust.successes_total").build(),
+            witness_events_total: meter.u64_counter("web4.trust.witness_events_total").build(),
+            magnitude: meter.f64_histogram("web4.trust.magnitude").build(),
+            t3_average: meter.f64_histogram("web4.trust.t3_average").build(),
+            v3_average: meter.f64_histogram("web4.trust.v3_average").build(),
+            success_rate: meter.f64_histogram("web4.trust.success_rate").build(),
+            days_since_last_action: meter
+                .f64_histogram("web4.trust.days_since_last_action")
+                .build(),
+            store_updates: meter.u64_counter("web4.trust.updates").build(),
+            store_witness_events: meter.u64_counter("web4.trust.witness_events").build(),
+        }
+    }
+
+    /// Record an `update_from_outcome` transition.
+    pub(crate) fn record_outcome(&self, entity_type: &str, success: bool, magnitude: f64) {
+        let attrs = [KeyValue::new("entity_type", entity_type.to_string())];
+        self.actions_total.add(1, &attrs);
+        if success {
+            self.successes_total.add(1, &attrs);
+        }
+        self.magnitude.record(magnitude, &attrs);
+    }
+
+    /// Record a `give_witness`/`receive_witness` transition.
+    pub(crate) fn record_witness(&self, entity_type: &str, magnitude: f64) {
+        let attrs = [KeyValue::new("entity_type", entity_type.to_string())];
+        self.witness_events_total.add(1, &attrs);
+        self.magnitude.record(magnitude, &attrs);
+    }
+
+    /// Record the tensor averages resulting from any update.
+    pub(crate) fn record_tensors(&self, entity_type: &str, t3_average: f64, v3_average: f64) {
+        let attrs = [KeyValue::new("entity_type", entity_type.to_string())];
+        self.t3_average.record(t3_average, &attrs);
+        self.v3_average.record(v3_average, &attrs);
+    }
+
+    /// Record a point-in-time snapshot of derived entity stats.
+    pub(crate) fn record_snapshot(
+        &self,
+        entity_type: &str,
+        success_rate: f64,
+        days_since_last_action: f64,
+    ) {
+        let attrs = [KeyValue::new("entity_type", entity_type.to_string())];
+        self.success_rate.record(success_rate, &attrs);
+        self.days_since_last_action
+            .record(days_since_last_action, &attrs);
+    }
+
+    /// Record a [`TrustStore::update`](crate::TrustStore::update) call:
+    /// increments `web4.trust.updates` labelled `success`, and — on success
+    /// — records the resulting tensor average to `web4.trust.t3_average`.
+    pub(crate) fn record_store_update(
+        &self,
+        entity_type: &str,
+        success: bool,
+        t3_average: Option<f64>,
+    ) {
+        let attrs = [
+            KeyValue::new("entity_type", entity_type.to_string()),
+            KeyValue::new("success", success),
+        ];
+        self.store_updates.add(1, &attrs);
+        if let Some(t3_average) = t3_average {
+            self.t3_average
+                .record(t3_average, &[KeyValue::new("entity_type", entity_type.to_string())]);
+        }
+    }
+
+    /// Record a [`TrustStore::witness`](crate::TrustStore::witness) call:
+    /// increments `web4.trust.witness_events` labelled `success`, and — on
+    /// success — records the target's resulting tensor average to
+    /// `web4.trust.t3_average`.
+    pub(crate) fn record_store_witness(
+        &self,
+        entity_type: &str,
+        success: bool,
+        t3_average: Option<f64>,
+    ) {
+        let attrs = [
+            KeyValue::new("entity_type", entity_type.to_string()),
+            KeyValue::new("success", success),
+        ];
+        self.store_witness_events.add(1, &attrs);
+        if let Some(t3_average) = t3_average {
+            self.t3_average
+                .record(t3_average, &[KeyValue::new("entity_type", entity_type.to_string())]);
+        }
+    }
+}
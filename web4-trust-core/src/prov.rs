@@ -0,0 +1,224 @@
+//! W3C PROV export of witnessing chains (behind the `prov` feature).
+//!
+//! Witnessing is fundamentally a provenance relation — one entity vouching
+//! for another's action — and this crate already grounds T3/V3 in an RDF
+//! ontology under the `web4:` prefix, so it's a natural fit for
+//! [PROV-O](https://www.w3.org/TR/prov-o/): each [`EntityTrust`] becomes a
+//! `prov:Agent`, and each witnessing [`HistoryEntry`] (a
+//! [`TrustCause::WitnessReceived`]) becomes a `prov:Activity` with
+//! `prov:startedAtTime` set from the entry's timestamp and
+//! `prov:wasAssociatedWith` edges to both the witnessing agent and the
+//! witnessed agent. The event's `success`/`magnitude` are attached as typed
+//! literals under the `web4:` namespace, since PROV-O has no native concept
+//! of either.
+//!
+//! This draws on [`storage::HistoryEntry`](crate::storage::HistoryEntry)
+//! rather than [`witnessing::WitnessEvent`](crate::witnessing::WitnessEvent):
+//! `WitnessEvent` is the in-memory shape a caller builds to *construct* a
+//! [`WitnessSlate`](crate::witnessing::WitnessSlate), not something a
+//! `TrustStore` persists, whereas every witnessing `HistoryEntry` a
+//! recording backend keeps already carries the same witness/target/
+//! success/magnitude/timestamp fields and is actually queryable store-wide.
+//!
+//! An entity only ever appears once as a `WitnessReceived` history entry's
+//! target even though `TrustStore::witness` also records the mirror
+//! `WitnessGiven` entry in the witness's own history — using only
+//! `WitnessReceived` entries avoids emitting each event twice.
+//!
+//! Key invariant: every agent IRI referenced by a witnessing edge is
+//! emitted as a `prov:Agent` node, even if that entity has since been
+//! deleted and has no current [`EntityTrust`] record, so dangling witness
+//! references stay resolvable.
+//!
+//! This module assumes the `web4:` prefix resolves to
+//! `https://web4.foundation/ontology#` — adjust [`WEB4_NS`] if this crate
+//! is vendored alongside a `web4-standard` checkout with a different base
+//! IRI.
+
+use std::collections::BTreeSet;
+
+use crate::storage::{HistoryEntry, TrustCause, TrustStore};
+use crate::Result;
+
+/// Base IRI for the `web4:` namespace. See the module docs.
+pub const WEB4_NS: &str = "https://web4.foundation/ontology#";
+
+/// Base IRI under which entity IDs are minted as agent IRIs.
+pub const WEB4_ENTITY_NS: &str = "https://web4.foundation/entity/";
+
+/// Base IRI under which witness events are minted as activity IRIs.
+pub const WEB4_ACTIVITY_NS: &str = "https://web4.foundation/activity/";
+
+/// One witnessing edge extracted from an entity's `history`, ready to
+/// render as a PROV activity.
+struct WitnessProvenance {
+    target_id: String,
+    witness_id: String,
+    success: bool,
+    magnitude: f64,
+    timestamp: chrono::DateTime<chrono::Utc>,
+}
+
+fn agent_iri(entity_id: &str) -> String {
+    format!("{WEB4_ENTITY_NS}{entity_id}")
+}
+
+fn activity_iri(witness: &WitnessProvenance, index: usize) -> String {
+    format!(
+        "{WEB4_ACTIVITY_NS}{}-{}-{}-{index}",
+        witness.witness_id,
+        witness.target_id,
+        witness.timestamp.timestamp_millis()
+    )
+}
+
+fn turtle_escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Walk every known entity's `history` and collect the witnessing edges
+/// (`WitnessReceived` entries only — see the module docs), plus the full
+/// set of agent IDs referenced (including entities with no current
+/// record).
+fn collect_witness_provenance(
+    store: &(impl TrustStore + ?Sized),
+) -> Result<(Vec<WitnessProvenance>, BTreeSet<String>)> {
+    let ids = store.list(None)?;
+    let mut agent_ids: BTreeSet<String> = ids.iter().cloned().collect();
+    let mut events = Vec::new();
+
+    for id in &ids {
+        let history: Vec<HistoryEntry> = store.history(id)?;
+        for entry in history {
+            if let TrustCause::WitnessReceived {
+                witness_id,
+                success,
+                magnitude,
+            } = entry.cause
+            {
+                agent_ids.insert(witness_id.clone());
+                agent_ids.insert(entry.entity_id.clone());
+                events.push(WitnessProvenance {
+                    target_id: entry.entity_id,
+                    witness_id,
+                    success,
+                    magnitude,
+                    timestamp: entry.timestamp,
+                });
+            }
+        }
+    }
+
+    Ok((events, agent_ids))
+}
+
+/// Serialize `store`'s entities and witnessing history as PROV-O Turtle.
+pub fn to_prov_turtle(store: &(impl TrustStore + ?Sized)) -> Result<String> {
+    let (events, agent_ids) = collect_witness_provenance(store)?;
+
+    let mut out = String::new();
+    out.push_str("@prefix prov: <http://www.w3.org/ns/prov#> .\n");
+    out.push_str("@prefix xsd: <http://www.w3.org/2001/XMLSchema#> .\n");
+    out.push_str(&format!("@prefix web4: <{WEB4_NS}> .\n\n"));
+
+    for id in &agent_ids {
+        out.push_str(&format!(
+            "<{}> a prov:Agent ;\n    web4:entityId \"{}\" .\n\n",
+            agent_iri(id),
+            turtle_escape(id)
+        ));
+    }
+
+    for (index, event) in events.iter().enumerate() {
+        out.push_str(&format!(
+            "<{activity}> a prov:Activity ;\n    prov:startedAtTime \"{time}\"^^xsd:dateTime ;\n    prov:wasAssociatedWith <{witness}>, <{target}> ;\n    web4:witnessId <{witness}> ;\n    web4:targetId <{target}> ;\n    web4:success \"{success}\"^^xsd:boolean ;\n    web4:magnitude \"{magnitude}\"^^xsd:double .\n\n",
+            activity = activity_iri(event, index),
+            time = event.timestamp.to_rfc3339(),
+            witness = agent_iri(&event.witness_id),
+            target = agent_iri(&event.target_id),
+            success = event.success,
+            magnitude = event.magnitude,
+        ));
+    }
+
+    Ok(out)
+}
+
+/// Serialize `store`'s entities and witnessing history as PROV-JSON-LD.
+pub fn to_prov_jsonld(store: &(impl TrustStore + ?Sized)) -> Result<serde_json::Value> {
+    let (events, agent_ids) = collect_witness_provenance(store)?;
+
+    let mut graph: Vec<serde_json::Value> = Vec::new();
+
+    for id in &agent_ids {
+        graph.push(serde_json::json!({
+            "@id": agent_iri(id),
+            "@type": "prov:Agent",
+            "web4:entityId": id,
+        }));
+    }
+
+    for (index, event) in events.iter().enumerate() {
+        graph.push(serde_json::json!({
+            "@id": activity_iri(event, index),
+            "@type": "prov:Activity",
+            "prov:startedAtTime": event.timestamp.to_rfc3339(),
+            "prov:wasAssociatedWith": [
+                { "@id": agent_iri(&event.witness_id) },
+                { "@id": agent_iri(&event.target_id) },
+            ],
+            "web4:witnessId": { "@id": agent_iri(&event.witness_id) },
+            "web4:targetId": { "@id": agent_iri(&event.target_id) },
+            "web4:success": event.success,
+            "web4:magnitude": event.magnitude,
+        }));
+    }
+
+    Ok(serde_json::json!({
+        "@context": {
+            "prov": "http://www.w3.org/ns/prov#",
+            "web4": WEB4_NS,
+        },
+        "@graph": graph,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::InMemoryStore;
+
+    #[test]
+    fn test_to_prov_turtle_includes_agents_and_activity() {
+        let store = InMemoryStore::new();
+        store.witness("session:a", "mcp:b", true, 0.5).unwrap();
+
+        let turtle = to_prov_turtle(&store).unwrap();
+        assert!(turtle.contains("prov:Agent"));
+        assert!(turtle.contains(&agent_iri("session:a")));
+        assert!(turtle.contains(&agent_iri("mcp:b")));
+        assert!(turtle.contains("prov:Activity"));
+        assert!(turtle.contains("web4:success \"true\"^^xsd:boolean"));
+    }
+
+    #[test]
+    fn test_to_prov_turtle_emits_dangling_agent_after_deletion() {
+        let store = InMemoryStore::new();
+        store.witness("session:a", "mcp:b", true, 0.5).unwrap();
+        store.delete("session:a").unwrap();
+
+        let turtle = to_prov_turtle(&store).unwrap();
+        assert!(turtle.contains(&agent_iri("session:a")));
+    }
+
+    #[test]
+    fn test_to_prov_jsonld_has_one_graph_node_per_agent_and_event() {
+        let store = InMemoryStore::new();
+        store.witness("session:a", "mcp:b", true, 0.5).unwrap();
+
+        let jsonld = to_prov_jsonld(&store).unwrap();
+        let graph = jsonld["@graph"].as_array().unwrap();
+        // 2 agents + 1 activity
+        assert_eq!(graph.len(), 3);
+    }
+}
@@ -0,0 +1,150 @@
+//! SCALE codec support for on-chain storage (behind the `scale` feature).
+//!
+//! SCALE (Simple Concatenated Aggregate Little-Endian) is the compact,
+//! non-self-describing binary format used by Substrate. Fixed structs encode
+//! as the concatenation of their fields and enums as a one-byte discriminant
+//! followed by the variant payload. `scale_info::TypeInfo` additionally emits a
+//! registry of type definitions so external tooling can decode the bytes
+//! without the Rust source.
+//!
+//! # Float representation
+//!
+//! The tensor dimensions are `f64` in `[0.0, 1.0]`, but `f64` has no SCALE
+//! encoding and its IEEE-754 bit pattern is not something a pallet wants to
+//! reason about. We therefore encode each dimension as a **fixed-point `u32`
+//! scaled by 1e9** (`value * 1e9`, rounded), which is fully deterministic
+//! across platforms and survives a round-trip to nine decimal places — ample
+//! for a quantity clamped to the unit interval. The `TypeInfo` for these types
+//! documents the fields as `FixedU32` so downstream decoders divide by 1e9.
+
+use parity_scale_codec::{Decode, Encode, Error as CodecError, Input, Output};
+use scale_info::{build::Fields, Path, Type, TypeInfo};
+
+use crate::tensor::{T3Tensor, V3Tensor};
+
+/// Scaling factor applied to unit-interval `f64` dimensions.
+pub(crate) const FIXED_SCALE: f64 = 1_000_000_000.0;
+
+/// Encode a unit-interval `f64` as a fixed-point `u32` (`value * 1e9`).
+pub(crate) fn f64_to_fixed(value: f64) -> u32 {
+    (value.clamp(0.0, 1.0) * FIXED_SCALE).round() as u32
+}
+
+/// Decode a fixed-point `u32` back to an `f64` in `[0.0, 1.0]`.
+pub(crate) fn fixed_to_f64(fixed: u32) -> f64 {
+    (fixed as f64 / FIXED_SCALE).clamp(0.0, 1.0)
+}
+
+impl Encode for T3Tensor {
+    fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+        f64_to_fixed(self.talent).encode_to(dest);
+        f64_to_fixed(self.training).encode_to(dest);
+        f64_to_fixed(self.temperament).encode_to(dest);
+    }
+}
+
+impl Decode for T3Tensor {
+    fn decode<I: Input>(input: &mut I) -> Result<Self, CodecError> {
+        Ok(Self::new(
+            fixed_to_f64(u32::decode(input)?),
+            fixed_to_f64(u32::decode(input)?),
+            fixed_to_f64(u32::decode(input)?),
+        ))
+    }
+}
+
+impl TypeInfo for T3Tensor {
+    type Identity = Self;
+
+    fn type_info() -> Type {
+        Type::builder()
+            .path(Path::new("T3Tensor", "web4_trust_core::tensor"))
+            .docs(&["Three trust dimensions, each a FixedU32 = value * 1e9"])
+            .composite(
+                Fields::named()
+                    .field(|f| f.ty::<u32>().name("talent").type_name("FixedU32"))
+                    .field(|f| f.ty::<u32>().name("training").type_name("FixedU32"))
+                    .field(|f| f.ty::<u32>().name("temperament").type_name("FixedU32")),
+            )
+    }
+}
+
+impl Encode for V3Tensor {
+    fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+        f64_to_fixed(self.valuation).encode_to(dest);
+        f64_to_fixed(self.veracity).encode_to(dest);
+        f64_to_fixed(self.validity).encode_to(dest);
+    }
+}
+
+impl Decode for V3Tensor {
+    fn decode<I: Input>(input: &mut I) -> Result<Self, CodecError> {
+        Ok(Self::new(
+            fixed_to_f64(u32::decode(input)?),
+            fixed_to_f64(u32::decode(input)?),
+            fixed_to_f64(u32::decode(input)?),
+        ))
+    }
+}
+
+impl TypeInfo for V3Tensor {
+    type Identity = Self;
+
+    fn type_info() -> Type {
+        Type::builder()
+            .path(Path::new("V3Tensor", "web4_trust_core::tensor"))
+            .docs(&["Three value dimensions, each a FixedU32 = value * 1e9"])
+            .composite(
+                Fields::named()
+                    .field(|f| f.ty::<u32>().name("valuation").type_name("FixedU32"))
+                    .field(|f| f.ty::<u32>().name("veracity").type_name("FixedU32"))
+                    .field(|f| f.ty::<u32>().name("validity").type_name("FixedU32")),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::EntityType;
+    use scale_info::TypeDef;
+
+    #[test]
+    fn t3_round_trips_through_scale() {
+        let t3 = T3Tensor::new(0.123456789, 0.5, 0.9);
+        let bytes = t3.encode();
+        assert_eq!(bytes.len(), 12); // three u32
+        let decoded = T3Tensor::decode(&mut &bytes[..]).unwrap();
+        assert!((decoded.talent - t3.talent).abs() < 1e-9);
+        assert!((decoded.training - t3.training).abs() < 1e-9);
+        assert!((decoded.temperament - t3.temperament).abs() < 1e-9);
+    }
+
+    #[test]
+    fn v3_round_trips_through_scale() {
+        let v3 = V3Tensor::new(0.25, 0.75, 1.0);
+        let decoded = V3Tensor::decode(&mut &v3.encode()[..]).unwrap();
+        assert_eq!(decoded, v3);
+    }
+
+    #[test]
+    fn entity_type_round_trips_through_scale() {
+        let e = EntityType::Mcp("filesystem".to_string());
+        let decoded = EntityType::decode(&mut &e.encode()[..]).unwrap();
+        assert_eq!(decoded, e);
+    }
+
+    #[test]
+    fn t3_metadata_snapshot() {
+        let ty = <T3Tensor as TypeInfo>::type_info();
+        assert_eq!(ty.path.ident(), Some("T3Tensor"));
+        match &ty.type_def {
+            TypeDef::Composite(c) => {
+                let names: Vec<_> = c.fields.iter().filter_map(|f| f.name).collect();
+                assert_eq!(names, vec!["talent", "training", "temperament"]);
+                assert!(c.fields.iter().all(|f| f.type_name == Some("FixedU32")));
+            }
+            _ => panic!("expected a composite type"),
+        }
+    }
+}
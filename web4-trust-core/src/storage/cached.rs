@@ -0,0 +1,247 @@
+//! In-memory LRU write-through cache wrapping any [`TrustStore`].
+//!
+//! Every backend pays its own read cost on every `get`/`exists` call — a
+//! filesystem read for [`FileStore`](super::FileStore), a B-tree lookup for
+//! [`SledStore`](super::SledStore) — which adds up for entities that are
+//! read far more often than they change (a frequently-called MCP, say).
+//! [`CachedStore`] sits in front of any `TrustStore` backend and serves
+//! repeat `get`/`get_existing`/`exists` calls from a bounded LRU cache,
+//! write-through on `save` and evicting on `delete`, the same pattern
+//! [`SledStore`] already uses internally but generalized to wrap any `S`.
+
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use lru::LruCache;
+
+use crate::entity::{EntityTrust, EntityType};
+use crate::storage::TrustStore;
+use crate::Result;
+
+/// Default number of `EntityTrust` values held in the cache.
+pub const DEFAULT_CACHE_CAPACITY: usize = 1024;
+
+/// Cumulative hit/miss counters for a [`CachedStore`], as returned by
+/// [`CachedStore::stats`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// Lookups served from the cache.
+    pub hits: u64,
+    /// Lookups that missed the cache and fell through to the backend.
+    pub misses: u64,
+}
+
+impl CacheStats {
+    /// Fraction of lookups served from cache, in `[0.0, 1.0]`. `0.0` if there
+    /// have been no lookups yet.
+    pub fn hit_rate(&self) -> f64 {
+        let total = self.hits + self.misses;
+        if total == 0 {
+            0.0
+        } else {
+            self.hits as f64 / total as f64
+        }
+    }
+}
+
+/// Bounded LRU write-through cache wrapping any [`TrustStore`] backend `S`.
+///
+/// `get`/`get_existing`/`exists` are served from cache on a hit; `save`
+/// updates the cache and the backend together; `delete` evicts. `list`,
+/// `history` and `register_observer` fall straight through to `S`. The
+/// trait's other default methods (`update`, `propagate_trust`, ...) run on
+/// top of this wrapper's own `get`/`save`, so what they write also lands in
+/// the cache, and `S`'s own overrides of those methods are not used.
+pub struct CachedStore<S: TrustStore> {
+    inner: S,
+    cache: Mutex<LruCache<String, EntityTrust>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl<S: TrustStore> CachedStore<S> {
+    /// Wrap `inner` with the default cache capacity.
+    pub fn new(inner: S) -> Self {
+        Self::with_capacity(inner, DEFAULT_CACHE_CAPACITY)
+    }
+
+    /// Wrap `inner` with an explicit cache capacity.
+    pub fn with_capacity(inner: S, capacity: usize) -> Self {
+        let capacity = NonZeroUsize::new(capacity.max(1)).unwrap();
+        Self {
+            inner,
+            cache: Mutex::new(LruCache::new(capacity)),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// The wrapped backend.
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    /// Cumulative hit/miss counters, accumulated since construction.
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+        }
+    }
+
+    fn cache_get(&self, entity_id: &str) -> Option<EntityTrust> {
+        let hit = self.cache.lock().unwrap().get(entity_id).cloned();
+        if hit.is_some() {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+        } else {
+            self.misses.fetch_add(1, Ordering::Relaxed);
+        }
+        hit
+    }
+
+    fn cache_put(&self, trust: &EntityTrust) {
+        self.cache
+            .lock()
+            .unwrap()
+            .put(trust.entity_id.clone(), trust.clone());
+    }
+
+    fn cache_invalidate(&self, entity_id: &str) {
+        self.cache.lock().unwrap().pop(entity_id);
+    }
+}
+
+impl<S: TrustStore> TrustStore for CachedStore<S> {
+    fn get(&self, entity_id: &str) -> Result<EntityTrust> {
+        if let Some(trust) = self.cache_get(entity_id) {
+            return Ok(trust);
+        }
+        let trust = self.inner.get(entity_id)?;
+        self.cache_put(&trust);
+        Ok(trust)
+    }
+
+    fn get_existing(&self, entity_id: &str) -> Result<Option<EntityTrust>> {
+        if let Some(trust) = self.cache_get(entity_id) {
+            return Ok(Some(trust));
+        }
+        let trust = self.inner.get_existing(entity_id)?;
+        if let Some(ref t) = trust {
+            self.cache_put(t);
+        }
+        Ok(trust)
+    }
+
+    fn save(&self, trust: &EntityTrust) -> Result<()> {
+        self.inner.save(trust)?;
+        self.cache_put(trust);
+        Ok(())
+    }
+
+    fn delete(&self, entity_id: &str) -> Result<bool> {
+        let existed = self.inner.delete(entity_id)?;
+        self.cache_invalidate(entity_id);
+        Ok(existed)
+    }
+
+    fn list(&self, entity_type: Option<&EntityType>) -> Result<Vec<String>> {
+        self.inner.list(entity_type)
+    }
+
+    fn exists(&self, entity_id: &str) -> Result<bool> {
+        if self.cache.lock().unwrap().contains(entity_id) {
+            return Ok(true);
+        }
+        self.inner.exists(entity_id)
+    }
+
+    fn witness(
+        &self,
+        witness_id: &str,
+        target_id: &str,
+        success: bool,
+        magnitude: f64,
+    ) -> Result<(EntityTrust, EntityTrust)> {
+        let (witness, target) = self.inner.witness(witness_id, target_id, success, magnitude)?;
+        self.cache_put(&witness);
+        self.cache_put(&target);
+        Ok((witness, target))
+    }
+
+    fn history(&self, entity_id: &str) -> Result<Vec<crate::storage::HistoryEntry>> {
+        self.inner.history(entity_id)
+    }
+
+    fn register_observer(&self, pattern: &str, callback: crate::storage::ObserverCallback) {
+        self.inner.register_observer(pattern, callback)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::InMemoryStore;
+
+    #[test]
+    fn test_get_populates_cache_and_counts_miss() {
+        let store = CachedStore::new(InMemoryStore::new());
+        store.get("mcp:a").unwrap();
+
+        let stats = store.stats();
+        assert_eq!(stats.hits, 0);
+        assert_eq!(stats.misses, 1);
+    }
+
+    #[test]
+    fn test_repeated_get_hits_cache() {
+        let store = CachedStore::new(InMemoryStore::new());
+        store.get("mcp:a").unwrap();
+        store.get("mcp:a").unwrap();
+        store.get("mcp:a").unwrap();
+
+        let stats = store.stats();
+        assert_eq!(stats.hits, 2);
+        assert_eq!(stats.misses, 1);
+        assert_eq!(stats.hit_rate(), 2.0 / 3.0);
+    }
+
+    #[test]
+    fn test_save_is_write_through() {
+        let store = CachedStore::new(InMemoryStore::new());
+        let mut trust = EntityTrust::new("mcp:a");
+        trust.update_from_outcome(true, 0.1);
+        store.save(&trust).unwrap();
+
+        // Served from cache...
+        let cached = store.get("mcp:a").unwrap();
+        assert_eq!(cached.action_count, 1);
+        // ...and also landed in the backing store.
+        let backed = store.inner().get("mcp:a").unwrap();
+        assert_eq!(backed.action_count, 1);
+    }
+
+    #[test]
+    fn test_delete_evicts_cache() {
+        let store = CachedStore::new(InMemoryStore::new());
+        store.get("mcp:a").unwrap();
+        store.delete("mcp:a").unwrap();
+
+        // Recreated on next get, a fresh entity with no history.
+        let recreated = store.get("mcp:a").unwrap();
+        assert_eq!(recreated.action_count, 0);
+    }
+
+    #[test]
+    fn test_capacity_evicts_least_recently_used() {
+        let store = CachedStore::with_capacity(InMemoryStore::new(), 1);
+        store.get("mcp:a").unwrap();
+        store.get("mcp:b").unwrap();
+
+        // "mcp:a" was evicted to make room for "mcp:b", so this is a cache miss
+        // that re-fetches from the backend (still present there).
+        let before = store.stats().misses;
+        store.get("mcp:a").unwrap();
+        assert_eq!(store.stats().misses, before + 1);
+    }
+}
@@ -2,14 +2,77 @@
 //!
 //! Compatible with the Python implementation's JSON format.
 //! Each entity is stored in a separate file named by SHA256 hash of entity_id.
+//!
+//! `EntityTrust::witnessed_by`/`has_witnessed` are plain entity-ID strings, so
+//! `save`/`get` here can't themselves detect a forged witnessing entry — there
+//! is no signature to check once an event has been folded into those lists.
+//! Callers who need tamper-evidence should verify before that point, via
+//! [`WitnessEvent::verify`](crate::witnessing::WitnessEvent::verify) or
+//! [`EntityTrust::receive_witness_verified`](crate::entity::EntityTrust::receive_witness_verified),
+//! and only call `save` once the signature has checked out.
+//!
+//! `save`/`delete` write to a temporary file and atomically rename it over
+//! the target, so a crash mid-write can never leave a truncated or
+//! interleaved JSON file behind, and hold a per-entity advisory lock file for
+//! the duration so two processes sharing `base_dir` can't interleave their
+//! writes to the same entity. [`verify_integrity`](FileStore::verify_integrity)
+//! scans for any file that still fails to deserialize (e.g. left behind by an
+//! older version without this protection) so an operator can quarantine it.
 
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 use sha2::{Sha256, Digest};
 
 use crate::{EntityTrust, EntityType, Error, Result};
 use super::TrustStore;
 
+/// How long [`FileStore`] will wait for a per-entity lock before giving up.
+const LOCK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How long to sleep between lock-acquisition retries.
+const LOCK_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Advisory per-entity lock, held for the duration of a `save`/`delete`.
+///
+/// Acquired by atomically creating `{entity_file}.lock` (`create_new` fails
+/// if the file already exists, making creation itself the mutual-exclusion
+/// check) and released by removing it on drop.
+struct EntityLock {
+    path: PathBuf,
+}
+
+impl EntityLock {
+    fn acquire(path: PathBuf) -> Result<Self> {
+        let deadline = Instant::now() + LOCK_TIMEOUT;
+        loop {
+            match fs::OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(&path)
+            {
+                Ok(_) => return Ok(Self { path }),
+                Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {
+                    if Instant::now() >= deadline {
+                        return Err(Error::Storage(format!(
+                            "timed out waiting for lock file {}",
+                            path.display()
+                        )));
+                    }
+                    std::thread::sleep(LOCK_POLL_INTERVAL);
+                }
+                Err(e) => return Err(Error::Io(e)),
+            }
+        }
+    }
+}
+
+impl Drop for EntityLock {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
 /// File-based trust store using JSON files
 ///
 /// Compatible with the Python implementation.
@@ -49,6 +112,33 @@ impl FileStore {
     pub fn base_dir(&self) -> &Path {
         &self.base_dir
     }
+
+    /// Scan `base_dir` and return the path of every `.json` file that fails
+    /// to deserialize as an [`EntityTrust`], so an operator can detect and
+    /// quarantine damage (e.g. left by an older version writing without the
+    /// atomic rename / lock this store now uses).
+    ///
+    /// Does not modify or remove anything itself.
+    pub fn verify_integrity(&self) -> Result<Vec<PathBuf>> {
+        let mut corrupted = Vec::new();
+
+        for entry in fs::read_dir(&self.base_dir)? {
+            let entry = entry?;
+            let path = entry.path();
+
+            if path.extension().map_or(false, |ext| ext == "json") {
+                let ok = fs::read_to_string(&path)
+                    .ok()
+                    .and_then(|content| serde_json::from_str::<EntityTrust>(&content).ok())
+                    .is_some();
+                if !ok {
+                    corrupted.push(path);
+                }
+            }
+        }
+
+        Ok(corrupted)
+    }
 }
 
 impl TrustStore for FileStore {
@@ -83,14 +173,24 @@ impl TrustStore for FileStore {
 
     fn save(&self, trust: &EntityTrust) -> Result<()> {
         let file_path = self.entity_file(&trust.entity_id);
+        let lock_path = file_path.with_extension("json.lock");
+        let _lock = EntityLock::acquire(lock_path)?;
+
         let content = serde_json::to_string_pretty(trust)
             .map_err(|e| Error::Serialization(e.to_string()))?;
-        fs::write(&file_path, content)?;
+
+        // Write to a temp file and rename over the target: a crash mid-write
+        // leaves only the stray temp file, never a truncated entity file.
+        let tmp_path = file_path.with_extension("json.tmp");
+        fs::write(&tmp_path, content)?;
+        fs::rename(&tmp_path, &file_path)?;
         Ok(())
     }
 
     fn delete(&self, entity_id: &str) -> Result<bool> {
         let file_path = self.entity_file(entity_id);
+        let lock_path = file_path.with_extension("json.lock");
+        let _lock = EntityLock::acquire(lock_path)?;
 
         if file_path.exists() {
             fs::remove_file(&file_path)?;
@@ -234,4 +334,38 @@ mod tests {
         assert!(content.contains("\"valuation\""));
         assert!(content.contains("\"witnessed_by\""));
     }
+
+    #[test]
+    fn test_save_leaves_no_stray_tmp_file() {
+        let (store, _temp) = temp_store();
+        let trust = EntityTrust::new("mcp:test");
+        store.save(&trust).unwrap();
+
+        let file_path = store.entity_file("mcp:test");
+        assert!(file_path.exists());
+        assert!(!file_path.with_extension("json.tmp").exists());
+        assert!(!file_path.with_extension("json.lock").exists());
+    }
+
+    #[test]
+    fn test_verify_integrity_reports_corrupted_file() {
+        let (store, _temp) = temp_store();
+        store.get("mcp:a").unwrap();
+        store.get("mcp:b").unwrap();
+
+        let corrupted_path = store.entity_file("mcp:b");
+        fs::write(&corrupted_path, "{ not valid json").unwrap();
+
+        let report = store.verify_integrity().unwrap();
+        assert_eq!(report, vec![corrupted_path]);
+    }
+
+    #[test]
+    fn test_verify_integrity_clean_store_reports_nothing() {
+        let (store, _temp) = temp_store();
+        store.get("mcp:a").unwrap();
+        store.get("mcp:b").unwrap();
+
+        assert!(store.verify_integrity().unwrap().is_empty());
+    }
 }
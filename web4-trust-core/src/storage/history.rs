@@ -0,0 +1,51 @@
+//! Append-only per-entity history, enabling time-travel queries
+//!
+//! `InMemoryStore` (and most other [`TrustStore`](super::TrustStore)
+//! backends) only keep an entity's latest [`EntityTrust`](crate::EntityTrust)
+//! — once `update`/`witness` overwrite it, the path that got there is gone.
+//! A [`HistoryEntry`] is an immutable record of one T3 snapshot and why it
+//! was taken; a backend that records them lets callers reconstruct the
+//! tensor as of any past instant via [`TrustStore::as_of`](super::TrustStore::as_of),
+//! and derive inactivity for decay automatically instead of requiring the
+//! caller to track it out of band.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::tensor::T3Tensor;
+
+/// Why a [`HistoryEntry`] was recorded.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum TrustCause {
+    /// A direct `TrustStore::save` call, not attributable to a more specific
+    /// cause below.
+    Saved,
+    /// `update_from_outcome` via `TrustStore::update`.
+    Outcome { success: bool, magnitude: f64 },
+    /// This entity witnessed `target_id`, via `TrustStore::witness`.
+    WitnessGiven {
+        target_id: String,
+        success: bool,
+        magnitude: f64,
+    },
+    /// This entity was witnessed by `witness_id`, via `TrustStore::witness`.
+    WitnessReceived {
+        witness_id: String,
+        success: bool,
+        magnitude: f64,
+    },
+    /// Temporal decay was applied.
+    Decay {
+        days_inactive: f64,
+        decay_rate: f64,
+    },
+}
+
+/// One immutable snapshot in an entity's history.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    pub entity_id: String,
+    pub timestamp: DateTime<Utc>,
+    pub t3: T3Tensor,
+    pub cause: TrustCause,
+}
@@ -0,0 +1,124 @@
+//! Instrumented [`TrustStore`] wrapper (behind the `otel` feature).
+//!
+//! A plain [`TrustStore`] backend gives an operator no visibility beyond
+//! whatever the backend itself logs. [`InstrumentedStore`] wraps any
+//! backend and records a `tracing` span around
+//! `get`/`save`/`update`/`witness`/`list`/`delete`, plus [`TrustMeter`]
+//! metrics on `update`/`witness` — the operations an operator actually wants
+//! to watch trust dynamics through. `get_existing`, `exists`,
+//! `propagate_trust`, `history` and `register_observer` fall straight
+//! through to the wrapped backend; the trait's remaining default methods run
+//! on top of the instrumented ones.
+
+use tracing::info_span;
+
+use crate::entity::{EntityTrust, EntityType};
+use crate::otel::TrustMeter;
+use crate::storage::TrustStore;
+use crate::Result;
+
+/// Wraps a [`TrustStore`] backend `S`, instrumenting its entity-identified
+/// operations. See the module docs for exactly which methods get a span.
+pub struct InstrumentedStore<S: TrustStore> {
+    inner: S,
+    meter: TrustMeter,
+}
+
+impl<S: TrustStore> InstrumentedStore<S> {
+    /// Wrap `inner`, recording metrics through `meter`. Construct `meter`
+    /// once per process (it owns the underlying OTel instruments) and share
+    /// it across every `InstrumentedStore` a service creates.
+    pub fn new(inner: S, meter: TrustMeter) -> Self {
+        Self { inner, meter }
+    }
+
+    /// The wrapped backend.
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+}
+
+impl<S: TrustStore> TrustStore for InstrumentedStore<S> {
+    fn get(&self, entity_id: &str) -> Result<EntityTrust> {
+        let _span = info_span!("trust_store.get", entity_id = %entity_id).entered();
+        self.inner.get(entity_id)
+    }
+
+    fn get_existing(&self, entity_id: &str) -> Result<Option<EntityTrust>> {
+        self.inner.get_existing(entity_id)
+    }
+
+    fn save(&self, trust: &EntityTrust) -> Result<()> {
+        let _span = info_span!(
+            "trust_store.save",
+            entity_id = %trust.entity_id,
+            entity_type = %trust.entity_type
+        )
+        .entered();
+        self.inner.save(trust)
+    }
+
+    fn delete(&self, entity_id: &str) -> Result<bool> {
+        let _span = info_span!("trust_store.delete", entity_id = %entity_id).entered();
+        self.inner.delete(entity_id)
+    }
+
+    fn list(&self, entity_type: Option<&EntityType>) -> Result<Vec<String>> {
+        let entity_type_label = entity_type.map(|t| t.to_string()).unwrap_or_default();
+        let _span = info_span!("trust_store.list", entity_type = %entity_type_label).entered();
+        self.inner.list(entity_type)
+    }
+
+    fn exists(&self, entity_id: &str) -> Result<bool> {
+        self.inner.exists(entity_id)
+    }
+
+    fn update(&self, entity_id: &str, success: bool, magnitude: f64) -> Result<EntityTrust> {
+        let _span = info_span!("trust_store.update", entity_id = %entity_id).entered();
+        let result = self.inner.update(entity_id, success, magnitude);
+        match &result {
+            Ok(trust) => self
+                .meter
+                .record_store_update(&trust.entity_type, true, Some(trust.t3_average())),
+            Err(_) => self.meter.record_store_update("unknown", false, None),
+        }
+        result
+    }
+
+    fn witness(
+        &self,
+        witness_id: &str,
+        target_id: &str,
+        success: bool,
+        magnitude: f64,
+    ) -> Result<(EntityTrust, EntityTrust)> {
+        let _span = info_span!(
+            "trust_store.witness",
+            witness_id = %witness_id,
+            target_id = %target_id
+        )
+        .entered();
+        let result = self.inner.witness(witness_id, target_id, success, magnitude);
+        match &result {
+            Ok((_witness, target)) => self.meter.record_store_witness(
+                &target.entity_type,
+                true,
+                Some(target.t3_average()),
+            ),
+            Err(_) => self.meter.record_store_witness("unknown", false, None),
+        }
+        result
+    }
+
+    fn propagate_trust(&self) -> Result<()> {
+        self.inner.propagate_trust()
+    }
+
+    fn history(&self, entity_id: &str) -> Result<Vec<crate::storage::HistoryEntry>> {
+        self.inner.history(entity_id)
+    }
+
+    fn register_observer(&self, pattern: &str, callback: crate::storage::ObserverCallback) {
+        self.inner.register_observer(pattern, callback)
+    }
+}
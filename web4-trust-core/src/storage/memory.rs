@@ -3,6 +3,11 @@
 use std::collections::HashMap;
 use std::sync::RwLock;
 
+use chrono::Utc;
+
+use crate::graph::{self, TEMPERAMENT_BLEND_RATE};
+use crate::storage::observer::pattern_matches;
+use crate::storage::{HistoryEntry, ObserverCallback, TrustCause, TrustChangeEvent};
 use crate::{EntityTrust, EntityType, Error, Result};
 use super::TrustStore;
 
@@ -12,6 +17,8 @@ use super::TrustStore;
 /// Data is lost when the store is dropped.
 pub struct InMemoryStore {
     entities: RwLock<HashMap<String, EntityTrust>>,
+    history: RwLock<HashMap<String, Vec<HistoryEntry>>>,
+    observers: RwLock<Vec<(String, ObserverCallback)>>,
 }
 
 impl InMemoryStore {
@@ -19,6 +26,8 @@ impl InMemoryStore {
     pub fn new() -> Self {
         Self {
             entities: RwLock::new(HashMap::new()),
+            history: RwLock::new(HashMap::new()),
+            observers: RwLock::new(Vec::new()),
         }
     }
 
@@ -31,6 +40,8 @@ impl InMemoryStore {
 
         Self {
             entities: RwLock::new(map),
+            history: RwLock::new(HashMap::new()),
+            observers: RwLock::new(Vec::new()),
         }
     }
 
@@ -47,6 +58,56 @@ impl InMemoryStore {
     /// Clear all entities
     pub fn clear(&self) {
         self.entities.write().unwrap().clear();
+        self.history.write().unwrap().clear();
+    }
+
+    /// Write `trust` and append a [`HistoryEntry`] recording why, under one
+    /// pair of locks, then dispatch a [`TrustChangeEvent`] to any matching
+    /// observers if this crossed a `TrustLevel` boundary. The single write
+    /// path behind `save`/`update`/`witness`.
+    fn save_with_cause(&self, trust: &EntityTrust, cause: TrustCause) -> Result<()> {
+        let mut entities = self.entities.write().map_err(|e| Error::Storage(e.to_string()))?;
+        let previous = entities.insert(trust.entity_id.clone(), trust.clone());
+        drop(entities);
+
+        if let Some(previous) = previous {
+            let old_level = previous.t3.level();
+            let new_level = trust.t3.level();
+            if old_level != new_level {
+                self.dispatch_observers(&TrustChangeEvent {
+                    entity_id: trust.entity_id.clone(),
+                    old_tensor: previous.t3,
+                    new_tensor: trust.t3.clone(),
+                    old_level,
+                    new_level,
+                    cause: cause.clone(),
+                });
+            }
+        }
+
+        let mut history = self.history.write().map_err(|e| Error::Storage(e.to_string()))?;
+        history
+            .entry(trust.entity_id.clone())
+            .or_default()
+            .push(HistoryEntry {
+                entity_id: trust.entity_id.clone(),
+                timestamp: Utc::now(),
+                t3: trust.t3.clone(),
+                cause,
+            });
+        Ok(())
+    }
+
+    /// Invoke every registered observer whose pattern matches `event.entity_id`.
+    fn dispatch_observers(&self, event: &TrustChangeEvent) {
+        let Ok(observers) = self.observers.read() else {
+            return;
+        };
+        for (pattern, callback) in observers.iter() {
+            if pattern_matches(pattern, &event.entity_id) {
+                callback(event);
+            }
+        }
     }
 }
 
@@ -78,9 +139,7 @@ impl TrustStore for InMemoryStore {
     }
 
     fn save(&self, trust: &EntityTrust) -> Result<()> {
-        let mut entities = self.entities.write().map_err(|e| Error::Storage(e.to_string()))?;
-        entities.insert(trust.entity_id.clone(), trust.clone());
-        Ok(())
+        self.save_with_cause(trust, TrustCause::Saved)
     }
 
     fn delete(&self, entity_id: &str) -> Result<bool> {
@@ -88,6 +147,77 @@ impl TrustStore for InMemoryStore {
         Ok(entities.remove(entity_id).is_some())
     }
 
+    fn update(&self, entity_id: &str, success: bool, magnitude: f64) -> Result<EntityTrust> {
+        let mut trust = self.get(entity_id)?;
+        trust.update_from_outcome(success, magnitude);
+        self.save_with_cause(&trust, TrustCause::Outcome { success, magnitude })?;
+        Ok(trust)
+    }
+
+    fn witness(
+        &self,
+        witness_id: &str,
+        target_id: &str,
+        success: bool,
+        magnitude: f64,
+    ) -> Result<(EntityTrust, EntityTrust)> {
+        let mut target = self.get(target_id)?;
+        target.receive_witness(witness_id, success, magnitude);
+        self.save_with_cause(
+            &target,
+            TrustCause::WitnessReceived {
+                witness_id: witness_id.to_string(),
+                success,
+                magnitude,
+            },
+        )?;
+
+        let mut witness = self.get(witness_id)?;
+        witness.give_witness(target_id, success, magnitude);
+        self.save_with_cause(
+            &witness,
+            TrustCause::WitnessGiven {
+                target_id: target_id.to_string(),
+                success,
+                magnitude,
+            },
+        )?;
+
+        Ok((witness, target))
+    }
+
+    fn history(&self, entity_id: &str) -> Result<Vec<HistoryEntry>> {
+        let history = self.history.read().map_err(|e| Error::Storage(e.to_string()))?;
+        Ok(history.get(entity_id).cloned().unwrap_or_default())
+    }
+
+    fn apply_decay_since_last_event(
+        &self,
+        entity_id: &str,
+        decay_rate: f64,
+    ) -> Result<EntityTrust> {
+        let mut trust = self.get(entity_id)?;
+        let days_inactive = match self.history(entity_id)?.last() {
+            Some(entry) => (Utc::now() - entry.timestamp).num_seconds() as f64 / 86400.0,
+            None => trust.days_since_last_action(),
+        };
+        trust.apply_decay(days_inactive, decay_rate);
+        self.save_with_cause(
+            &trust,
+            TrustCause::Decay {
+                days_inactive,
+                decay_rate,
+            },
+        )?;
+        Ok(trust)
+    }
+
+    fn register_observer(&self, pattern: &str, callback: ObserverCallback) {
+        if let Ok(mut observers) = self.observers.write() {
+            observers.push((pattern.to_string(), callback));
+        }
+    }
+
     fn list(&self, entity_type: Option<&EntityType>) -> Result<Vec<String>> {
         let entities = self.entities.read().map_err(|e| Error::Storage(e.to_string()))?;
 
@@ -110,11 +240,70 @@ impl TrustStore for InMemoryStore {
         let entities = self.entities.read().map_err(|e| Error::Storage(e.to_string()))?;
         Ok(entities.contains_key(entity_id))
     }
+
+    /// Fast path: the default `TrustStore::propagate_trust` round-trips
+    /// every entity through `list`/`get_existing`/`save`, each taking its
+    /// own lock. Since an in-memory store already holds every entity, do the
+    /// whole computation under a single write lock instead.
+    fn propagate_trust(&self) -> Result<()> {
+        let mut entities = self.entities.write().map_err(|e| Error::Storage(e.to_string()))?;
+
+        let ids: Vec<String> = entities.keys().cloned().collect();
+        if ids.len() < 2 {
+            return Ok(());
+        }
+
+        let edges: HashMap<String, Vec<String>> = entities
+            .values()
+            .map(|t| (t.entity_id.clone(), t.has_witnessed.clone()))
+            .collect();
+        let centrality = graph::compute_centrality(&ids, &edges);
+
+        let new_temperaments: Vec<(String, f64)> = entities
+            .values()
+            .filter_map(|trust| {
+                if trust.witnessed_by.is_empty() {
+                    return None;
+                }
+
+                let mut weight_sum = 0.0;
+                let mut weighted_temperament = 0.0;
+                for witness_id in &trust.witnessed_by {
+                    if witness_id == &trust.entity_id {
+                        continue; // exclude self-loops
+                    }
+                    let Some(witness) = entities.get(witness_id) else {
+                        continue;
+                    };
+                    let weight = centrality.weight(witness_id);
+                    weight_sum += weight;
+                    weighted_temperament += weight * witness.t3.temperament;
+                }
+                if weight_sum <= 0.0 {
+                    return None;
+                }
+
+                let mean = weighted_temperament / weight_sum;
+                let blended = (1.0 - TEMPERAMENT_BLEND_RATE) * trust.t3.temperament
+                    + TEMPERAMENT_BLEND_RATE * mean;
+                Some((trust.entity_id.clone(), blended))
+            })
+            .collect();
+
+        for (id, temperament) in new_temperaments {
+            if let Some(trust) = entities.get_mut(&id) {
+                trust.t3.temperament = temperament;
+            }
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::TrustLevel;
 
     #[test]
     fn test_new_store() {
@@ -209,4 +398,184 @@ mod tests {
         assert_eq!(trust.action_count, 2);
         assert_eq!(trust.success_count, 1);
     }
+
+    #[test]
+    fn test_propagate_trust_weights_well_connected_witness_more() {
+        let mut hub = EntityTrust::new("session:hub");
+        hub.has_witnessed = vec![
+            "mcp:a".to_string(),
+            "mcp:b".to_string(),
+            "mcp:c".to_string(),
+        ];
+        hub.t3.temperament = 0.9;
+
+        let mut isolated = EntityTrust::new("session:isolated");
+        isolated.t3.temperament = 0.1;
+
+        let mut leaf = EntityTrust::new("mcp:leaf");
+        leaf.witnessed_by = vec!["session:hub".to_string(), "session:isolated".to_string()];
+        leaf.t3.temperament = 0.5;
+
+        let store = InMemoryStore::with_entities(vec![
+            hub,
+            isolated,
+            leaf,
+            EntityTrust::new("mcp:a"),
+            EntityTrust::new("mcp:b"),
+            EntityTrust::new("mcp:c"),
+        ]);
+
+        store.propagate_trust().unwrap();
+
+        let leaf = store.get_existing("mcp:leaf").unwrap().unwrap();
+        // `hub` sits at the center of a small star and has nonzero
+        // centrality; `isolated` witnesses no one and has zero centrality,
+        // so it should contribute nothing to the blend.
+        let expected = 0.8 * 0.5 + 0.2 * 0.9;
+        assert!((leaf.t3.temperament - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_propagate_trust_leaves_unwitnessed_entities_unchanged() {
+        let mut solo = EntityTrust::new("mcp:solo");
+        solo.t3.temperament = 0.42;
+        let store = InMemoryStore::with_entities(vec![solo, EntityTrust::new("mcp:other")]);
+
+        store.propagate_trust().unwrap();
+
+        let solo = store.get_existing("mcp:solo").unwrap().unwrap();
+        assert_eq!(solo.t3.temperament, 0.42);
+    }
+
+    #[test]
+    fn test_propagate_trust_is_noop_on_empty_store() {
+        let store = InMemoryStore::new();
+        store.propagate_trust().unwrap();
+        assert!(store.is_empty());
+    }
+
+    #[test]
+    fn test_update_and_witness_append_history_with_distinct_causes() {
+        let store = InMemoryStore::new();
+
+        store.update("mcp:test", true, 0.1).unwrap();
+        store.witness("session:a", "mcp:test", true, 0.2).unwrap();
+
+        let history = store.history("mcp:test").unwrap();
+        assert_eq!(history.len(), 3); // creation save + update + witness-received
+        assert!(matches!(history[0].cause, TrustCause::Saved));
+        assert!(matches!(history[1].cause, TrustCause::Outcome { .. }));
+        assert!(matches!(
+            history[2].cause,
+            TrustCause::WitnessReceived { .. }
+        ));
+
+        let witness_history = store.history("session:a").unwrap();
+        assert!(witness_history
+            .iter()
+            .any(|e| matches!(e.cause, TrustCause::WitnessGiven { .. })));
+    }
+
+    #[test]
+    fn test_history_is_empty_for_unknown_entity() {
+        let store = InMemoryStore::new();
+        assert!(store.history("mcp:never-seen").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_as_of_reconstructs_past_tensor() {
+        let store = InMemoryStore::new();
+        store.update("mcp:test", true, 0.1).unwrap();
+
+        let mid = Utc::now();
+        store.update("mcp:test", true, 0.9).unwrap();
+
+        let past = store.as_of("mcp:test", mid).unwrap().unwrap();
+        let current = store.get("mcp:test").unwrap();
+        assert_ne!(past.talent, current.t3.talent);
+
+        let before_anything = mid - chrono::Duration::days(365);
+        assert!(store.as_of("mcp:test", before_anything).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_apply_decay_since_last_event_uses_history_not_days_since_last_action() {
+        let store = InMemoryStore::new();
+        store.update("mcp:test", true, 0.5).unwrap();
+
+        // Right after an update, history-derived inactivity should be ~0
+        // days, so decay should barely (if at all) move the tensor.
+        let before = store.get("mcp:test").unwrap();
+        let decayed = store
+            .apply_decay_since_last_event("mcp:test", 0.1)
+            .unwrap();
+
+        assert!((decayed.t3.talent - before.t3.talent).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_observer_fires_once_on_level_crossing() {
+        use std::sync::{Arc, Mutex};
+
+        let store = InMemoryStore::new();
+        let seen: Arc<Mutex<Vec<(TrustLevel, TrustLevel)>>> = Arc::new(Mutex::new(Vec::new()));
+
+        let seen_clone = Arc::clone(&seen);
+        store.register_observer(
+            "mcp:*",
+            Box::new(move |event| {
+                seen_clone
+                    .lock()
+                    .unwrap()
+                    .push((event.old_level, event.new_level));
+            }),
+        );
+
+        // Each failure has a smaller absolute effect than the last (delta
+        // scales with current `training`), so several are needed to cross
+        // a full TrustLevel boundary from the neutral Medium starting point.
+        for _ in 0..4 {
+            store.update("mcp:flaky", false, 1.0).unwrap();
+        }
+
+        let seen = seen.lock().unwrap();
+        assert_eq!(seen.len(), 1);
+        assert_eq!(seen[0], (TrustLevel::Medium, TrustLevel::Low));
+    }
+
+    #[test]
+    fn test_observer_ignores_non_matching_pattern() {
+        let store = InMemoryStore::new();
+        let fired = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+
+        let fired_clone = std::sync::Arc::clone(&fired);
+        store.register_observer(
+            "session:*",
+            Box::new(move |_event| {
+                fired_clone.store(true, std::sync::atomic::Ordering::SeqCst);
+            }),
+        );
+
+        for _ in 0..4 {
+            store.update("mcp:flaky", false, 1.0).unwrap();
+        }
+
+        assert!(!fired.load(std::sync::atomic::Ordering::SeqCst));
+    }
+
+    #[cfg(feature = "arrow")]
+    #[test]
+    fn test_export_record_batch() {
+        let store = InMemoryStore::new();
+        store.update("mcp:a", true, 0.1).unwrap();
+        store.update("role:x", true, 0.2).unwrap();
+
+        let all = store.export_record_batch(None).unwrap();
+        assert_eq!(all.num_rows(), 2);
+
+        let roles = store
+            .export_record_batch(Some(&EntityType::Role("".to_string())))
+            .unwrap();
+        assert_eq!(roles.num_rows(), 1);
+    }
 }
@@ -6,12 +6,30 @@
 
 mod traits;
 mod memory;
+mod history;
+mod observer;
+mod cached;
 
 #[cfg(feature = "file-store")]
 mod file;
 
+#[cfg(feature = "sled-store")]
+mod sled;
+
+#[cfg(feature = "otel")]
+mod instrumented;
+
 pub use traits::TrustStore;
 pub use memory::InMemoryStore;
+pub use history::{HistoryEntry, TrustCause};
+pub use observer::{ObserverCallback, TrustChangeEvent};
+pub use cached::{CacheStats, CachedStore, DEFAULT_CACHE_CAPACITY};
 
 #[cfg(feature = "file-store")]
 pub use file::FileStore;
+
+#[cfg(feature = "sled-store")]
+pub use sled::SledStore;
+
+#[cfg(feature = "otel")]
+pub use instrumented::InstrumentedStore;
@@ -0,0 +1,62 @@
+//! Change-observer callbacks for `TrustLevel` transitions
+//!
+//! Most trust degradation happens silently inside `save`/`update`/`witness`:
+//! nothing lets a caller react the instant an entity's categorical
+//! [`TrustLevel`](crate::TrustLevel) actually crosses a boundary (e.g.
+//! `Medium` -> `Low` after repeated failures). Polling `get` on a schedule
+//! works but is slow and wastes cycles on entities that never change.
+//! [`TrustChangeEvent`] plus [`TrustStore::register_observer`](super::TrustStore::register_observer)
+//! let a caller register a callback once, scoped to an `entity_id` pattern,
+//! and get pushed the transition the moment it happens — e.g. revoking an
+//! `mcp:*` tool's capability the instant it drops into `Low`.
+
+use crate::tensor::{T3Tensor, TrustLevel};
+use crate::storage::TrustCause;
+
+/// A `TrustLevel` transition, as dispatched to observers registered via
+/// [`TrustStore::register_observer`](super::TrustStore::register_observer).
+pub struct TrustChangeEvent {
+    pub entity_id: String,
+    pub old_tensor: T3Tensor,
+    pub new_tensor: T3Tensor,
+    pub old_level: TrustLevel,
+    pub new_level: TrustLevel,
+    pub cause: TrustCause,
+}
+
+/// A registered observer callback, scoped to an entity ID pattern.
+pub type ObserverCallback = Box<dyn Fn(&TrustChangeEvent) + Send + Sync>;
+
+/// Does `entity_id` match `pattern`?
+///
+/// A pattern ending in `*` matches any `entity_id` sharing that prefix
+/// (e.g. `"mcp:*"` matches `"mcp:filesystem"`); any other pattern must
+/// match `entity_id` exactly.
+pub fn pattern_matches(pattern: &str, entity_id: &str) -> bool {
+    match pattern.strip_suffix('*') {
+        Some(prefix) => entity_id.starts_with(prefix),
+        None => pattern == entity_id,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wildcard_pattern_matches_prefix() {
+        assert!(pattern_matches("mcp:*", "mcp:filesystem"));
+        assert!(!pattern_matches("mcp:*", "session:abc"));
+    }
+
+    #[test]
+    fn test_exact_pattern_requires_full_match() {
+        assert!(pattern_matches("mcp:filesystem", "mcp:filesystem"));
+        assert!(!pattern_matches("mcp:filesystem", "mcp:filesystem2"));
+    }
+
+    #[test]
+    fn test_bare_wildcard_matches_everything() {
+        assert!(pattern_matches("*", "anything:at-all"));
+    }
+}
@@ -0,0 +1,370 @@
+//! Embedded-database storage backed by [`sled`]
+//!
+//! Unlike [`InMemoryStore`](super::InMemoryStore) (volatile) and
+//! [`FileStore`](super::FileStore) (one JSON file per entity, awkward under
+//! concurrent writers), `SledStore` persists every [`EntityTrust`] under its
+//! entity id in an embedded key-value database and survives process restarts at
+//! scale. A bounded in-memory LRU cache sits in front of the database — the
+//! same client-db + state-cache split used in large node codebases: reads are
+//! served from cache (read-through on miss) and writes go through to the
+//! database with the cache updated in lock-step (write-through).
+//!
+//! `witness()` is overridden to apply both tensor updates in a single atomic
+//! [`sled::Batch`], so a crash can never leave one side of a witnessing event
+//! persisted without the other.
+//!
+//! A secondary `entity_type_index` tree keyed by `{type_prefix}\0{entity_id}`
+//! is kept alongside the main tree so `list(Some(type))` is a prefix scan over
+//! that index instead of a full-database walk-and-deserialize.
+
+use std::num::NonZeroUsize;
+use std::path::Path;
+use std::sync::Mutex;
+
+use lru::LruCache;
+
+use crate::{EntityTrust, EntityType, Error, Result};
+use super::TrustStore;
+
+#[cfg(feature = "file-store")]
+use super::FileStore;
+
+/// Default number of `EntityTrust` records held in the front cache.
+pub const DEFAULT_CACHE_CAPACITY: usize = 4096;
+
+/// Name of the secondary tree mapping `{type_prefix}\0{entity_id}` to nothing,
+/// used to answer `list(Some(type))` as a prefix scan.
+const TYPE_INDEX_TREE: &str = "entity_type_index";
+
+/// Persistent `TrustStore` backed by an embedded sled database with an LRU
+/// write-through cache.
+pub struct SledStore {
+    db: sled::Db,
+    type_index: sled::Tree,
+    cache: Mutex<LruCache<String, EntityTrust>>,
+}
+
+/// Key into `type_index`: `{type_prefix}\0{entity_id}`, so a prefix scan on
+/// `{type_prefix}\0` yields exactly the entities of that type.
+fn type_index_key(entity_type: &str, entity_id: &str) -> Vec<u8> {
+    let mut key = entity_type.as_bytes().to_vec();
+    key.push(0);
+    key.extend_from_slice(entity_id.as_bytes());
+    key
+}
+
+fn to_storage_err<E: std::fmt::Display>(e: E) -> Error {
+    Error::Storage(e.to_string())
+}
+
+fn serialize(trust: &EntityTrust) -> Result<Vec<u8>> {
+    serde_json::to_vec(trust).map_err(|e| Error::Serialization(e.to_string()))
+}
+
+fn deserialize(bytes: &[u8]) -> Result<EntityTrust> {
+    serde_json::from_slice(bytes).map_err(|e| Error::Serialization(e.to_string()))
+}
+
+impl SledStore {
+    /// Open (or create) a sled store at `path` with the default cache capacity.
+    pub fn new(path: impl AsRef<Path>) -> Result<Self> {
+        Self::with_cache_capacity(path, DEFAULT_CACHE_CAPACITY)
+    }
+
+    /// Open (or create) a sled store with an explicit cache capacity.
+    pub fn with_cache_capacity(path: impl AsRef<Path>, capacity: usize) -> Result<Self> {
+        let db = sled::open(path).map_err(to_storage_err)?;
+        let type_index = db.open_tree(TYPE_INDEX_TREE).map_err(to_storage_err)?;
+        let capacity = NonZeroUsize::new(capacity.max(1)).unwrap();
+        Ok(Self {
+            db,
+            type_index,
+            cache: Mutex::new(LruCache::new(capacity)),
+        })
+    }
+
+    /// Open the default store location (`~/.web4/governance/sled`).
+    pub fn open_default() -> Result<Self> {
+        let home = std::env::var_os("HOME")
+            .or_else(|| std::env::var_os("USERPROFILE"))
+            .ok_or_else(|| Error::Storage("Cannot find home directory".to_string()))?;
+        let base = Path::new(&home).join(".web4").join("governance").join("sled");
+        Self::new(base)
+    }
+
+    /// Flush any pending writes to disk.
+    pub fn flush(&self) -> Result<()> {
+        self.db.flush().map_err(to_storage_err)?;
+        Ok(())
+    }
+
+    fn cache_get(&self, entity_id: &str) -> Option<EntityTrust> {
+        self.cache.lock().unwrap().get(entity_id).cloned()
+    }
+
+    fn cache_put(&self, trust: &EntityTrust) {
+        self.cache
+            .lock()
+            .unwrap()
+            .put(trust.entity_id.clone(), trust.clone());
+    }
+
+    fn cache_invalidate(&self, entity_id: &str) {
+        self.cache.lock().unwrap().pop(entity_id);
+    }
+
+    /// Read an entity from the database, bypassing the cache.
+    fn db_get(&self, entity_id: &str) -> Result<Option<EntityTrust>> {
+        match self.db.get(entity_id.as_bytes()).map_err(to_storage_err)? {
+            Some(bytes) => Ok(Some(deserialize(&bytes)?)),
+            None => Ok(None),
+        }
+    }
+}
+
+impl TrustStore for SledStore {
+    fn get(&self, entity_id: &str) -> Result<EntityTrust> {
+        if let Some(trust) = self.cache_get(entity_id) {
+            return Ok(trust);
+        }
+
+        if let Some(trust) = self.db_get(entity_id)? {
+            self.cache_put(&trust);
+            return Ok(trust);
+        }
+
+        // Create new entity with neutral trust.
+        let trust = EntityTrust::new(entity_id);
+        self.save(&trust)?;
+        Ok(trust)
+    }
+
+    fn get_existing(&self, entity_id: &str) -> Result<Option<EntityTrust>> {
+        if let Some(trust) = self.cache_get(entity_id) {
+            return Ok(Some(trust));
+        }
+        let trust = self.db_get(entity_id)?;
+        if let Some(ref t) = trust {
+            self.cache_put(t);
+        }
+        Ok(trust)
+    }
+
+    fn save(&self, trust: &EntityTrust) -> Result<()> {
+        let bytes = serialize(trust)?;
+        self.db
+            .insert(trust.entity_id.as_bytes(), bytes)
+            .map_err(to_storage_err)?;
+        self.type_index
+            .insert(type_index_key(&trust.entity_type, &trust.entity_id), &[])
+            .map_err(to_storage_err)?;
+        self.cache_put(trust);
+        Ok(())
+    }
+
+    fn delete(&self, entity_id: &str) -> Result<bool> {
+        let existing = self.db_get(entity_id)?;
+        let existed = self
+            .db
+            .remove(entity_id.as_bytes())
+            .map_err(to_storage_err)?
+            .is_some();
+        if let Some(trust) = existing {
+            self.type_index
+                .remove(type_index_key(&trust.entity_type, entity_id))
+                .map_err(to_storage_err)?;
+        }
+        self.cache_invalidate(entity_id);
+        Ok(existed)
+    }
+
+    fn list(&self, entity_type: Option<&EntityType>) -> Result<Vec<String>> {
+        match entity_type {
+            Some(etype) => {
+                let mut prefix = etype.type_prefix().as_bytes().to_vec();
+                prefix.push(0);
+                let mut entities = Vec::new();
+                for item in self.type_index.scan_prefix(&prefix) {
+                    let (key, _value) = item.map_err(to_storage_err)?;
+                    let entity_id = String::from_utf8_lossy(&key[prefix.len()..]).into_owned();
+                    entities.push(entity_id);
+                }
+                Ok(entities)
+            }
+            None => {
+                let mut entities = Vec::new();
+                for item in self.db.iter() {
+                    let (_key, value) = item.map_err(to_storage_err)?;
+                    entities.push(deserialize(&value)?.entity_id);
+                }
+                Ok(entities)
+            }
+        }
+    }
+
+    fn exists(&self, entity_id: &str) -> Result<bool> {
+        if self.cache.lock().unwrap().contains(entity_id) {
+            return Ok(true);
+        }
+        self.db.contains_key(entity_id.as_bytes()).map_err(to_storage_err)
+    }
+
+    /// Record a witnessing event, persisting both sides in one atomic batch.
+    fn witness(
+        &self,
+        witness_id: &str,
+        target_id: &str,
+        success: bool,
+        magnitude: f64,
+    ) -> Result<(EntityTrust, EntityTrust)> {
+        let mut target = self.get(target_id)?;
+        target.receive_witness(witness_id, success, magnitude);
+
+        let mut witness = self.get(witness_id)?;
+        witness.give_witness(target_id, success, magnitude);
+
+        // Atomic batch: either both tensors land on disk or neither does.
+        let mut batch = sled::Batch::default();
+        batch.insert(target.entity_id.as_bytes(), serialize(&target)?);
+        batch.insert(witness.entity_id.as_bytes(), serialize(&witness)?);
+        self.db.apply_batch(batch).map_err(to_storage_err)?;
+
+        let mut index_batch = sled::Batch::default();
+        index_batch.insert(type_index_key(&target.entity_type, &target.entity_id), &[]);
+        index_batch.insert(type_index_key(&witness.entity_type, &witness.entity_id), &[]);
+        self.type_index.apply_batch(index_batch).map_err(to_storage_err)?;
+
+        // Write-through: refresh the cache only after the durable write succeeds.
+        self.cache_put(&target);
+        self.cache_put(&witness);
+
+        Ok((witness, target))
+    }
+}
+
+#[cfg(feature = "file-store")]
+impl SledStore {
+    /// Copy every entity from `source` into this store, preserving JSON
+    /// compatibility since both backends serialize `EntityTrust` the same way.
+    ///
+    /// Existing entries in this store with the same id are overwritten.
+    /// Returns the number of entities migrated.
+    pub fn migrate_from(&self, source: &FileStore) -> Result<usize> {
+        let ids = source.list(None)?;
+        for entity_id in &ids {
+            let trust = source.get(entity_id)?;
+            self.save(&trust)?;
+        }
+        Ok(ids.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn temp_store() -> (SledStore, TempDir) {
+        let temp_dir = TempDir::new().unwrap();
+        let store = SledStore::new(temp_dir.path()).unwrap();
+        (store, temp_dir)
+    }
+
+    #[test]
+    fn test_get_creates_and_persists() {
+        let temp_dir = TempDir::new().unwrap();
+        {
+            let store = SledStore::new(temp_dir.path()).unwrap();
+            let trust = store.get("mcp:filesystem").unwrap();
+            assert_eq!(trust.entity_id, "mcp:filesystem");
+            store.flush().unwrap();
+        }
+        // Reopen: state survives the restart.
+        let store = SledStore::new(temp_dir.path()).unwrap();
+        assert!(store.exists("mcp:filesystem").unwrap());
+    }
+
+    #[test]
+    fn test_cache_read_through() {
+        let (store, _t) = temp_store();
+        store.get("mcp:a").unwrap();
+        // Second read is served from cache and stays consistent.
+        let again = store.get("mcp:a").unwrap();
+        assert_eq!(again.entity_id, "mcp:a");
+    }
+
+    #[test]
+    fn test_witness_atomic_and_cached() {
+        let (store, _t) = temp_store();
+        let (witness, target) = store.witness("session:a", "mcp:test", true, 0.1).unwrap();
+        assert_eq!(witness.witness_count, 1);
+        assert_eq!(target.witness_count, 1);
+        // Both sides readable (from cache) after the batch.
+        assert!(store.get_existing("session:a").unwrap().is_some());
+        assert!(store.get_existing("mcp:test").unwrap().is_some());
+    }
+
+    #[test]
+    fn test_delete_invalidates_cache() {
+        let (store, _t) = temp_store();
+        store.get("mcp:test").unwrap();
+        assert!(store.delete("mcp:test").unwrap());
+        assert!(!store.exists("mcp:test").unwrap());
+    }
+
+    #[test]
+    fn test_list_filters_by_type() {
+        let (store, _t) = temp_store();
+        store.get("mcp:a").unwrap();
+        store.get("mcp:b").unwrap();
+        store.get("role:x").unwrap();
+        assert_eq!(store.list(None).unwrap().len(), 3);
+        let mcps = store.list(Some(&EntityType::Mcp(String::new()))).unwrap();
+        assert_eq!(mcps.len(), 2);
+    }
+
+    #[test]
+    fn test_type_index_survives_delete() {
+        let (store, _t) = temp_store();
+        store.get("mcp:a").unwrap();
+        store.get("mcp:b").unwrap();
+        store.delete("mcp:a").unwrap();
+
+        let mcps = store.list(Some(&EntityType::Mcp(String::new()))).unwrap();
+        assert_eq!(mcps, vec!["mcp:b".to_string()]);
+    }
+
+    #[test]
+    fn test_witness_updates_type_index() {
+        let (store, _t) = temp_store();
+        store.witness("session:a", "mcp:test", true, 0.1).unwrap();
+
+        let sessions = store.list(Some(&EntityType::Session(String::new()))).unwrap();
+        assert_eq!(sessions, vec!["session:a".to_string()]);
+        let mcps = store.list(Some(&EntityType::Mcp(String::new()))).unwrap();
+        assert_eq!(mcps, vec!["mcp:test".to_string()]);
+    }
+
+    #[cfg(feature = "file-store")]
+    #[test]
+    fn test_migrate_from_file_store() {
+        use crate::storage::FileStore;
+
+        let file_dir = TempDir::new().unwrap();
+        let file_store = FileStore::new(file_dir.path()).unwrap();
+        let mut trust = file_store.get("mcp:legacy").unwrap();
+        trust.update_from_outcome(true, 0.1);
+        file_store.save(&trust).unwrap();
+
+        let (sled_store, _t) = temp_store();
+        let migrated = sled_store.migrate_from(&file_store).unwrap();
+        assert_eq!(migrated, 1);
+
+        let moved = sled_store.get_existing("mcp:legacy").unwrap().unwrap();
+        assert_eq!(moved.action_count, 1);
+        assert_eq!(
+            sled_store.list(Some(&EntityType::Mcp(String::new()))).unwrap(),
+            vec!["mcp:legacy".to_string()]
+        );
+    }
+}
@@ -1,5 +1,11 @@
 //! Storage trait definition
 
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+
+use super::{HistoryEntry, ObserverCallback};
+use crate::graph::{self, TEMPERAMENT_BLEND_RATE};
 use crate::{EntityTrust, EntityType, Result};
 
 /// Trait for entity trust storage backends
@@ -64,4 +70,162 @@ pub trait TrustStore {
     fn count(&self, entity_type: Option<&EntityType>) -> Result<usize> {
         Ok(self.list(entity_type)?.len())
     }
+
+    /// Reweight every entity's temperament by its witnesses' structural
+    /// centrality in the witness graph (`has_witnessed` edges), so
+    /// attestations from well-connected, hard-to-bypass witnesses count more
+    /// than ones from isolated entities.
+    ///
+    /// For each entity, blends its temperament toward the
+    /// centrality-weighted mean temperament of the entities in its
+    /// `witnessed_by` list (weight = [`graph::Centrality::weight`] of the
+    /// witness). Entities with no witnesses, and witnesses whose combined
+    /// centrality weight is zero, are left unchanged.
+    ///
+    /// This default implementation round-trips every entity through
+    /// `list`/`get_existing`/`save`; backends that hold all entities in
+    /// memory should override it with a direct fast path.
+    fn propagate_trust(&self) -> Result<()> {
+        let ids = self.list(None)?;
+        if ids.len() < 2 {
+            return Ok(());
+        }
+
+        let mut trusts: HashMap<String, EntityTrust> = HashMap::new();
+        for id in &ids {
+            if let Some(trust) = self.get_existing(id)? {
+                trusts.insert(id.clone(), trust);
+            }
+        }
+
+        let edges: HashMap<String, Vec<String>> = trusts
+            .values()
+            .map(|t| (t.entity_id.clone(), t.has_witnessed.clone()))
+            .collect();
+        let centrality = graph::compute_centrality(&ids, &edges);
+
+        for trust in trusts.values() {
+            if trust.witnessed_by.is_empty() {
+                continue;
+            }
+
+            let mut weight_sum = 0.0;
+            let mut weighted_temperament = 0.0;
+            for witness_id in &trust.witnessed_by {
+                if witness_id == &trust.entity_id {
+                    continue; // exclude self-loops
+                }
+                let Some(witness) = trusts.get(witness_id) else {
+                    continue;
+                };
+                let weight = centrality.weight(witness_id);
+                weight_sum += weight;
+                weighted_temperament += weight * witness.t3.temperament;
+            }
+            if weight_sum <= 0.0 {
+                continue;
+            }
+
+            let mut updated = trust.clone();
+            let mean = weighted_temperament / weight_sum;
+            updated.t3.temperament =
+                (1.0 - TEMPERAMENT_BLEND_RATE) * updated.t3.temperament + TEMPERAMENT_BLEND_RATE * mean;
+            self.save(&updated)?;
+        }
+
+        Ok(())
+    }
+
+    /// This entity's recorded history, oldest first.
+    ///
+    /// Default: no-op, returning an empty history. Override for backends
+    /// that actually record [`HistoryEntry`] snapshots on `save`/`update`/
+    /// `witness`.
+    fn history(&self, entity_id: &str) -> Result<Vec<HistoryEntry>> {
+        let _ = entity_id;
+        Ok(Vec::new())
+    }
+
+    /// Reconstruct the [`T3Tensor`](crate::T3Tensor) as it stood at or
+    /// before `timestamp`, from `history(entity_id)`.
+    ///
+    /// Returns `None` if there's no recorded entry at or before `timestamp`
+    /// (including on backends where `history` always no-ops).
+    fn as_of(
+        &self,
+        entity_id: &str,
+        timestamp: DateTime<Utc>,
+    ) -> Result<Option<crate::T3Tensor>> {
+        Ok(self
+            .history(entity_id)?
+            .into_iter()
+            .filter(|entry| entry.timestamp <= timestamp)
+            .last()
+            .map(|entry| entry.t3))
+    }
+
+    /// Apply temporal decay, deriving `days_inactive` from the time since
+    /// the entity's last recorded history entry instead of requiring the
+    /// caller to track it out of band.
+    ///
+    /// Falls back to [`EntityTrust::days_since_last_action`] on backends
+    /// without history support, where `history` no-ops to an empty `Vec`.
+    fn apply_decay_since_last_event(
+        &self,
+        entity_id: &str,
+        decay_rate: f64,
+    ) -> Result<EntityTrust> {
+        let mut trust = self.get(entity_id)?;
+        let days_inactive = match self.history(entity_id)?.last() {
+            Some(entry) => (Utc::now() - entry.timestamp).num_seconds() as f64 / 86400.0,
+            None => trust.days_since_last_action(),
+        };
+        trust.apply_decay(days_inactive, decay_rate);
+        self.save(&trust)?;
+        Ok(trust)
+    }
+
+    /// Export all (or, with `entity_type`, a type-filtered subset of)
+    /// entities as a single Arrow [`RecordBatch`](arrow::record_batch::RecordBatch),
+    /// for bulk analytics over a whole store instead of one entity at a
+    /// time. See [`crate::arrow`] for the column layout.
+    #[cfg(feature = "arrow")]
+    fn export_record_batch(
+        &self,
+        entity_type: Option<&EntityType>,
+    ) -> Result<arrow::record_batch::RecordBatch> {
+        let ids = self.list(entity_type)?;
+        let mut entities = Vec::with_capacity(ids.len());
+        for id in &ids {
+            if let Some(trust) = self.get_existing(id)? {
+                entities.push(trust);
+            }
+        }
+        Ok(crate::arrow::to_record_batch(&entities))
+    }
+
+    /// Serialize this store's entities and witnessing history as W3C
+    /// PROV-O Turtle. See [`crate::prov`] for the agent/activity mapping.
+    #[cfg(feature = "prov")]
+    fn to_prov_turtle(&self) -> Result<String> {
+        crate::prov::to_prov_turtle(self)
+    }
+
+    /// Serialize this store's entities and witnessing history as
+    /// PROV-JSON-LD. See [`crate::prov`] for the agent/activity mapping.
+    #[cfg(feature = "prov")]
+    fn to_prov_jsonld(&self) -> Result<serde_json::Value> {
+        crate::prov::to_prov_jsonld(self)
+    }
+
+    /// Register `callback`, to be invoked whenever a `save`/`update`/
+    /// `witness` call crosses a [`TrustLevel`](crate::TrustLevel) boundary
+    /// for an `entity_id` matching `pattern`. A pattern ending in `*`
+    /// matches any `entity_id` sharing that prefix (e.g. `"mcp:*"`);
+    /// any other pattern must match `entity_id` exactly.
+    ///
+    /// Default: no-op. Override for backends that hold registrations and
+    /// actually dispatch [`TrustChangeEvent`](super::TrustChangeEvent)s, such
+    /// as `InMemoryStore`.
+    fn register_observer(&self, _pattern: &str, _callback: ObserverCallback) {}
 }
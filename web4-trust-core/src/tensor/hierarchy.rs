@@ -0,0 +1,273 @@
+//! Hierarchical sub-dimensions under each T3 root
+//!
+//! [`T3Tensor`] is the flat, storage-canonical 3-value projection the
+//! module docs promise an "open-ended RDF sub-graph extensible via
+//! `web4:subDimensionOf`" on top of. [`T3Graph`] is that sub-graph: each
+//! root — talent/training/temperament — can carry named, weighted child
+//! scores (e.g. `"ability:coding"`, `"ability:review"` under talent) that
+//! roll up into the root's flat value via a weighted mean. A root with no
+//! children keeps its flat value as-is, so `T3Graph` is opt-in per root
+//! and a freshly-built one collapses to exactly the same `T3Tensor` it
+//! wraps.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::T3Tensor;
+
+/// One of the three T3 root dimensions, used to select which sub-dimension
+/// map a [`T3Graph`] operation targets.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RootDimension {
+    Talent,
+    Training,
+    Temperament,
+}
+
+/// A named child score under a T3 root, with its weight in that root's
+/// rollup.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SubScore {
+    pub score: f64,
+    pub weight: f64,
+}
+
+/// Hierarchical sub-dimensions layered on top of a flat [`T3Tensor`].
+///
+/// See the module docs for the rollup rule. Serializes as the flat base
+/// tensor plus the three sub-dimension maps, so a `T3Graph` with empty
+/// maps round-trips identically to its wrapped `T3Tensor`.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct T3Graph {
+    base: T3Tensor,
+    talent: HashMap<String, SubScore>,
+    training: HashMap<String, SubScore>,
+    temperament: HashMap<String, SubScore>,
+}
+
+impl T3Graph {
+    /// An empty graph wrapping a neutral `T3Tensor` (no sub-dimensions).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Wrap an existing flat tensor with no sub-dimensions yet.
+    pub fn from_tensor(base: T3Tensor) -> Self {
+        Self {
+            base,
+            ..Default::default()
+        }
+    }
+
+    fn dimension(&self, root: RootDimension) -> &HashMap<String, SubScore> {
+        match root {
+            RootDimension::Talent => &self.talent,
+            RootDimension::Training => &self.training,
+            RootDimension::Temperament => &self.temperament,
+        }
+    }
+
+    fn dimension_mut(&mut self, root: RootDimension) -> &mut HashMap<String, SubScore> {
+        match root {
+            RootDimension::Talent => &mut self.talent,
+            RootDimension::Training => &mut self.training,
+            RootDimension::Temperament => &mut self.temperament,
+        }
+    }
+
+    fn base_value(&self, root: RootDimension) -> f64 {
+        match root {
+            RootDimension::Talent => self.base.talent,
+            RootDimension::Training => self.base.training,
+            RootDimension::Temperament => self.base.temperament,
+        }
+    }
+
+    fn set_base_value(&mut self, root: RootDimension, value: f64) {
+        match root {
+            RootDimension::Talent => self.base.talent = value,
+            RootDimension::Training => self.base.training = value,
+            RootDimension::Temperament => self.base.temperament = value,
+        }
+    }
+
+    /// The named sub-dimensions currently defined under `root`.
+    pub fn sub_dimensions(&self, root: RootDimension) -> &HashMap<String, SubScore> {
+        self.dimension(root)
+    }
+
+    /// Set (or overwrite) a named sub-dimension's score and weight under
+    /// `root`, then refresh the root's flat base value from the new
+    /// rollup.
+    pub fn set_sub_dimension(&mut self, root: RootDimension, name: impl Into<String>, score: f64, weight: f64) {
+        self.dimension_mut(root).insert(
+            name.into(),
+            SubScore {
+                score: score.clamp(0.0, 1.0),
+                weight: weight.max(0.0),
+            },
+        );
+        self.sync_base(root);
+    }
+
+    /// Remove a named sub-dimension from `root`, refreshing its flat base
+    /// value from the remaining children (or leaving the last rolled-up
+    /// value in place if none remain).
+    pub fn remove_sub_dimension(&mut self, root: RootDimension, name: &str) {
+        self.dimension_mut(root).remove(name);
+        self.sync_base(root);
+    }
+
+    /// Weighted-mean rollup of `root`'s children, or its flat base value
+    /// if it has no children (or they carry zero total weight).
+    pub fn rollup(&self, root: RootDimension) -> f64 {
+        let children = self.dimension(root);
+        if children.is_empty() {
+            return self.base_value(root);
+        }
+
+        let weight_sum: f64 = children.values().map(|c| c.weight).sum();
+        if weight_sum <= 0.0 {
+            return self.base_value(root);
+        }
+
+        children.values().map(|c| c.score * c.weight).sum::<f64>() / weight_sum
+    }
+
+    fn sync_base(&mut self, root: RootDimension) {
+        let value = self.rollup(root);
+        self.set_base_value(root, value);
+    }
+
+    /// Collapse to the canonical 3D [`T3Tensor`] projection, each root
+    /// rolled up from its sub-dimensions (or passed through unchanged if
+    /// it has none).
+    pub fn as_tensor(&self) -> T3Tensor {
+        T3Tensor::new(
+            self.rollup(RootDimension::Talent),
+            self.rollup(RootDimension::Training),
+            self.rollup(RootDimension::Temperament),
+        )
+    }
+
+    /// Update a single named sub-dimension from an action outcome,
+    /// independent of its root's other children, mirroring
+    /// [`T3Tensor::update_from_outcome`]'s asymmetric success/failure
+    /// curve. Reuses the sub-dimension's existing weight, defaulting new
+    /// ones to `1.0`.
+    pub fn update_from_outcome(&mut self, root: RootDimension, name: &str, success: bool, magnitude: f64) {
+        let magnitude = magnitude.clamp(0.0, 1.0);
+        let existing = self.dimension(root).get(name).copied();
+        let current = existing.map(|c| c.score).unwrap_or(0.5);
+        let weight = existing.map(|c| c.weight).unwrap_or(1.0);
+
+        let delta = if success {
+            magnitude * 0.05 * (1.0 - current)
+        } else {
+            -magnitude * 0.10 * current
+        };
+
+        self.set_sub_dimension(root, name, current + delta, weight);
+    }
+
+    /// Update a named sub-dimension from track-record history, mirroring
+    /// [`T3Tensor::update_training`]. Reuses the sub-dimension's existing
+    /// weight, defaulting new ones to `1.0`.
+    pub fn update_training(&mut self, name: &str, success_count: u64, action_count: u64) {
+        if action_count == 0 {
+            return;
+        }
+
+        let weight = self
+            .dimension(RootDimension::Training)
+            .get(name)
+            .map(|c| c.weight)
+            .unwrap_or(1.0);
+
+        let success_rate = success_count as f64 / action_count as f64;
+        let history_factor = (action_count as f64 / 100.0).min(1.0);
+        let score = 0.2 + 0.8 * success_rate.sqrt() * history_factor;
+
+        self.set_sub_dimension(RootDimension::Training, name, score, weight);
+    }
+
+    /// Canonical 3-element array projection `[talent, training, temperament]`,
+    /// for storage/migration compatibility — identical to
+    /// [`T3Tensor::as_array`] on the rolled-up tensor.
+    pub fn as_array(&self) -> [f64; 3] {
+        self.as_tensor().as_array()
+    }
+
+    /// Build a graph with no sub-dimensions from a flat 3-element array,
+    /// mirroring [`T3Tensor::from_array`].
+    pub fn from_array(values: [f64; 3]) -> Self {
+        Self::from_tensor(T3Tensor::from_array(values))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_graph_collapses_to_wrapped_tensor() {
+        let base = T3Tensor::new(0.7, 0.6, 0.4);
+        let graph = T3Graph::from_tensor(base.clone());
+        assert_eq!(graph.as_tensor(), base);
+    }
+
+    #[test]
+    fn test_weighted_rollup_overrides_root_value() {
+        let mut graph = T3Graph::new();
+        graph.set_sub_dimension(RootDimension::Talent, "ability:coding", 0.8, 2.0);
+        graph.set_sub_dimension(RootDimension::Talent, "ability:review", 0.6, 1.0);
+
+        // weighted mean = (0.8*2.0 + 0.6*1.0) / 3.0
+        let expected = (0.8 * 2.0 + 0.6 * 1.0) / 3.0;
+        assert!((graph.rollup(RootDimension::Talent) - expected).abs() < 1e-9);
+        assert!((graph.as_tensor().talent - expected).abs() < 1e-9);
+
+        // Untouched roots still pass through the neutral base value.
+        assert_eq!(graph.rollup(RootDimension::Training), 0.5);
+    }
+
+    #[test]
+    fn test_update_from_outcome_targets_one_sub_dimension() {
+        let mut graph = T3Graph::new();
+        graph.set_sub_dimension(RootDimension::Talent, "ability:coding", 0.5, 1.0);
+        graph.set_sub_dimension(RootDimension::Talent, "ability:review", 0.5, 1.0);
+
+        graph.update_from_outcome(RootDimension::Talent, "ability:coding", true, 0.1);
+
+        let coding = graph.sub_dimensions(RootDimension::Talent)["ability:coding"].score;
+        let review = graph.sub_dimensions(RootDimension::Talent)["ability:review"].score;
+        assert!(coding > 0.5);
+        assert_eq!(review, 0.5);
+    }
+
+    #[test]
+    fn test_remove_sub_dimension_falls_back_to_remaining_rollup() {
+        let mut graph = T3Graph::new();
+        graph.set_sub_dimension(RootDimension::Training, "cert:rust", 1.0, 1.0);
+        graph.set_sub_dimension(RootDimension::Training, "cert:go", 0.0, 1.0);
+        assert!((graph.rollup(RootDimension::Training) - 0.5).abs() < 1e-9);
+
+        graph.remove_sub_dimension(RootDimension::Training, "cert:go");
+        assert!((graph.rollup(RootDimension::Training) - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_as_array_matches_collapsed_tensor() {
+        let mut graph = T3Graph::new();
+        graph.set_sub_dimension(RootDimension::Temperament, "trait:consistency", 0.9, 1.0);
+        assert_eq!(graph.as_array(), graph.as_tensor().as_array());
+    }
+
+    #[test]
+    fn test_from_array_round_trips_with_t3tensor() {
+        let values = [0.8, 0.3, 0.6];
+        let graph = T3Graph::from_array(values);
+        assert_eq!(graph.as_array(), values);
+    }
+}
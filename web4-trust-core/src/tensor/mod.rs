@@ -7,9 +7,11 @@
 
 mod t3;
 mod v3;
+mod hierarchy;
 
 pub use t3::T3Tensor;
 pub use v3::V3Tensor;
+pub use hierarchy::{RootDimension, SubScore, T3Graph};
 
 /// Categorical trust level derived from T3 average
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -9,6 +9,7 @@
 
 use serde::{Deserialize, Serialize};
 use super::TrustLevel;
+use crate::decay::{DecayConfig, DecayStrategy};
 
 /// T3 Trust Tensor - 3 root dimensions measuring trustworthiness
 ///
@@ -160,6 +161,37 @@ impl T3Tensor {
         (old_training - self.training).abs() > 0.001
     }
 
+    /// Apply temporal decay using a pluggable [`DecayStrategy`] and `DecayConfig`.
+    ///
+    /// Honors the config's grace period and floor; the per-dimension speed
+    /// multipliers match [`T3Tensor::apply_decay`]. Returns `true` if meaningful
+    /// decay occurred (> 0.001 change in `training`).
+    pub fn apply_decay_with<S: DecayStrategy>(
+        &mut self,
+        days_inactive: f64,
+        config: &DecayConfig,
+        strategy: &S,
+    ) -> bool {
+        if days_inactive <= config.grace_period_days {
+            return false;
+        }
+
+        let effective_days = days_inactive - config.grace_period_days;
+        let decay_factor = strategy.factor(effective_days, config);
+        let floor = config.floor;
+
+        let decay_value = |current: f64, mult: f64| -> f64 {
+            (floor + (current - floor) * decay_factor * mult).max(floor)
+        };
+
+        let old_training = self.training;
+        self.training = decay_value(self.training, 1.0);
+        self.temperament = decay_value(self.temperament, 0.98);
+        self.talent = decay_value(self.talent, 0.995);
+
+        (old_training - self.training).abs() > 0.001
+    }
+
     /// Update temperament from being witnessed by others
     ///
     /// # Arguments
@@ -9,6 +9,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::decay::{DecayConfig, DecayStrategy};
+
 /// V3 Value Tensor - 3 root dimensions measuring value contribution
 ///
 /// Each root dimension is a node in an open-ended RDF sub-graph.
@@ -129,6 +131,33 @@ impl V3Tensor {
         self.valuation = decay_value(self.valuation, 0.99);
     }
 
+    /// Apply temporal decay using a pluggable [`DecayStrategy`] and `DecayConfig`.
+    ///
+    /// Honors the config's grace period and floor; the per-dimension speed
+    /// multipliers match [`V3Tensor::apply_decay`]. Pass [`Exponential`] for the
+    /// legacy curve, or [`Linear`]/[`Gompertz`] for alternative models.
+    pub fn apply_decay_with<S: DecayStrategy>(
+        &mut self,
+        days_inactive: f64,
+        config: &DecayConfig,
+        strategy: &S,
+    ) {
+        if days_inactive <= config.grace_period_days {
+            return;
+        }
+
+        let effective_days = days_inactive - config.grace_period_days;
+        let decay_factor = strategy.factor(effective_days, config);
+        let floor = config.floor;
+
+        let decay_value = |current: f64, mult: f64| -> f64 {
+            (floor + (current - floor) * decay_factor * mult).max(floor)
+        };
+
+        self.validity = decay_value(self.validity, 1.0);
+        self.valuation = decay_value(self.valuation, 0.99);
+    }
+
     /// Get tensor as an array of values [valuation, veracity, validity]
     pub fn as_array(&self) -> [f64; 3] {
         [self.valuation, self.veracity, self.validity]
@@ -0,0 +1,482 @@
+//! Append-only transparency log with Merkle inclusion/consistency proofs
+//!
+//! `EntityTrust` normally accumulates trust silently inside mutable counters:
+//! nothing records that a particular [`update_from_outcome`](crate::EntityTrust::update_from_outcome)
+//! or [`receive_witness`](crate::EntityTrust::receive_witness) call actually
+//! happened. [`TransparencyLog`] gives those events a tamper-evident home,
+//! Rekor/Certificate-Transparency style: every event becomes a leaf in an
+//! append-only Merkle tree, and an auditor who only has a published root hash
+//! can still prove a specific event is committed under it (an inclusion
+//! proof) or prove that one root is an append-only extension of an earlier
+//! one (a consistency proof).
+//!
+//! The tree follows the RFC 6962 Merkle Tree Hash construction: leaves are
+//! domain-separated with a `0x00` prefix and internal nodes with `0x01`
+//! (so a leaf hash can never be mistaken for an internal node hash), and a
+//! range of `n` leaves is split at the largest power of two strictly less
+//! than `n`. That specific split is what makes consistency proofs possible —
+//! every earlier root is a genuine subtree of every later one.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use web4_core::crypto::sha256;
+
+/// A trust-affecting event as recorded in a [`TransparencyLog`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum TrustEvent {
+    /// An entity's own action outcome (see [`EntityTrust::update_from_outcome`](crate::EntityTrust::update_from_outcome)).
+    Outcome {
+        entity_id: String,
+        success: bool,
+        magnitude: f64,
+        timestamp: DateTime<Utc>,
+    },
+    /// `entity_id` was witnessed by `witness_id` (see [`EntityTrust::receive_witness`](crate::EntityTrust::receive_witness)).
+    WitnessReceived {
+        entity_id: String,
+        witness_id: String,
+        success: bool,
+        magnitude: f64,
+        timestamp: DateTime<Utc>,
+    },
+    /// `entity_id` witnessed `target_id` (see [`EntityTrust::give_witness`](crate::EntityTrust::give_witness)).
+    WitnessGiven {
+        entity_id: String,
+        target_id: String,
+        success: bool,
+        magnitude: f64,
+        timestamp: DateTime<Utc>,
+    },
+}
+
+/// Hash a leaf input `d` as `H(0x00 || d)`, per RFC 6962 §2.1.
+fn leaf_hash(data: &[u8]) -> [u8; 32] {
+    let mut buf = Vec::with_capacity(data.len() + 1);
+    buf.push(0x00);
+    buf.extend_from_slice(data);
+    sha256(&buf)
+}
+
+/// Hash an internal node as `H(0x01 || left || right)`, per RFC 6962 §2.1.
+fn node_hash(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut buf = Vec::with_capacity(65);
+    buf.push(0x01);
+    buf.extend_from_slice(left);
+    buf.extend_from_slice(right);
+    sha256(&buf)
+}
+
+/// The largest power of two strictly less than `n`. Only called with `n > 1`.
+fn split_point(n: usize) -> usize {
+    let mut k = 1;
+    while k * 2 < n {
+        k *= 2;
+    }
+    k
+}
+
+/// Merkle Tree Hash of a leaf-hash slice, per RFC 6962 §2.1.
+fn merkle_root(leaves: &[[u8; 32]]) -> [u8; 32] {
+    match leaves.len() {
+        0 => sha256(&[]),
+        1 => leaves[0],
+        n => {
+            let k = split_point(n);
+            node_hash(&merkle_root(&leaves[..k]), &merkle_root(&leaves[k..]))
+        }
+    }
+}
+
+/// The audit path for leaf `index`, per RFC 6962's `PATH` algorithm.
+fn build_path(index: usize, leaves: &[[u8; 32]]) -> Vec<[u8; 32]> {
+    let n = leaves.len();
+    if n <= 1 {
+        return Vec::new();
+    }
+    let k = split_point(n);
+    if index < k {
+        let mut path = build_path(index, &leaves[..k]);
+        path.push(merkle_root(&leaves[k..]));
+        path
+    } else {
+        let mut path = build_path(index - k, &leaves[k..]);
+        path.push(merkle_root(&leaves[..k]));
+        path
+    }
+}
+
+/// Recompute the root implied by an audit path, mirroring [`build_path`]'s
+/// recursion so the two stay in lockstep. Returns `None` if `proof` is the
+/// wrong length for `(index, tree_size)`.
+fn recompute_root(
+    index: usize,
+    tree_size: usize,
+    leaf: [u8; 32],
+    proof: &mut std::slice::Iter<[u8; 32]>,
+) -> Option<[u8; 32]> {
+    if tree_size <= 1 {
+        return Some(leaf);
+    }
+    let k = split_point(tree_size);
+    if index < k {
+        let left = recompute_root(index, k, leaf, proof)?;
+        let right = proof.next()?;
+        Some(node_hash(&left, right))
+    } else {
+        let right = recompute_root(index - k, tree_size - k, leaf, proof)?;
+        let left = proof.next()?;
+        Some(node_hash(left, &right))
+    }
+}
+
+/// The subproof construction behind [`TransparencyLog::consistency_proof`],
+/// per RFC 6962's `SUBPROOF` algorithm. `b` tracks whether this subrange is
+/// still an exact, untouched prefix of the old tree (in which case no proof
+/// element is needed — the caller already knows its hash as `old_root`).
+fn build_subproof(m: usize, leaves: &[[u8; 32]], b: bool) -> Vec<[u8; 32]> {
+    let n = leaves.len();
+    if m == n {
+        if b {
+            Vec::new()
+        } else {
+            vec![merkle_root(leaves)]
+        }
+    } else {
+        let k = split_point(n);
+        if m <= k {
+            let mut proof = build_subproof(m, &leaves[..k], b);
+            proof.push(merkle_root(&leaves[k..]));
+            proof
+        } else {
+            let mut proof = build_subproof(m - k, &leaves[k..], false);
+            proof.push(merkle_root(&leaves[..k]));
+            proof
+        }
+    }
+}
+
+/// Mirrors [`build_subproof`] to recompute `(old_subrange_root, new_subrange_root)`
+/// for verification, consuming proof elements in the same order they were
+/// produced.
+fn verify_subproof(
+    m: usize,
+    n: usize,
+    b: bool,
+    old_root: [u8; 32],
+    proof: &mut std::collections::VecDeque<[u8; 32]>,
+) -> Option<([u8; 32], [u8; 32])> {
+    if m == n {
+        if b {
+            Some((old_root, old_root))
+        } else {
+            let h = proof.pop_front()?;
+            Some((h, h))
+        }
+    } else {
+        let k = split_point(n);
+        if m <= k {
+            let (old_l, new_l) = verify_subproof(m, k, b, old_root, proof)?;
+            let right = proof.pop_front()?;
+            Some((old_l, node_hash(&new_l, &right)))
+        } else {
+            let (old_r, new_r) = verify_subproof(m - k, n - k, false, old_root, proof)?;
+            let left = proof.pop_front()?;
+            Some((node_hash(&left, &old_r), node_hash(&left, &new_r)))
+        }
+    }
+}
+
+/// An append-only Merkle log of [`TrustEvent`]s.
+///
+/// Mirrors the shape of a transparency-log client: events go in with
+/// [`append`](Self::append), and [`root`](Self::root) is what you'd publish.
+/// Anyone holding a published root can later check a specific event is
+/// committed under it ([`verify_inclusion`](Self::verify_inclusion)) or that
+/// a newer root is a strict extension of an older one
+/// ([`verify_consistency`](Self::verify_consistency)), without trusting
+/// the log operator.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct TransparencyLog {
+    leaves: Vec<[u8; 32]>,
+}
+
+impl TransparencyLog {
+    /// Create an empty log.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of leaves (events) committed so far.
+    pub fn len(&self) -> usize {
+        self.leaves.len()
+    }
+
+    /// Whether the log has no events yet.
+    pub fn is_empty(&self) -> bool {
+        self.leaves.is_empty()
+    }
+
+    /// Append `event`, returning its `(leaf_index, log_size)`.
+    pub fn append(&mut self, event: &TrustEvent) -> (usize, usize) {
+        let serialized = serde_json::to_vec(event).expect("TrustEvent always serializes");
+        self.leaves.push(leaf_hash(&serialized));
+        (self.leaves.len() - 1, self.leaves.len())
+    }
+
+    /// The current Merkle root — what an auditor would publish.
+    pub fn root(&self) -> [u8; 32] {
+        merkle_root(&self.leaves)
+    }
+
+    /// The audit path proving leaf `leaf_index` is committed under
+    /// [`root`](Self::root). `None` if `leaf_index` is out of range.
+    pub fn inclusion_proof(&self, leaf_index: usize) -> Option<Vec<[u8; 32]>> {
+        if leaf_index >= self.leaves.len() {
+            return None;
+        }
+        Some(build_path(leaf_index, &self.leaves))
+    }
+
+    /// Verify an inclusion proof produced by [`inclusion_proof`](Self::inclusion_proof).
+    ///
+    /// Takes `tree_size` in addition to the leaf's `index` — unlike a plain
+    /// hash list, an audit path alone doesn't pin down the tree shape it was
+    /// built against, and a verifier who wasn't around for `append` has no
+    /// other way to know it.
+    pub fn verify_inclusion(
+        leaf: [u8; 32],
+        index: usize,
+        tree_size: usize,
+        proof: &[[u8; 32]],
+        root: [u8; 32],
+    ) -> bool {
+        if index >= tree_size {
+            return false;
+        }
+        let mut it = proof.iter();
+        let recomputed = recompute_root(index, tree_size, leaf, &mut it);
+        it.next().is_none() && recomputed == Some(root)
+    }
+
+    /// The leaf hash of `event`, for callers verifying inclusion without
+    /// holding the log itself (e.g. an auditor who only has the serialized
+    /// event and a proof handed to them).
+    pub fn event_leaf_hash(event: &TrustEvent) -> [u8; 32] {
+        let serialized = serde_json::to_vec(event).expect("TrustEvent always serializes");
+        leaf_hash(&serialized)
+    }
+
+    /// A proof that the first `old_size` leaves of the tree at `new_size`
+    /// are exactly the tree that existed when it had `old_size` leaves —
+    /// i.e. that growing from `old_size` to `new_size` only appended.
+    ///
+    /// Returns an empty proof for the trivial cases (`old_size == 0` or
+    /// `old_size == new_size`), which need no evidence.
+    pub fn consistency_proof(&self, old_size: usize, new_size: usize) -> Vec<[u8; 32]> {
+        if old_size == 0 || old_size == new_size || new_size == 0 {
+            return Vec::new();
+        }
+        let size = new_size.min(self.leaves.len());
+        build_subproof(old_size, &self.leaves[..size], true)
+    }
+
+    /// Verify a consistency proof produced by [`consistency_proof`](Self::consistency_proof):
+    /// that `new_root` (a tree of `new_size` leaves) only ever appended to
+    /// `old_root` (a tree of `old_size` leaves), never rewrote it.
+    pub fn verify_consistency(
+        old_size: usize,
+        new_size: usize,
+        old_root: [u8; 32],
+        new_root: [u8; 32],
+        proof: &[[u8; 32]],
+    ) -> bool {
+        if old_size == 0 {
+            return true;
+        }
+        if old_size == new_size {
+            return proof.is_empty() && old_root == new_root;
+        }
+        if old_size > new_size {
+            return false;
+        }
+        let mut queue: std::collections::VecDeque<[u8; 32]> = proof.iter().copied().collect();
+        match verify_subproof(old_size, new_size, true, old_root, &mut queue) {
+            Some((computed_old, computed_new)) => {
+                queue.is_empty() && computed_old == old_root && computed_new == new_root
+            }
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn outcome_event(n: u64) -> TrustEvent {
+        TrustEvent::Outcome {
+            entity_id: format!("mcp:service-{n}"),
+            success: n % 2 == 0,
+            magnitude: 0.1,
+            timestamp: DateTime::parse_from_rfc3339("2026-01-01T00:00:00Z")
+                .unwrap()
+                .with_timezone(&Utc),
+        }
+    }
+
+    #[test]
+    fn test_append_returns_index_and_growing_size() {
+        let mut log = TransparencyLog::new();
+        assert_eq!(log.append(&outcome_event(0)), (0, 1));
+        assert_eq!(log.append(&outcome_event(1)), (1, 2));
+        assert_eq!(log.append(&outcome_event(2)), (2, 3));
+        assert_eq!(log.len(), 3);
+    }
+
+    #[test]
+    fn test_inclusion_proof_verifies_for_every_leaf() {
+        let mut log = TransparencyLog::new();
+        let leaves: Vec<[u8; 32]> = (0..13)
+            .map(|n| {
+                let event = outcome_event(n);
+                log.append(&event);
+                TransparencyLog::event_leaf_hash(&event)
+            })
+            .collect();
+
+        let root = log.root();
+        for (index, leaf) in leaves.iter().enumerate() {
+            let proof = log.inclusion_proof(index).unwrap();
+            assert!(TransparencyLog::verify_inclusion(
+                *leaf,
+                index,
+                log.len(),
+                &proof,
+                root
+            ));
+        }
+    }
+
+    #[test]
+    fn test_inclusion_proof_rejects_wrong_leaf_or_root() {
+        let mut log = TransparencyLog::new();
+        for n in 0..7 {
+            log.append(&outcome_event(n));
+        }
+        let root = log.root();
+        let proof = log.inclusion_proof(3).unwrap();
+
+        let wrong_leaf = TransparencyLog::event_leaf_hash(&outcome_event(99));
+        assert!(!TransparencyLog::verify_inclusion(
+            wrong_leaf,
+            3,
+            log.len(),
+            &proof,
+            root
+        ));
+
+        let real_leaf = TransparencyLog::event_leaf_hash(&outcome_event(3));
+        let wrong_root = sha256(b"not the root");
+        assert!(!TransparencyLog::verify_inclusion(
+            real_leaf,
+            3,
+            log.len(),
+            &proof,
+            wrong_root
+        ));
+    }
+
+    #[test]
+    fn test_single_leaf_inclusion_proof_is_empty_path() {
+        let mut log = TransparencyLog::new();
+        let event = outcome_event(0);
+        log.append(&event);
+        let leaf = TransparencyLog::event_leaf_hash(&event);
+
+        let proof = log.inclusion_proof(0).unwrap();
+        assert!(proof.is_empty());
+        assert!(TransparencyLog::verify_inclusion(
+            leaf,
+            0,
+            1,
+            &proof,
+            log.root()
+        ));
+    }
+
+    #[test]
+    fn test_consistency_proof_across_growth_sizes() {
+        let mut log = TransparencyLog::new();
+        let mut roots = Vec::new();
+        for n in 0..20 {
+            log.append(&outcome_event(n));
+            roots.push((log.len(), log.root()));
+        }
+
+        for &(old_size, old_root) in &roots {
+            for &(new_size, new_root) in &roots {
+                if new_size < old_size {
+                    continue;
+                }
+                let proof = log.consistency_proof(old_size, new_size);
+                assert!(
+                    TransparencyLog::verify_consistency(
+                        old_size, new_size, old_root, new_root, &proof
+                    ),
+                    "consistency failed for old_size={old_size} new_size={new_size}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_consistency_proof_rejects_rewritten_history() {
+        let mut log = TransparencyLog::new();
+        for n in 0..5 {
+            log.append(&outcome_event(n));
+        }
+        let old_root = log.root();
+        let old_size = log.len();
+
+        // Simulate a rewrite: mutate a leaf that was already part of the old
+        // tree (not merely append past it), then grow the log further.
+        log.leaves[2] = leaf_hash(b"tampered");
+        for n in 5..10 {
+            log.append(&outcome_event(n));
+        }
+        let new_root = log.root();
+        let new_size = log.len();
+
+        let proof = log.consistency_proof(old_size, new_size);
+        assert!(!TransparencyLog::verify_consistency(
+            old_size, new_size, old_root, new_root, &proof
+        ));
+    }
+
+    #[test]
+    fn test_consistency_proof_trivial_cases() {
+        let mut log = TransparencyLog::new();
+        for n in 0..4 {
+            log.append(&outcome_event(n));
+        }
+        let root = log.root();
+
+        // old_size == 0: anything is a consistent extension of an empty log.
+        assert!(TransparencyLog::verify_consistency(
+            0,
+            log.len(),
+            sha256(b"irrelevant"),
+            root,
+            &[]
+        ));
+
+        // old_size == new_size: the log is trivially consistent with itself.
+        assert!(TransparencyLog::verify_consistency(
+            log.len(),
+            log.len(),
+            root,
+            root,
+            &[]
+        ));
+    }
+}
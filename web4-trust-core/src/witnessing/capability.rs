@@ -0,0 +1,393 @@
+//! Capability-based delegation of witnessing authority (UCAN-style)
+//!
+//! By default any entity can [`give_witness`](crate::EntityTrust::give_witness)
+//! against any target with full weight — there is no notion of who is
+//! *authorized* to witness on whose behalf. A [`WitnessCapability`] is a
+//! scoped, expiring, Ed25519-signed delegation from an issuer to an
+//! audience (modeled on [UCAN](https://github.com/ucan-wg/spec)): "you may
+//! witness entities matching this scope, with at most this magnitude, until
+//! this time". Capabilities chain — an audience can re-delegate a narrower
+//! subset of what it was given — and [`WitnessCapability::validate_chain`]
+//! checks the whole chain before a witness event is allowed to land.
+
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use web4_core::crypto::{sha256, KeyPair, PublicKey, SignatureBytes};
+use web4_core::{Result as Web4Result, Web4Error};
+
+use super::WitnessProof;
+
+const PROOF_TYPE: &str = "Ed25519Signature2020";
+
+/// The delegated claim, canonicalized (sorted keys) before hashing/signing.
+///
+/// `audience_key` is part of the signed claim (not just a loose attribute)
+/// so a holder of an intercepted capability can't swap in their own key
+/// while keeping the `audience` id string intact.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct CapabilityClaim {
+    issuer: String,
+    audience: String,
+    audience_key: PublicKey,
+    scope: String,
+    max_magnitude: Option<f64>,
+    expires_at: DateTime<Utc>,
+}
+
+impl CapabilityClaim {
+    fn canonical_bytes(&self) -> Vec<u8> {
+        let value = serde_json::to_value(self).expect("CapabilityClaim always serializes");
+        serde_json::to_vec(&value).expect("serde_json::Value always serializes")
+    }
+}
+
+/// A scoped, expiring grant of witnessing authority from `issuer` to
+/// `audience`, signed with the issuer's Ed25519 key.
+///
+/// `scope` is an entity-id prefix (entity ids are `"type:name"`, e.g.
+/// `"mcp:"` matches every `mcp:*` entity); a delegated link's scope must be
+/// a prefix extension of its parent's, i.e. only ever narrower.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct WitnessCapability {
+    /// The delegating entity's ID.
+    pub issuer: String,
+    /// The delegating entity's public key, used to verify `proof`.
+    pub issuer_key: PublicKey,
+    /// The delegate entity's ID.
+    pub audience: String,
+    /// The delegate's public key — binds `audience` to a specific keypair
+    /// so the next link in the chain must be signed by this exact key.
+    pub audience_key: PublicKey,
+    /// Entity-id prefix this capability authorizes witnessing against.
+    pub scope: String,
+    /// Ceiling on the witnessing magnitude this capability permits, if any.
+    pub max_magnitude: Option<f64>,
+    /// When this capability stops being valid.
+    pub expires_at: DateTime<Utc>,
+    /// Cryptographic proof binding the claim to the issuer's keypair.
+    pub proof: WitnessProof,
+}
+
+impl WitnessCapability {
+    /// Issue a capability delegating `scope`/`max_magnitude` to `audience`,
+    /// signed with the issuer's `keypair`.
+    pub fn issue(
+        issuer: &str,
+        keypair: &KeyPair,
+        audience: &str,
+        audience_key: PublicKey,
+        scope: &str,
+        max_magnitude: Option<f64>,
+        expires_at: DateTime<Utc>,
+    ) -> Self {
+        let claim = CapabilityClaim {
+            issuer: issuer.to_string(),
+            audience: audience.to_string(),
+            audience_key: audience_key.clone(),
+            scope: scope.to_string(),
+            max_magnitude,
+            expires_at,
+        };
+
+        let hash = sha256(&claim.canonical_bytes());
+        let signature = keypair.sign(&hash);
+
+        Self {
+            issuer: issuer.to_string(),
+            issuer_key: keypair.verifying_key(),
+            audience: audience.to_string(),
+            audience_key,
+            scope: scope.to_string(),
+            max_magnitude,
+            expires_at,
+            proof: WitnessProof {
+                proof_type: PROOF_TYPE.to_string(),
+                created: Utc::now(),
+                verification_method: issuer.to_string(),
+                proof_value: BASE64.encode(signature.bytes),
+            },
+        }
+    }
+
+    /// Verify this link's signature against its own embedded `issuer_key`.
+    fn verify_signature(&self) -> Web4Result<()> {
+        let claim = CapabilityClaim {
+            issuer: self.issuer.clone(),
+            audience: self.audience.clone(),
+            audience_key: self.audience_key.clone(),
+            scope: self.scope.clone(),
+            max_magnitude: self.max_magnitude,
+            expires_at: self.expires_at,
+        };
+
+        let sig_bytes = BASE64
+            .decode(&self.proof.proof_value)
+            .map_err(|e| Web4Error::Unauthorized(format!("malformed base64 proof: {e}")))?;
+        let sig_bytes: [u8; 64] = sig_bytes
+            .try_into()
+            .map_err(|_| Web4Error::Unauthorized("signature is not 64 bytes".into()))?;
+        let signature = SignatureBytes::from_bytes(sig_bytes);
+
+        let hash = sha256(&claim.canonical_bytes());
+        self.issuer_key.verify(&hash, &signature).map_err(|e| {
+            Web4Error::Unauthorized(format!(
+                "capability signature from {} failed to verify: {e}",
+                self.issuer
+            ))
+        })
+    }
+
+    /// Validate a full delegation chain authorizing `holder` to witness
+    /// `target_id` with `magnitude`.
+    ///
+    /// Checks that every link's signature verifies, no link has expired,
+    /// each link's `audience`/`audience_key` matches the next link's
+    /// `issuer`/`issuer_key`, and scope/magnitude/expiry only ever narrow
+    /// down the chain. The final link's audience must be `holder`, its
+    /// scope must cover `target_id`, and `magnitude` must fit within its
+    /// ceiling.
+    ///
+    /// This only checks internal consistency of the chain — it does not
+    /// pin `chain[0].issuer_key` to any externally-trusted root of
+    /// authority. Callers that need a trust anchor (e.g. an organization's
+    /// well-known root key) must check `chain[0]` against it themselves.
+    pub fn validate_chain(
+        chain: &[WitnessCapability],
+        holder: &str,
+        target_id: &str,
+        magnitude: f64,
+    ) -> Web4Result<()> {
+        let Some((first, rest)) = chain.split_first() else {
+            return Err(Web4Error::Unauthorized(
+                "empty witness capability chain".into(),
+            ));
+        };
+
+        let now = Utc::now();
+        first.verify_signature()?;
+        if first.expires_at <= now {
+            return Err(Web4Error::Unauthorized(format!(
+                "capability issued by {} has expired",
+                first.issuer
+            )));
+        }
+
+        let mut current = first;
+        for link in rest {
+            link.verify_signature()?;
+            if link.expires_at <= now {
+                return Err(Web4Error::Unauthorized(format!(
+                    "capability issued by {} has expired",
+                    link.issuer
+                )));
+            }
+            if link.issuer != current.audience || link.issuer_key != current.audience_key {
+                return Err(Web4Error::Unauthorized(format!(
+                    "delegation chain broken: {} delegated to {}, but next link is issued by {}",
+                    current.issuer, current.audience, link.issuer
+                )));
+            }
+            if !link.scope.starts_with(&current.scope) {
+                return Err(Web4Error::Unauthorized(format!(
+                    "delegated scope \"{}\" does not narrow parent scope \"{}\"",
+                    link.scope, current.scope
+                )));
+            }
+            if link.expires_at > current.expires_at {
+                return Err(Web4Error::Unauthorized(
+                    "delegated capability outlives its parent".into(),
+                ));
+            }
+            if let Some(parent_max) = current.max_magnitude {
+                if link.max_magnitude.map_or(true, |m| m > parent_max) {
+                    return Err(Web4Error::Unauthorized(
+                        "delegated capability widens parent's magnitude ceiling".into(),
+                    ));
+                }
+            }
+            current = link;
+        }
+
+        if current.audience != holder {
+            return Err(Web4Error::Unauthorized(format!(
+                "capability chain grants authority to {}, not {holder}",
+                current.audience
+            )));
+        }
+        if !target_id.starts_with(&current.scope) {
+            return Err(Web4Error::Unauthorized(format!(
+                "capability scope \"{}\" does not cover target {target_id}",
+                current.scope
+            )));
+        }
+        if let Some(max) = current.max_magnitude {
+            if magnitude > max {
+                return Err(Web4Error::Unauthorized(format!(
+                    "magnitude {magnitude} exceeds capability ceiling {max}"
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn leaf_capability(
+        issuer: &str,
+        issuer_keypair: &KeyPair,
+        audience: &str,
+        audience_keypair: &KeyPair,
+        scope: &str,
+        max_magnitude: Option<f64>,
+    ) -> WitnessCapability {
+        WitnessCapability::issue(
+            issuer,
+            issuer_keypair,
+            audience,
+            audience_keypair.verifying_key(),
+            scope,
+            max_magnitude,
+            Utc::now() + Duration::hours(1),
+        )
+    }
+
+    #[test]
+    fn test_single_link_chain_validates() {
+        let org = KeyPair::generate();
+        let agent = KeyPair::generate();
+        let cap = leaf_capability("org:acme", &org, "session:agent-1", &agent, "mcp:", Some(0.5));
+
+        assert!(WitnessCapability::validate_chain(
+            &[cap],
+            "session:agent-1",
+            "mcp:filesystem",
+            0.2
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_nested_delegation_narrows_and_validates() {
+        let org = KeyPair::generate();
+        let team = KeyPair::generate();
+        let agent = KeyPair::generate();
+
+        let root = leaf_capability("org:acme", &org, "team:infra", &team, "mcp:", Some(0.5));
+        // Expire with the parent: a fresh `now + 1h` would outlive it.
+        let delegated = WitnessCapability::issue(
+            "team:infra",
+            &team,
+            "session:agent-1",
+            agent.verifying_key(),
+            "mcp:filesystem",
+            Some(0.2),
+            root.expires_at,
+        );
+
+        assert!(WitnessCapability::validate_chain(
+            &[root, delegated],
+            "session:agent-1",
+            "mcp:filesystem:readonly",
+            0.1
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_rejects_widened_scope() {
+        let org = KeyPair::generate();
+        let team = KeyPair::generate();
+        let agent = KeyPair::generate();
+
+        let root = leaf_capability(
+            "org:acme",
+            &org,
+            "team:infra",
+            &team,
+            "mcp:filesystem",
+            None,
+        );
+        // "mcp:" is broader than the parent's "mcp:filesystem" scope.
+        let delegated = leaf_capability("team:infra", &team, "session:agent-1", &agent, "mcp:", None);
+
+        let err =
+            WitnessCapability::validate_chain(&[root, delegated], "session:agent-1", "mcp:db", 0.1)
+                .unwrap_err();
+        assert!(matches!(err, Web4Error::Unauthorized(_)));
+    }
+
+    #[test]
+    fn test_rejects_widened_magnitude() {
+        let org = KeyPair::generate();
+        let agent = KeyPair::generate();
+        let cap = leaf_capability("org:acme", &org, "session:agent-1", &agent, "mcp:", Some(0.2));
+
+        let err = WitnessCapability::validate_chain(
+            &[cap],
+            "session:agent-1",
+            "mcp:filesystem",
+            0.5,
+        )
+        .unwrap_err();
+        assert!(matches!(err, Web4Error::Unauthorized(_)));
+    }
+
+    #[test]
+    fn test_rejects_expired_capability() {
+        let org = KeyPair::generate();
+        let agent = KeyPair::generate();
+        let cap = WitnessCapability::issue(
+            "org:acme",
+            &org,
+            "session:agent-1",
+            agent.verifying_key(),
+            "mcp:",
+            None,
+            Utc::now() - Duration::seconds(1),
+        );
+
+        let err =
+            WitnessCapability::validate_chain(&[cap], "session:agent-1", "mcp:filesystem", 0.1)
+                .unwrap_err();
+        assert!(matches!(err, Web4Error::Unauthorized(_)));
+    }
+
+    #[test]
+    fn test_rejects_broken_chain_audience_mismatch() {
+        let org = KeyPair::generate();
+        let team = KeyPair::generate();
+        let impostor = KeyPair::generate();
+        let agent = KeyPair::generate();
+
+        let root = leaf_capability("org:acme", &org, "team:infra", &team, "mcp:", None);
+        // Signed by `impostor`, not `team`, even though it claims issuer "team:infra".
+        let forged = leaf_capability("team:infra", &impostor, "session:agent-1", &agent, "mcp:", None);
+
+        let err = WitnessCapability::validate_chain(
+            &[root, forged],
+            "session:agent-1",
+            "mcp:filesystem",
+            0.1,
+        )
+        .unwrap_err();
+        assert!(matches!(err, Web4Error::Unauthorized(_)));
+    }
+
+    #[test]
+    fn test_rejects_wrong_holder() {
+        let org = KeyPair::generate();
+        let agent = KeyPair::generate();
+        let cap = leaf_capability("org:acme", &org, "session:agent-1", &agent, "mcp:", None);
+
+        let err =
+            WitnessCapability::validate_chain(&[cap], "session:agent-2", "mcp:filesystem", 0.1)
+                .unwrap_err();
+        assert!(matches!(err, Web4Error::Unauthorized(_)));
+    }
+}
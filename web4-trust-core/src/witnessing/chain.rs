@@ -1,7 +1,16 @@
 //! Witnessing chain traversal
 
+use std::collections::{HashSet, VecDeque};
+
 use serde::{Deserialize, Serialize};
+
 use super::WitnessNode;
+use crate::storage::TrustStore;
+use crate::Result;
+
+/// Default per-hop decay applied by [`WitnessingChain::aggregate_witness_trust`]
+/// and [`ChainBuilder`] when neither specifies its own.
+pub const DEFAULT_DEPTH_DECAY: f64 = 0.5;
 
 /// Result of traversing a witnessing chain
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -49,21 +58,48 @@ impl WitnessingChain {
         self.witnessed_by.len() + self.has_witnessed.len()
     }
 
-    /// Calculate aggregate trust from witnesses
+    /// Calculate aggregate trust from witnesses, weighting each by
+    /// [`DEFAULT_DEPTH_DECAY`] raised to its BFS depth (see
+    /// [`aggregate_witness_trust_with_decay`](Self::aggregate_witness_trust_with_decay)).
     ///
-    /// Entities witnessed by high-trust witnesses get a boost.
+    /// Witnesses all at the same depth (the common case before [`ChainBuilder`]
+    /// populates multiple depths) weight equally, so this is a plain average
+    /// in that case — depth-weighting only matters once `witnessed_by` spans
+    /// more than one hop.
     pub fn aggregate_witness_trust(&self) -> f64 {
+        self.aggregate_witness_trust_with_decay(DEFAULT_DEPTH_DECAY)
+    }
+
+    /// Calculate aggregate trust from witnesses with an explicit per-hop
+    /// `decay`.
+    ///
+    /// A witness at BFS depth `d` contributes with weight `decay.powi(d)`, so
+    /// `aggregate = Σ(node.t3_average * decay^depth) / Σ(decay^depth)` —
+    /// direct witnesses (depth 1) count more than witnesses-of-witnesses
+    /// (depth 2), and so on.
+    pub fn aggregate_witness_trust_with_decay(&self, decay: f64) -> f64 {
         if self.witnessed_by.is_empty() {
             return 0.0;
         }
 
-        let total: f64 = self.witnessed_by.iter().map(|w| w.t3_average).sum();
-        total / self.witnessed_by.len() as f64
+        let mut weighted_sum = 0.0;
+        let mut weight_total = 0.0;
+        for node in &self.witnessed_by {
+            let weight = decay.powi(node.depth as i32);
+            weighted_sum += node.t3_average * weight;
+            weight_total += weight;
+        }
+
+        if weight_total <= 0.0 {
+            0.0
+        } else {
+            weighted_sum / weight_total
+        }
     }
 
     /// Calculate transitive trust score
     ///
-    /// Combines direct trust with witness attestations.
+    /// Combines direct trust with depth-weighted witness attestations.
     /// Formula: direct_trust * 0.7 + witness_trust * 0.3
     pub fn transitive_trust(&self) -> f64 {
         let witness_trust = self.aggregate_witness_trust();
@@ -71,9 +107,157 @@ impl WitnessingChain {
     }
 }
 
+/// Builds a [`WitnessingChain`] by breadth-first traversal of a
+/// [`TrustStore`]'s witness graph, rather than the single-level
+/// [`WitnessingChain::add_witness`] calls a caller would otherwise have to
+/// make by hand.
+///
+/// Starting from a root entity, follows `witnessed_by` edges outward up to
+/// `max_depth` hops, tracking visited entity ids in a [`HashSet`] so cycles
+/// (witness graphs can loop: A witnesses B witnesses A) terminate the
+/// traversal instead of recursing forever. Each discovered entity is added to
+/// the chain as a [`WitnessNode`] at the BFS depth it was first reached.
+pub struct ChainBuilder<'a> {
+    store: &'a dyn TrustStore,
+    max_depth: u32,
+    decay: f64,
+}
+
+impl<'a> ChainBuilder<'a> {
+    /// Traverse `store`'s witness graph up to depth 2 with
+    /// [`DEFAULT_DEPTH_DECAY`].
+    pub fn new(store: &'a dyn TrustStore) -> Self {
+        Self {
+            store,
+            max_depth: 2,
+            decay: DEFAULT_DEPTH_DECAY,
+        }
+    }
+
+    /// Set how many hops out from the root to traverse.
+    pub fn with_max_depth(mut self, max_depth: u32) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Set the per-hop decay used by [`transitive_trust`](Self::transitive_trust).
+    pub fn with_decay(mut self, decay: f64) -> Self {
+        self.decay = decay;
+        self
+    }
+
+    /// Run the traversal, returning a [`WitnessingChain`] rooted at
+    /// `entity_id` whose `witnessed_by` spans every depth reached.
+    pub fn build(&self, entity_id: &str) -> Result<WitnessingChain> {
+        let root = self.store.get(entity_id)?;
+        let mut chain =
+            WitnessingChain::new(&root.entity_id, root.t3_average(), root.trust_level().to_string());
+
+        let mut visited: HashSet<String> = HashSet::new();
+        visited.insert(root.entity_id.clone());
+
+        let mut frontier: VecDeque<(String, u32)> = root
+            .witnessed_by
+            .iter()
+            .map(|witness_id| (witness_id.clone(), 1))
+            .collect();
+
+        while let Some((current_id, depth)) = frontier.pop_front() {
+            if depth > self.max_depth || visited.contains(&current_id) {
+                continue;
+            }
+            visited.insert(current_id.clone());
+
+            let Some(entity) = self.store.get_existing(&current_id)? else {
+                continue;
+            };
+            chain.add_witness(WitnessNode::new(
+                entity.entity_id.clone(),
+                entity.t3_average(),
+                entity.trust_level().to_string(),
+                depth,
+            ));
+
+            if depth < self.max_depth {
+                for next_id in &entity.witnessed_by {
+                    if !visited.contains(next_id) {
+                        frontier.push_back((next_id.clone(), depth + 1));
+                    }
+                }
+            }
+        }
+
+        Ok(chain)
+    }
+
+    /// Run [`build`](Self::build), then return its
+    /// [`transitive_trust`](WitnessingChain::transitive_trust)-equivalent
+    /// score computed with this builder's `decay` instead of the default.
+    pub fn transitive_trust(&self, entity_id: &str) -> Result<f64> {
+        let chain = self.build(entity_id)?;
+        let witness_trust = chain.aggregate_witness_trust_with_decay(self.decay);
+        Ok(chain.t3_average * 0.7 + witness_trust * 0.3)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::storage::InMemoryStore;
+
+    #[test]
+    fn test_chain_builder_populates_multiple_depths() {
+        let store = InMemoryStore::new();
+        // session:c witnesses session:b witnesses mcp:root.
+        store.witness("session:b", "mcp:root", true, 0.1).unwrap();
+        store.witness("session:c", "session:b", true, 0.1).unwrap();
+
+        let chain = ChainBuilder::new(&store).with_max_depth(2).build("mcp:root").unwrap();
+
+        let depths: std::collections::HashMap<&str, u32> = chain
+            .witnessed_by
+            .iter()
+            .map(|n| (n.entity_id.as_str(), n.depth))
+            .collect();
+        assert_eq!(depths.get("session:b"), Some(&1));
+        assert_eq!(depths.get("session:c"), Some(&2));
+    }
+
+    #[test]
+    fn test_chain_builder_respects_max_depth() {
+        let store = InMemoryStore::new();
+        store.witness("session:b", "mcp:root", true, 0.1).unwrap();
+        store.witness("session:c", "session:b", true, 0.1).unwrap();
+
+        let chain = ChainBuilder::new(&store).with_max_depth(1).build("mcp:root").unwrap();
+
+        assert_eq!(chain.witnessed_by.len(), 1);
+        assert_eq!(chain.witnessed_by[0].entity_id, "session:b");
+    }
+
+    #[test]
+    fn test_chain_builder_terminates_on_cycle() {
+        let store = InMemoryStore::new();
+        // mcp:a and mcp:b mutually witness each other.
+        store.witness("mcp:a", "mcp:b", true, 0.1).unwrap();
+        store.witness("mcp:b", "mcp:a", true, 0.1).unwrap();
+
+        let chain = ChainBuilder::new(&store).with_max_depth(5).build("mcp:a").unwrap();
+
+        // Must terminate (no infinite loop) and never revisit the root.
+        assert!(chain.witnessed_by.iter().all(|n| n.entity_id != "mcp:a"));
+    }
+
+    #[test]
+    fn test_aggregate_witness_trust_with_decay_weights_by_depth() {
+        let mut chain = WitnessingChain::new("mcp:test", 0.5, "medium");
+        chain.add_witness(WitnessNode::new("session:a", 0.8, "high", 1));
+        chain.add_witness(WitnessNode::new("session:b", 0.4, "low", 2));
+
+        // weighted = (0.8*0.5 + 0.4*0.25) / (0.5 + 0.25) = 0.6/0.75
+        let weighted = chain.aggregate_witness_trust_with_decay(0.5);
+        assert!((weighted - (0.6 / 0.75)).abs() < 1e-9);
+    }
 
     #[test]
     fn test_new_chain() {
@@ -0,0 +1,190 @@
+//! Witness events packaged as signed W3C Verifiable Credentials
+//!
+//! Plain [`WitnessEvent`](super::WitnessEvent)s (and the `witnessed_by`/
+//! `has_witnessed` ID lists on [`EntityTrust`](crate::EntityTrust)) carry no
+//! proof — anyone can fabricate a witness relationship in the persisted JSON.
+//! A [`WitnessCredential`] instead binds the claim to the witness's keypair:
+//! the canonicalized claim is hashed and Ed25519-signed, so a relying party
+//! can verify the witness count it's trusting independently of the store.
+
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use web4_core::crypto::{sha256, KeyPair, PublicKey, SignatureBytes};
+
+use crate::{Error, Result};
+
+const CREDENTIAL_CONTEXT: &str = "https://www.w3.org/2018/credentials/v1";
+const CREDENTIAL_TYPE: &str = "VerifiableCredential";
+const WITNESS_CREDENTIAL_TYPE: &str = "Web4WitnessCredential";
+const PROOF_TYPE: &str = "Ed25519Signature2020";
+
+/// The witnessed claim, canonicalized (sorted keys) before hashing/signing.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct WitnessClaim {
+    issuer: String,
+    subject_id: String,
+    success: bool,
+    magnitude: f64,
+    issuance_date: DateTime<Utc>,
+}
+
+impl WitnessClaim {
+    /// Serialize via `serde_json::Value` so struct field order doesn't leak
+    /// into the signed bytes — `Value`'s object map sorts keys alphabetically.
+    fn canonical_bytes(&self) -> Vec<u8> {
+        let value = serde_json::to_value(self).expect("WitnessClaim always serializes");
+        serde_json::to_vec(&value).expect("serde_json::Value always serializes")
+    }
+}
+
+/// `credentialSubject` of a [`WitnessCredential`]: the witnessed entity and
+/// what was observed.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct WitnessCredentialSubject {
+    /// The witnessed entity's ID.
+    pub id: String,
+    /// Whether the witnessed action succeeded.
+    pub success: bool,
+    /// Magnitude of the witnessing (0.0 - 1.0).
+    pub magnitude: f64,
+}
+
+/// Ed25519 proof over the canonicalized [`WitnessClaim`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct WitnessProof {
+    /// Proof suite identifier.
+    #[serde(rename = "type")]
+    pub proof_type: String,
+    /// When the proof was produced.
+    pub created: DateTime<Utc>,
+    /// The issuer's entity ID, used to look up the verifying key out of band.
+    pub verification_method: String,
+    /// Base64-encoded Ed25519 signature over `sha256(canonical claim bytes)`.
+    pub proof_value: String,
+}
+
+/// A witness event packaged as a W3C Verifiable Credential.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct WitnessCredential {
+    /// JSON-LD context.
+    #[serde(rename = "@context")]
+    pub context: Vec<String>,
+    /// Credential types; always `["VerifiableCredential", "Web4WitnessCredential"]`.
+    #[serde(rename = "type")]
+    pub credential_type: Vec<String>,
+    /// The witness's entity ID (the credential issuer).
+    pub issuer: String,
+    /// The witnessed entity and what was observed.
+    pub credential_subject: WitnessCredentialSubject,
+    /// When the credential was issued.
+    pub issuance_date: DateTime<Utc>,
+    /// Cryptographic proof binding the claim to the issuer's keypair.
+    pub proof: WitnessProof,
+}
+
+impl WitnessCredential {
+    /// Issue a credential for `witness_id` observing `subject_id`, signed with
+    /// `keypair`.
+    pub fn issue(
+        witness_id: &str,
+        subject_id: &str,
+        success: bool,
+        magnitude: f64,
+        keypair: &KeyPair,
+    ) -> Self {
+        let issuance_date = Utc::now();
+        let claim = WitnessClaim {
+            issuer: witness_id.to_string(),
+            subject_id: subject_id.to_string(),
+            success,
+            magnitude,
+            issuance_date,
+        };
+
+        let hash = sha256(&claim.canonical_bytes());
+        let signature = keypair.sign(&hash);
+
+        Self {
+            context: vec![CREDENTIAL_CONTEXT.to_string()],
+            credential_type: vec![
+                CREDENTIAL_TYPE.to_string(),
+                WITNESS_CREDENTIAL_TYPE.to_string(),
+            ],
+            issuer: witness_id.to_string(),
+            credential_subject: WitnessCredentialSubject {
+                id: subject_id.to_string(),
+                success,
+                magnitude,
+            },
+            issuance_date,
+            proof: WitnessProof {
+                proof_type: PROOF_TYPE.to_string(),
+                created: issuance_date,
+                verification_method: witness_id.to_string(),
+                proof_value: BASE64.encode(signature.bytes),
+            },
+        }
+    }
+
+    /// Verify the proof against the witness's public key.
+    ///
+    /// Recomputes the claim hash and checks it against `proof.proof_value`;
+    /// fails closed on any malformed or mismatched signature.
+    pub fn verify(&self, witness_pubkey: &PublicKey) -> Result<()> {
+        let claim = WitnessClaim {
+            issuer: self.issuer.clone(),
+            subject_id: self.credential_subject.id.clone(),
+            success: self.credential_subject.success,
+            magnitude: self.credential_subject.magnitude,
+            issuance_date: self.issuance_date,
+        };
+
+        let sig_bytes = BASE64
+            .decode(&self.proof.proof_value)
+            .map_err(|e| Error::InvalidWitnessSignature(format!("malformed base64 proof: {e}")))?;
+        let sig_bytes: [u8; 64] = sig_bytes
+            .try_into()
+            .map_err(|_| Error::InvalidWitnessSignature("signature is not 64 bytes".into()))?;
+        let signature = SignatureBytes::from_bytes(sig_bytes);
+
+        let hash = sha256(&claim.canonical_bytes());
+        witness_pubkey
+            .verify(&hash, &signature)
+            .map_err(|e| Error::InvalidWitnessSignature(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_issue_and_verify_roundtrip() {
+        let keypair = KeyPair::generate();
+        let cred = WitnessCredential::issue("session:abc", "mcp:filesystem", true, 0.1, &keypair);
+
+        assert_eq!(cred.issuer, "session:abc");
+        assert_eq!(cred.credential_subject.id, "mcp:filesystem");
+        assert!(cred.verify(&keypair.verifying_key()).is_ok());
+    }
+
+    #[test]
+    fn test_verify_rejects_wrong_key() {
+        let keypair = KeyPair::generate();
+        let other = KeyPair::generate();
+        let cred = WitnessCredential::issue("session:abc", "mcp:filesystem", true, 0.1, &keypair);
+
+        assert!(cred.verify(&other.verifying_key()).is_err());
+    }
+
+    #[test]
+    fn test_verify_rejects_tampered_claim() {
+        let keypair = KeyPair::generate();
+        let mut cred = WitnessCredential::issue("session:abc", "mcp:filesystem", true, 0.1, &keypair);
+
+        cred.credential_subject.magnitude = 0.9;
+
+        assert!(cred.verify(&keypair.verifying_key()).is_err());
+    }
+}
@@ -2,8 +2,17 @@
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use web4_core::crypto::{KeyPair, PublicKey, SignatureBytes};
 
 /// A witnessing event between two entities
+///
+/// `signature`/`signer_public_key` are optional so existing unsigned events
+/// (and the JSON already on disk) keep deserializing; see
+/// [`sign`](Self::sign)/[`verify`](Self::verify) for the tamper-evidence this
+/// adds on top. This is a lighter-weight, single-struct alternative to
+/// [`WitnessCredential`](super::WitnessCredential)'s full VC wrapping, for
+/// callers that just want to carry a self-verifying event around rather than
+/// issue a credential.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct WitnessEvent {
     /// Entity doing the witnessing
@@ -24,6 +33,16 @@ pub struct WitnessEvent {
     /// Optional context about what was witnessed
     #[serde(default)]
     pub context: Option<String>,
+
+    /// Ed25519 signature over [`canonical_bytes`](Self::canonical_bytes),
+    /// set by [`sign`](Self::sign).
+    #[serde(default)]
+    pub signature: Option<SignatureBytes>,
+
+    /// Public key that produced `signature`, embedded so [`verify`](Self::verify)
+    /// is self-contained (Ed25519 has no `ecrecover`-style key recovery).
+    #[serde(default)]
+    pub signer_public_key: Option<PublicKey>,
 }
 
 impl WitnessEvent {
@@ -41,6 +60,8 @@ impl WitnessEvent {
             magnitude: magnitude.clamp(0.0, 1.0),
             timestamp: Utc::now(),
             context: None,
+            signature: None,
+            signer_public_key: None,
         }
     }
 
@@ -49,6 +70,75 @@ impl WitnessEvent {
         self.context = Some(context.into());
         self
     }
+
+    /// Canonical bytes covered by `sign`/`verify`: every field except the
+    /// signature itself, serialized via `serde_json::to_vec` (object-map keys
+    /// sort alphabetically, same canonicalization [`WitnessClaim`] and
+    /// [`SlateClaim`] use).
+    ///
+    /// [`WitnessClaim`]: super::credential
+    /// [`SlateClaim`]: super::slate
+    fn canonical_bytes(&self) -> Vec<u8> {
+        #[derive(Serialize)]
+        struct Canonical<'a> {
+            witness_id: &'a str,
+            target_id: &'a str,
+            success: bool,
+            magnitude: f64,
+            timestamp: DateTime<Utc>,
+            context: &'a Option<String>,
+        }
+
+        let canonical = Canonical {
+            witness_id: &self.witness_id,
+            target_id: &self.target_id,
+            success: self.success,
+            magnitude: self.magnitude,
+            timestamp: self.timestamp,
+            context: &self.context,
+        };
+        let value = serde_json::to_value(&canonical).expect("WitnessEvent fields always serialize");
+        serde_json::to_vec(&value).expect("serde_json::Value always serializes")
+    }
+
+    /// Sign this event with `keypair`, embedding both the signature and the
+    /// signer's public key so `verify` needs nothing beyond the event itself.
+    pub fn sign(&mut self, keypair: &KeyPair) {
+        let signature = keypair.sign(&self.canonical_bytes());
+        self.signature = Some(signature);
+        self.signer_public_key = Some(keypair.verifying_key());
+    }
+
+    /// Verify the embedded signature against the embedded signer public key.
+    /// Returns `false` if either is missing, so an unsigned event is never
+    /// mistaken for a verified one.
+    pub fn verify(&self) -> bool {
+        match (&self.signature, &self.signer_public_key) {
+            (Some(signature), Some(public_key)) => {
+                public_key.verify(&self.canonical_bytes(), signature).is_ok()
+            }
+            _ => false,
+        }
+    }
+
+    /// Recover the signer's public key, but only once its signature over this
+    /// event has been confirmed.
+    ///
+    /// Ed25519 can't algebraically recover a public key from a signature the
+    /// way secp256k1's `ecrecover` can, so "recovery" here is: confirm the
+    /// embedded key actually produced the signature, then hand it back.
+    /// Callers that need to confirm the signer's *claimed identity* still
+    /// have to check the recovered key against `witness_id` out-of-band (the
+    /// same `did:key`/registry lookup [`TrustCredential`] relies on).
+    ///
+    /// [`TrustCredential`]: crate::credential::TrustCredential
+    pub fn recover_signer(&self) -> Option<&PublicKey> {
+        if self.verify() {
+            self.signer_public_key.as_ref()
+        } else {
+            None
+        }
+    }
 }
 
 /// Node in a witnessing chain
@@ -109,4 +199,45 @@ mod tests {
 
         assert_eq!(event.context, Some("Tool call succeeded".to_string()));
     }
+
+    #[test]
+    fn test_unsigned_event_does_not_verify() {
+        let event = WitnessEvent::new("session:abc", "mcp:filesystem", true, 0.1);
+        assert!(!event.verify());
+        assert!(event.recover_signer().is_none());
+    }
+
+    #[test]
+    fn test_sign_and_verify_round_trip() {
+        let keypair = KeyPair::generate();
+        let mut event = WitnessEvent::new("session:abc", "mcp:filesystem", true, 0.1);
+        event.sign(&keypair);
+
+        assert!(event.verify());
+        assert_eq!(event.recover_signer(), Some(&keypair.verifying_key()));
+    }
+
+    #[test]
+    fn test_verify_rejects_tampered_event() {
+        let keypair = KeyPair::generate();
+        let mut event = WitnessEvent::new("session:abc", "mcp:filesystem", true, 0.1);
+        event.sign(&keypair);
+
+        event.magnitude = 0.9;
+
+        assert!(!event.verify());
+        assert!(event.recover_signer().is_none());
+    }
+
+    #[test]
+    fn test_verify_rejects_wrong_signer_key() {
+        let keypair = KeyPair::generate();
+        let other = KeyPair::generate();
+        let mut event = WitnessEvent::new("session:abc", "mcp:filesystem", true, 0.1);
+        event.sign(&keypair);
+
+        event.signer_public_key = Some(other.verifying_key());
+
+        assert!(!event.verify());
+    }
 }
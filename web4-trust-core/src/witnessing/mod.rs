@@ -7,6 +7,12 @@
 
 mod event;
 mod chain;
+mod credential;
+mod capability;
+mod slate;
 
 pub use event::{WitnessEvent, WitnessNode};
-pub use chain::WitnessingChain;
+pub use chain::{ChainBuilder, WitnessingChain, DEFAULT_DEPTH_DECAY};
+pub use credential::{WitnessCredential, WitnessCredentialSubject, WitnessProof};
+pub use capability::WitnessCapability;
+pub use slate::{SlateRole, WitnessSlate};
@@ -0,0 +1,277 @@
+//! Interactive two-party mutual witnessing via a serializable "slate"
+//!
+//! Plain [`EntityTrust::give_witness`]/[`receive_witness`](EntityTrust::receive_witness)
+//! let each side independently and blindly record its own view of an
+//! interaction — nothing binds both parties to agreeing on what actually
+//! happened. A [`WitnessSlate`] is a round-trip artifact instead: the
+//! witness (party A) describes the interaction and signs it
+//! ([`EntityTrust::new_round1`]), hands the serialized slate to the subject
+//! (party B), who checks A's signature and counter-signs
+//! ([`WitnessSlate::add_round2`]). Only a slate carrying both valid
+//! signatures can be applied ([`WitnessSlate::apply_to`]), so the T3/V3
+//! update on each side is symmetric and non-repudiable — neither party can
+//! later claim the other fabricated the interaction.
+
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use web4_core::crypto::{sha256, KeyPair, PublicKey, SignatureBytes};
+
+use crate::entity::EntityTrust;
+use crate::{Error, Result};
+
+use super::WitnessProof;
+
+const PROOF_TYPE: &str = "Ed25519Signature2020";
+
+/// Which side of a [`WitnessSlate`] the calling [`EntityTrust`] played,
+/// passed to [`WitnessSlate::apply_to`] so it applies the right update.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SlateRole {
+    /// This entity is `witness_id` — the one that observed the interaction.
+    Witness,
+    /// This entity is `subject_id` — the one that was witnessed.
+    Subject,
+}
+
+/// The claim both parties sign: what happened, and who it's between.
+/// Identical for round 1 and round 2 — round 2 is agreement, not amendment.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct SlateClaim {
+    witness_id: String,
+    subject_id: String,
+    success: bool,
+    magnitude: f64,
+    nonce: u64,
+}
+
+impl SlateClaim {
+    fn canonical_bytes(&self) -> Vec<u8> {
+        let value = serde_json::to_value(self).expect("SlateClaim always serializes");
+        serde_json::to_vec(&value).expect("serde_json::Value always serializes")
+    }
+}
+
+/// A two-round mutual-witnessing handshake. Serialize after round 1 and
+/// hand it to the counterparty; they add round 2 and hand it back.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct WitnessSlate {
+    witness_id: String,
+    witness_key: PublicKey,
+    subject_id: String,
+    subject_key: Option<PublicKey>,
+    success: bool,
+    magnitude: f64,
+    nonce: u64,
+    round1_proof: WitnessProof,
+    round2_proof: Option<WitnessProof>,
+}
+
+impl WitnessSlate {
+    fn claim(&self) -> SlateClaim {
+        SlateClaim {
+            witness_id: self.witness_id.clone(),
+            subject_id: self.subject_id.clone(),
+            success: self.success,
+            magnitude: self.magnitude,
+            nonce: self.nonce,
+        }
+    }
+
+    fn sign(claim: &SlateClaim, signer_id: &str, keypair: &KeyPair) -> WitnessProof {
+        let hash = sha256(&claim.canonical_bytes());
+        let signature = keypair.sign(&hash);
+        WitnessProof {
+            proof_type: PROOF_TYPE.to_string(),
+            created: chrono::Utc::now(),
+            verification_method: signer_id.to_string(),
+            proof_value: BASE64.encode(signature.bytes),
+        }
+    }
+
+    fn verify(claim: &SlateClaim, proof: &WitnessProof, key: &PublicKey) -> Result<()> {
+        let sig_bytes = BASE64
+            .decode(&proof.proof_value)
+            .map_err(|e| Error::InvalidWitnessSlate(format!("malformed base64 proof: {e}")))?;
+        let sig_bytes: [u8; 64] = sig_bytes
+            .try_into()
+            .map_err(|_| Error::InvalidWitnessSlate("signature is not 64 bytes".into()))?;
+        let signature = SignatureBytes::from_bytes(sig_bytes);
+        let hash = sha256(&claim.canonical_bytes());
+        key.verify(&hash, &signature)
+            .map_err(|e| Error::InvalidWitnessSlate(format!("signature verification failed: {e}")))
+    }
+
+    /// Round 1: create a slate describing an interaction between
+    /// `witness_id` and `subject_id`, signed by `witness_id`'s keypair.
+    ///
+    /// Prefer [`EntityTrust::new_round1`], which fills in `witness_id` from
+    /// `self` — this is the building block it calls into.
+    pub fn create(
+        witness_id: &str,
+        subject_id: &str,
+        success: bool,
+        magnitude: f64,
+        keypair: &KeyPair,
+    ) -> Self {
+        let nonce = rand::thread_rng().next_u64();
+        let claim = SlateClaim {
+            witness_id: witness_id.to_string(),
+            subject_id: subject_id.to_string(),
+            success,
+            magnitude,
+            nonce,
+        };
+        let round1_proof = Self::sign(&claim, witness_id, keypair);
+
+        Self {
+            witness_id: witness_id.to_string(),
+            witness_key: keypair.verifying_key(),
+            subject_id: subject_id.to_string(),
+            subject_key: None,
+            success,
+            magnitude,
+            nonce,
+            round1_proof,
+            round2_proof: None,
+        }
+    }
+
+    /// Round 2: validate the round-1 signature, then counter-sign with the
+    /// subject's keypair. Errors (and leaves the slate untouched) if round 1
+    /// doesn't check out or round 2 has already been added.
+    pub fn add_round2(&mut self, keypair: &KeyPair) -> Result<()> {
+        if self.round2_proof.is_some() {
+            return Err(Error::InvalidWitnessSlate(
+                "slate already has a round-2 signature".into(),
+            ));
+        }
+        let claim = self.claim();
+        Self::verify(&claim, &self.round1_proof, &self.witness_key)?;
+
+        self.subject_key = Some(keypair.verifying_key());
+        self.round2_proof = Some(Self::sign(&claim, &self.subject_id, keypair));
+        Ok(())
+    }
+
+    /// Verify both signatures and apply the agreed-upon outcome to `entity`,
+    /// as either the witness or the subject depending on `own_role`.
+    ///
+    /// Fails closed: an incomplete slate (no round-2 signature yet), a
+    /// signature that doesn't verify, or an `own_role` that doesn't match
+    /// `entity.entity_id` all return [`Error::InvalidWitnessSlate`] without
+    /// mutating `entity`.
+    pub fn apply_to(&self, entity: &mut EntityTrust, own_role: SlateRole) -> Result<()> {
+        let subject_key = self.subject_key.as_ref().ok_or_else(|| {
+            Error::InvalidWitnessSlate("slate is missing its round-2 signature".into())
+        })?;
+        let round2_proof = self.round2_proof.as_ref().ok_or_else(|| {
+            Error::InvalidWitnessSlate("slate is missing its round-2 signature".into())
+        })?;
+
+        let claim = self.claim();
+        Self::verify(&claim, &self.round1_proof, &self.witness_key)?;
+        Self::verify(&claim, round2_proof, subject_key)?;
+
+        match own_role {
+            SlateRole::Witness => {
+                if entity.entity_id != self.witness_id {
+                    return Err(Error::InvalidWitnessSlate(format!(
+                        "entity {} is not the slate's witness {}",
+                        entity.entity_id, self.witness_id
+                    )));
+                }
+                entity.give_witness(&self.subject_id, self.success, self.magnitude);
+            }
+            SlateRole::Subject => {
+                if entity.entity_id != self.subject_id {
+                    return Err(Error::InvalidWitnessSlate(format!(
+                        "entity {} is not the slate's subject {}",
+                        entity.entity_id, self.subject_id
+                    )));
+                }
+                entity.receive_witness(&self.witness_id, self.success, self.magnitude);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_full_round_trip_applies_to_both_sides() {
+        let witness_keys = KeyPair::generate();
+        let subject_keys = KeyPair::generate();
+
+        let mut witness = EntityTrust::new("session:abc");
+        let mut subject = EntityTrust::new("mcp:test");
+
+        let mut slate = witness.new_round1("mcp:test", true, 0.1, &witness_keys);
+        slate.add_round2(&subject_keys).unwrap();
+
+        slate.apply_to(&mut witness, SlateRole::Witness).unwrap();
+        slate.apply_to(&mut subject, SlateRole::Subject).unwrap();
+
+        assert!(witness.has_witnessed.contains(&"mcp:test".to_string()));
+        assert_eq!(subject.witness_count, 1);
+        assert!(subject.witnessed_by.contains(&"session:abc".to_string()));
+    }
+
+    #[test]
+    fn test_apply_to_rejects_incomplete_slate() {
+        let witness_keys = KeyPair::generate();
+        let witness = EntityTrust::new("session:abc");
+        let mut subject = EntityTrust::new("mcp:test");
+
+        let slate = witness.new_round1("mcp:test", true, 0.1, &witness_keys);
+
+        let err = slate
+            .apply_to(&mut subject, SlateRole::Subject)
+            .unwrap_err();
+        assert!(matches!(err, Error::InvalidWitnessSlate(_)));
+        assert_eq!(subject.witness_count, 0);
+    }
+
+    #[test]
+    fn test_add_round2_rejects_tampered_round1() {
+        let witness_keys = KeyPair::generate();
+        let subject_keys = KeyPair::generate();
+        let witness = EntityTrust::new("session:abc");
+
+        let mut slate = witness.new_round1("mcp:test", true, 0.1, &witness_keys);
+        slate.magnitude = 0.9; // tamper after signing, before round 2
+
+        let err = slate.add_round2(&subject_keys).unwrap_err();
+        assert!(matches!(err, Error::InvalidWitnessSlate(_)));
+    }
+
+    #[test]
+    fn test_apply_to_rejects_wrong_role() {
+        let witness_keys = KeyPair::generate();
+        let subject_keys = KeyPair::generate();
+        let mut witness = EntityTrust::new("session:abc");
+
+        let mut slate = witness.new_round1("mcp:test", true, 0.1, &witness_keys);
+        slate.add_round2(&subject_keys).unwrap();
+
+        // `witness` is the slate's witness, not its subject.
+        let err = slate
+            .apply_to(&mut witness, SlateRole::Subject)
+            .unwrap_err();
+        assert!(matches!(err, Error::InvalidWitnessSlate(_)));
+    }
+
+    #[test]
+    fn test_nonce_differs_across_slates() {
+        let witness_keys = KeyPair::generate();
+        let witness = EntityTrust::new("session:abc");
+
+        let slate_a = witness.new_round1("mcp:test", true, 0.1, &witness_keys);
+        let slate_b = witness.new_round1("mcp:test", true, 0.1, &witness_keys);
+
+        assert_ne!(slate_a.nonce, slate_b.nonce);
+    }
+}